@@ -66,6 +66,8 @@ interface Milestone {
     project: string
     target_date?: string
     created?: string
+    issue_count: number
+    completed_count: number
 }
 
 interface Issue {
@@ -79,6 +81,17 @@ interface Issue {
     description?: string
 }
 
+// `pillar ui --token` prints a URL carrying the token; keep it for the rest of the session
+const apiToken = new URLSearchParams(window.location.search).get('token') ?? sessionStorage.getItem('pillar-token')
+if (apiToken) sessionStorage.setItem('pillar-token', apiToken)
+
+const apiFetch = (url: string, init: RequestInit = {}) => {
+    if (!apiToken) return fetch(url, init)
+    const headers = new Headers(init.headers)
+    headers.set('Authorization', `Bearer ${apiToken}`)
+    return fetch(url, { ...init, headers })
+}
+
 const statusWeight: Record<string, number> = {
     'backlog': 1,
     'todo': 2,
@@ -181,7 +194,7 @@ function App() {
     const fetchData = async () => {
         setLoading(true)
         try {
-            const res = await fetch('/api/data')
+            const res = await apiFetch('/api/data')
             const data = await res.json()
             setProjects(data.projects || [])
             setMilestones(data.milestones || [])
@@ -304,7 +317,7 @@ function App() {
         if (!selectedIssue) return
         try {
             const [proj, num] = selectedIssue.id.split('/')
-            await fetch(`/api/issues/${proj}/${num}`, {
+            await apiFetch(`/api/issues/${proj}/${num}`, {
                 method: 'PATCH',
                 headers: { 'Content-Type': 'application/json' },
                 body: JSON.stringify({
@@ -323,7 +336,7 @@ function App() {
     const handleSaveProject = async () => {
         if (!selectedProjectId) return
         try {
-            await fetch(`/api/projects/${selectedProjectId}`, {
+            await apiFetch(`/api/projects/${selectedProjectId}`, {
                 method: 'PATCH',
                 headers: { 'Content-Type': 'application/json' },
                 body: JSON.stringify({
@@ -366,7 +379,7 @@ function App() {
         }
 
         try {
-            const res = await fetch(url, {
+            const res = await apiFetch(url, {
                 method: 'POST',
                 headers: { 'Content-Type': 'application/json' },
                 body: JSON.stringify(body)
@@ -788,7 +801,7 @@ function App() {
                                                     value={selectedIssue.status} 
                                                     onValueChange={async (val) => {
                                                         const [proj, num] = selectedIssue.id.split('/')
-                                                        await fetch(`/api/issues/${proj}/${num}`, {
+                                                        await apiFetch(`/api/issues/${proj}/${num}`, {
                                                             method: 'PATCH',
                                                             headers: { 'Content-Type': 'application/json' },
                                                             body: JSON.stringify({ status: val })
@@ -818,7 +831,7 @@ function App() {
                                                     value={selectedIssue.priority} 
                                                     onValueChange={async (val) => {
                                                         const [proj, num] = selectedIssue.id.split('/')
-                                                        await fetch(`/api/issues/${proj}/${num}`, {
+                                                        await apiFetch(`/api/issues/${proj}/${num}`, {
                                                             method: 'PATCH',
                                                             headers: { 'Content-Type': 'application/json' },
                                                             body: JSON.stringify({ priority: val })
@@ -891,7 +904,7 @@ function App() {
                                                         <Select 
                                                             value={project.status} 
                                                             onValueChange={async (val) => {
-                                                                await fetch(`/api/projects/${project.id}`, {
+                                                                await apiFetch(`/api/projects/${project.id}`, {
                                                                     method: 'PATCH',
                                                                     headers: { 'Content-Type': 'application/json' },
                                                                     body: JSON.stringify({ status: val })
@@ -916,7 +929,7 @@ function App() {
                                                         <Select 
                                                             value={project.priority} 
                                                             onValueChange={async (val) => {
-                                                                await fetch(`/api/projects/${project.id}`, {
+                                                                await apiFetch(`/api/projects/${project.id}`, {
                                                                     method: 'PATCH',
                                                                     headers: { 'Content-Type': 'application/json' },
                                                                     body: JSON.stringify({ priority: val })
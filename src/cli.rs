@@ -1,10 +1,27 @@
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "pillar")]
 #[command(about = "A file-based task tracker CLI", long_about = None)]
 #[command(version)]
 pub struct Cli {
+    /// Workspace root to use instead of searching upward for .pillar
+    #[arg(long, global = true, env = "PILLAR_WORKSPACE")]
+    pub workspace: Option<PathBuf>,
+
+    /// Print long output directly instead of through $PAGER
+    #[arg(long, global = true)]
+    pub no_pager: bool,
+
+    /// Don't print success messages; results and errors are still shown
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Print extra detail about what commands did
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -16,6 +33,10 @@ pub enum Commands {
         /// Base directory for project data (relative to workspace root)
         #[arg(short = 'p', long = "path")]
         base_directory: Option<String>,
+
+        /// Also create a demo project with a milestone and two issues
+        #[arg(long)]
+        with_example: bool,
     },
 
     /// Manage projects
@@ -34,13 +55,67 @@ pub enum Commands {
     #[command(subcommand)]
     Comment(CommentCommands),
 
+    /// Manage issue tags
+    #[command(subcommand)]
+    Tag(TagCommands),
+
+    /// Import data from other tools
+    #[command(subcommand)]
+    Import(ImportCommands),
+
+    /// View or change settings in .pillar/config.toml
+    #[command(subcommand)]
+    Config(ConfigCommands),
+
     /// Show workspace status overview
-    Status,
+    Status {
+        /// Limit the overview to a single project
+        #[arg(short = 'P', long)]
+        project: Option<String>,
+
+        /// Clear the screen and redraw periodically until Ctrl-C
+        #[arg(long)]
+        watch: bool,
+
+        /// Stable tab-separated lines for scripts instead of the human overview
+        #[arg(long, conflicts_with = "watch")]
+        porcelain: bool,
+
+        /// Seconds between redraws with --watch
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+    },
+
+    /// Show the highest-priority todo or in-progress issue to work on next
+    Next {
+        /// Only consider issues in this project
+        #[arg(short = 'P', long)]
+        project: Option<String>,
+    },
+
+    /// Show aggregate workspace statistics
+    Stats {
+        /// Print machine-readable JSON
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Display Kanban board view
     Board {
         /// Optional project name to filter by
         project: Option<String>,
+
+        /// Also show a Cancelled column
+        #[arg(long)]
+        all_statuses: bool,
+
+        /// Column layout: status, priority, or milestone
+        #[arg(long, value_parser = ["status", "priority", "milestone"], default_value = "status")]
+        group_by: String,
+
+        /// One row of columns per project instead of merging all projects
+        #[arg(long)]
+        swimlanes: bool,
     },
 
     /// Search across all entities
@@ -51,11 +126,43 @@ pub enum Commands {
         /// Entity type to search (project, milestone, issue, or all)
         #[arg(short, long, default_value = "all")]
         entity_type: String,
+
+        /// Print only the number of matches per entity type
+        #[arg(long)]
+        count_only: bool,
+    },
+
+    /// Show the activity log of changes made through Pillar
+    Log {
+        /// Only show activity for this entity (e.g. my-project or my-project/001)
+        #[arg(long)]
+        entity: Option<String>,
+
+        /// Only show the most recent N entries
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
+
+    /// Write changelog-style notes for the completed issues of a milestone
+    ReleaseNotes {
+        /// Project name or ID
+        project: String,
+
+        /// Milestone title
+        milestone: String,
+
+        /// Section headings: each issue's first tag, or its priority
+        #[arg(long, value_parser = ["tag", "priority"], default_value = "tag")]
+        group_by: String,
+
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<String>,
     },
 
     /// Export data to various formats
     Export {
-        /// Export format (json or csv)
+        /// Export format (json, csv, or mermaid for a milestone Gantt chart)
         #[arg(short, long, default_value = "json")]
         format: String,
 
@@ -66,6 +173,30 @@ pub enum Commands {
         /// Output file (defaults to stdout)
         #[arg(short, long)]
         output: Option<String>,
+
+        /// Only export entities in this project
+        #[arg(long)]
+        project: Option<String>,
+
+        /// Filter by status
+        #[arg(long)]
+        status: Option<String>,
+
+        /// Filter by priority (projects and issues)
+        #[arg(long)]
+        priority: Option<String>,
+
+        /// Filter issues by milestone
+        #[arg(long)]
+        milestone: Option<String>,
+
+        /// Filter issues by tag
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Comma-separated issue columns for CSV export, in order (e.g. title,status,tags)
+        #[arg(long)]
+        fields: Option<String>,
     },
 
     /// Launch the Pillar Web UI
@@ -73,10 +204,77 @@ pub enum Commands {
         /// Port to run the UI on
         #[arg(short, long, default_value = "3000")]
         port: u16,
+
+        /// If the port is taken, use the next free one instead of failing
+        #[arg(long)]
+        auto_port: bool,
+
+        /// Require `Authorization: Bearer <token>` on API requests
+        #[arg(long, env = "PILLAR_UI_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+
+        /// Serve the UI without allowing any changes
+        #[arg(long)]
+        read_only: bool,
     },
 
     /// Show recommended system prompts for AI agents
     Prompts,
+
+    /// Upgrade an older workspace to the current on-disk format
+    Migrate,
+
+    /// Check for mismatched project fields, deleted milestones, and unpadded issue IDs
+    Doctor {
+        /// Repair the problems instead of only listing them
+        #[arg(long)]
+        fix: bool,
+    },
+
+    /// Print the JSON Schema of the frontmatter, for validation and editor integrations
+    Schema {
+        /// Only this entity type (project, milestone, or issue); all three by default
+        #[arg(long, value_parser = ["project", "milestone", "issue"])]
+        entity: Option<String>,
+    },
+
+    /// Generate roff man pages for pillar and all subcommands
+    Manpages {
+        /// Directory to write the pages into (created if missing)
+        dir: PathBuf,
+    },
+}
+
+impl Commands {
+    /// Whether this command prints listings long enough to be worth paging
+    pub fn pages_output(&self) -> bool {
+        match self {
+            Commands::Project(cmd) => {
+                matches!(
+                    cmd,
+                    ProjectCommands::List { .. } | ProjectCommands::Show { .. }
+                )
+            }
+            Commands::Milestone(cmd) => matches!(cmd, MilestoneCommands::List { .. }),
+            Commands::Issue(cmd) => matches!(
+                cmd,
+                IssueCommands::List { .. }
+                    | IssueCommands::Show { .. }
+                    | IssueCommands::History { .. }
+            ),
+            Commands::Comment(cmd) => matches!(
+                cmd,
+                CommentCommands::List { .. }
+                    | CommentCommands::ListAll { .. }
+                    | CommentCommands::Mentions { .. }
+            ),
+            Commands::Status {
+                watch, porcelain, ..
+            } => !watch && !porcelain,
+            Commands::Board { .. } | Commands::Search { .. } | Commands::Log { .. } => true,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Subcommand)]
@@ -90,9 +288,9 @@ pub enum ProjectCommands {
         #[arg(long)]
         id: Option<String>,
 
-        /// Priority (low, medium, high, urgent)
-        #[arg(short, long, default_value = "medium")]
-        priority: String,
+        /// Priority (low, medium, high, urgent); defaults to [defaults] priority in config
+        #[arg(short, long)]
+        priority: Option<String>,
     },
 
     /// List all projects
@@ -104,6 +302,14 @@ pub enum ProjectCommands {
         /// Filter by priority
         #[arg(short, long)]
         priority: Option<String>,
+
+        /// Print only the number of matching projects
+        #[arg(long)]
+        count: bool,
+
+        /// Print the matching projects as JSON
+        #[arg(long, conflicts_with = "count")]
+        json: bool,
     },
 
     /// Show project details
@@ -125,6 +331,15 @@ pub enum ProjectCommands {
         #[arg(long)]
         priority: Option<String>,
     },
+
+    /// Rename a project and update references in its milestones and issues
+    Rename {
+        /// Current project name or ID
+        old: String,
+
+        /// New project name
+        new: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -137,15 +352,40 @@ pub enum MilestoneCommands {
         /// Milestone title
         title: String,
 
-        /// Target date (YYYY-MM-DD)
+        /// Target date (YYYY-MM-DD, or relative: today, tomorrow, +7d, +2w, next-friday)
         #[arg(short, long)]
         date: Option<String>,
+
+        /// Initial status; defaults to [defaults] status in config
+        #[arg(short, long)]
+        status: Option<String>,
+
+        /// Optional priority (low, medium, high, urgent), used when sorting milestones
+        #[arg(short, long)]
+        priority: Option<String>,
+
+        /// Create a series of milestones spaced this far apart (month or quarter),
+        /// starting at --date. Use {n} in the title for the position in the series
+        #[arg(long, value_parser = ["month", "quarter"], requires_all = ["date", "count"])]
+        every: Option<String>,
+
+        /// How many milestones to create with --every
+        #[arg(long, requires = "every")]
+        count: Option<u32>,
     },
 
     /// List milestones
     List {
         /// Optional project name to filter by
         project: Option<String>,
+
+        /// Print only the number of matching milestones
+        #[arg(long)]
+        count: bool,
+
+        /// Print the matching milestones as JSON
+        #[arg(long, conflicts_with = "count")]
+        json: bool,
     },
 
     /// Edit milestone metadata
@@ -160,9 +400,30 @@ pub enum MilestoneCommands {
         #[arg(long)]
         status: Option<String>,
 
-        /// Update target date
+        /// Update target date (YYYY-MM-DD or relative like +2w; empty to clear)
         #[arg(long)]
         date: Option<String>,
+
+        /// Rename the milestone (also updates issues that reference it)
+        #[arg(long = "title")]
+        new_title: Option<String>,
+    },
+
+    /// Show remaining open issues per day for a milestone
+    Burndown {
+        /// Project name
+        project: String,
+
+        /// Milestone title
+        title: String,
+
+        /// Output format (csv or json)
+        #[arg(short, long, default_value = "csv")]
+        format: String,
+
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<String>,
     },
 }
 
@@ -170,15 +431,24 @@ pub enum MilestoneCommands {
 pub enum IssueCommands {
     /// Create a new issue
     Create {
-        /// Project name
+        /// Project name (with --inbox, the issue title)
         project: String,
 
         /// Issue title
-        title: String,
+        #[arg(required_unless_present = "inbox")]
+        title: Option<String>,
+
+        /// Put the issue in the workspace inbox, to file under a project later
+        #[arg(long, conflicts_with = "title")]
+        inbox: bool,
+
+        /// Priority (low, medium, high, urgent); defaults to [defaults] priority in config
+        #[arg(short, long)]
+        priority: Option<String>,
 
-        /// Priority (low, medium, high, urgent)
-        #[arg(short, long, default_value = "medium")]
-        priority: String,
+        /// Severity, the technical impact (trivial, minor, major, critical)
+        #[arg(long)]
+        severity: Option<String>,
 
         /// Milestone to assign to
         #[arg(short, long)]
@@ -187,6 +457,14 @@ pub enum IssueCommands {
         /// Tags (comma-separated)
         #[arg(short, long)]
         tags: Option<String>,
+
+        /// Named template to use (.pillar/templates/issue.<name>.md)
+        #[arg(long)]
+        template: Option<String>,
+
+        /// Link to an external ticket this issue mirrors
+        #[arg(long)]
+        url: Option<String>,
     },
 
     /// List issues
@@ -199,6 +477,10 @@ pub enum IssueCommands {
         #[arg(short, long)]
         priority: Option<String>,
 
+        /// Filter by severity
+        #[arg(long)]
+        severity: Option<String>,
+
         /// Filter by project
         #[arg(short = 'P', long)]
         project: Option<String>,
@@ -210,17 +492,99 @@ pub enum IssueCommands {
         /// Filter by tag
         #[arg(short, long)]
         tag: Option<String>,
+
+        /// Filter by assignee
+        #[arg(short, long)]
+        assignee: Option<String>,
+
+        /// Only issues assigned to you (your git user.name, or $USER)
+        #[arg(long, conflicts_with_all = ["assignee", "unassigned"])]
+        me: bool,
+
+        /// Only issues with no assignee
+        #[arg(long, conflicts_with = "assignee")]
+        unassigned: bool,
+
+        /// Only issues updated on or after this date (YYYY-MM-DD, relative like yesterday, or RFC3339)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only issues updated on or before this date (YYYY-MM-DD, relative like yesterday, or RFC3339)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Only issues updated strictly after this RFC3339 timestamp, oldest update first
+        #[arg(long, conflicts_with = "sort")]
+        updated_after: Option<String>,
+
+        /// Order: priority (then title), or score, which also weighs how old an issue is
+        #[arg(long, default_value = "priority", value_parser = ["priority", "score"])]
+        sort: String,
+
+        /// Print only the number of matching issues
+        #[arg(long)]
+        count: bool,
+
+        /// Print an aligned table (ID, title, status, priority, milestone)
+        #[arg(long)]
+        table: bool,
+
+        /// Print the matching issues as JSON
+        #[arg(long, conflicts_with_all = ["count", "table"])]
+        json: bool,
+
+        /// Show checklist progress ("3/5 tasks") under each issue
+        #[arg(long)]
+        show_tasks: bool,
     },
 
     /// Show issue details
     Show {
-        /// Issue ID (e.g., project-name/001)
+        /// Issue ID (e.g., project-name/001, or just 001 inside a project directory)
+        id: String,
+    },
+
+    /// Show the git commit history of an issue
+    History {
+        /// Issue ID (e.g., project-name/001, or just 001 inside a project directory)
+        id: String,
+    },
+
+    /// Check or uncheck an item of the issue's `- [ ]` checklist
+    Task {
+        /// Issue ID (e.g., project-name/001, or just 001 inside a project directory)
         id: String,
+
+        /// Which checklist item, counting from 1
+        index: usize,
+
+        /// Mark the item as done
+        #[arg(long, required_unless_present = "undone", conflicts_with = "undone")]
+        done: bool,
+
+        /// Mark the item as not done
+        #[arg(long)]
+        undone: bool,
+    },
+
+    /// Copy an issue (without its comments) as a new Todo issue in the same project
+    Clone {
+        /// Issue ID (e.g., project-name/001, or just 001 inside a project directory)
+        id: String,
+    },
+
+    /// Move an issue to another project (it gets a new ID there)
+    Move {
+        /// Issue ID (e.g., project-name/001, or just 001 inside a project directory)
+        id: String,
+
+        /// Destination project name or ID
+        project: String,
     },
 
     /// Edit issue metadata
     Edit {
-        /// Issue ID (e.g., project-name/001)
+        /// Issue ID (e.g., project-name/001, or just 001 inside a project directory)
         id: String,
 
         /// Update status
@@ -231,6 +595,10 @@ pub enum IssueCommands {
         #[arg(long)]
         priority: Option<String>,
 
+        /// Update severity (empty to clear)
+        #[arg(long)]
+        severity: Option<String>,
+
         /// Update milestone
         #[arg(long)]
         milestone: Option<String>,
@@ -238,6 +606,60 @@ pub enum IssueCommands {
         /// Update tags (comma-separated, replaces existing)
         #[arg(long)]
         tags: Option<String>,
+
+        /// Update the external ticket link (empty to clear)
+        #[arg(long)]
+        url: Option<String>,
+
+        /// Start the issue even if the project is at its in-progress limit
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TagCommands {
+    /// List all tags with the number of issues using each
+    List,
+
+    /// Rename a tag on every issue
+    Rename {
+        /// Current tag name
+        old: String,
+
+        /// New tag name
+        new: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ImportCommands {
+    /// Create issues from a CSV file with the columns produced by CSV export
+    Csv {
+        /// Path to the CSV file
+        file: String,
+
+        /// Project to create the issues in
+        #[arg(short = 'P', long)]
+        project: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Print a setting (e.g. defaults.priority)
+    Get {
+        /// Dotted key, such as workspace.base_directory or git.auto_commit
+        key: String,
+    },
+
+    /// Change a setting after checking the value is valid
+    Set {
+        /// Dotted key, such as workspace.base_directory or git.auto_commit
+        key: String,
+
+        /// New value
+        value: String,
     },
 }
 
@@ -265,10 +687,50 @@ pub enum CommentCommands {
         #[arg(value_parser = ["project", "milestone", "issue"])]
         entity_type: String,
 
-        /// Project name
-        project: String,
+        /// Project name; may be left out when run inside the project's directory
+        project: Option<String>,
 
         /// Milestone title or issue ID (not needed for projects)
         identifier: Option<String>,
     },
+
+    /// List comments across all projects, milestones, and issues
+    ListAll {
+        /// Only show comments at or after this date (YYYY-MM-DD, relative like yesterday, or RFC3339)
+        #[arg(long)]
+        since: Option<String>,
+    },
+
+    /// List every comment that @mentions a user
+    Mentions {
+        /// Username to look for, with or without the leading '@'
+        username: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pages(args: &[&str]) -> bool {
+        let cli = Cli::try_parse_from(std::iter::once("pillar").chain(args.iter().copied()))
+            .expect("arguments should parse");
+        cli.command.pages_output()
+    }
+
+    #[test]
+    fn test_pages_output_only_for_listings() {
+        assert!(pages(&["issue", "list"]));
+        assert!(pages(&["issue", "show", "proj/001"]));
+        assert!(pages(&["project", "list"]));
+        assert!(pages(&["comment", "list-all"]));
+        assert!(pages(&["status"]));
+        assert!(pages(&["board"]));
+
+        assert!(!pages(&["status", "--watch"]));
+        assert!(!pages(&["status", "--porcelain"]));
+        assert!(!pages(&["issue", "create", "proj", "Title"]));
+        assert!(!pages(&["project", "create", "proj"]));
+        assert!(!pages(&["init"]));
+    }
 }
@@ -1,63 +1,30 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use std::fs;
-
-use crate::fs::{find_project, get_author, get_base_directory};
-use crate::models::Comment;
-use crate::parser::{read_comments, write_comments};
+use std::path::{Path, PathBuf};
+
+use crate::dates::parse_since;
+use crate::display::success;
+use crate::fs::{
+    extract_issue_id, find_project, get_author, get_base_directory, infer_project, list_issues,
+    list_milestones, list_projects, record_activity,
+};
+use crate::models::{Comment, Project};
+use crate::parser::{read_comments, split_frontmatter, write_comments, write_with_frontmatter};
 use walkdir::WalkDir;
 
-/// Add a comment to a project, milestone, or issue
+/// Add a comment to a project, milestone, or issue, returning the new comment
 pub fn add(
     entity_type: &str,
     project_name: &str,
     identifier: Option<&str>,
     content: &str,
-) -> Result<()> {
+) -> Result<Comment> {
     let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
     let project = find_project(&base_dir, project_name)?;
 
-    // Determine the file path based on entity type
-    let file_path = match entity_type {
-        "project" => project.path.join("README.md"),
-        "milestone" => {
-            let milestone_title =
-                identifier.ok_or_else(|| anyhow::anyhow!("Milestone title required"))?;
-            project
-                .path
-                .join("milestones")
-                .join(format!("{}.md", milestone_title))
-        }
-        "issue" => {
-            let issue_id = identifier.ok_or_else(|| anyhow::anyhow!("Issue ID required"))?;
-
-            // Find the issue file by ID (files are named like "001-title.md")
-            let issues_dir = project.path.join("issues");
-            let mut found_path = None;
-
-            for entry in WalkDir::new(&issues_dir)
-                .max_depth(1)
-                .into_iter()
-                .filter_map(|e| e.ok())
-            {
-                let path = entry.path();
-                if path.is_file() {
-                    if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
-                        // Check if filename starts with the issue ID (with or without leading zeros)
-                        let padded_id = format!("{:03}", issue_id.parse::<usize>().unwrap_or(0));
-                        if filename.starts_with(&format!("{}-", padded_id))
-                            || filename.starts_with(&format!("{}-", issue_id))
-                        {
-                            found_path = Some(path.to_path_buf());
-                            break;
-                        }
-                    }
-                }
-            }
-
-            found_path.ok_or_else(|| anyhow::anyhow!("Issue {} not found", issue_id))?
-        }
-        _ => return Err(anyhow::anyhow!("Invalid entity type: {}", entity_type)),
-    };
+    let file_path = comment_path(&project, entity_type, identifier)?;
 
     if !file_path.exists() {
         return Err(anyhow::anyhow!("{} does not exist", entity_type));
@@ -66,55 +33,76 @@ pub fn add(
     // Read the file
     let file_content = fs::read_to_string(&file_path)?;
 
-    // Split frontmatter and body
-    let body_start = if let Some(end_pos) = file_content[3..].find("\n---\n") {
-        end_pos + 7 // Position after "\n---\n"
-    } else {
-        return Err(anyhow::anyhow!("Invalid file format"));
-    };
+    // Split frontmatter and body the same way the readers do, so hand-edited files
+    // (CRLF line endings, no trailing newline) are accepted too
+    let (frontmatter, body) = split_frontmatter(&file_content)
+        .with_context(|| format!("Invalid file format in {}", file_path.display()))?;
 
-    let frontmatter = &file_content[..body_start];
-    let body = file_content[body_start..].trim();
+    // Parse the frontmatter generically so fields this version doesn't know about survive
+    let mut frontmatter: serde_yaml::Value =
+        serde_yaml::from_str(frontmatter).context("Failed to parse frontmatter YAML")?;
+    let body = body.trim();
 
     // Read existing comments
     let mut comments = read_comments(body);
 
-    // Create and add new comment
+    // Create and add new comment, keeping the thread in chronological order
+    // even if earlier comments were hand-edited out of order
     let author = get_author();
     let new_comment = Comment::new(author.clone(), content.to_string());
-    comments.push(new_comment);
+    comments.push(new_comment.clone());
+    sort_comments(&mut comments);
+
+    // A new comment counts as a modification of the entity
+    if let serde_yaml::Value::Mapping(fields) = &mut frontmatter {
+        fields.insert("updated".into(), serde_yaml::to_value(Utc::now())?);
+    }
 
     // Write back with updated comments
     let updated_body = write_comments(body, &comments);
-    let updated_content = format!("{}\n\n{}", frontmatter.trim(), updated_body);
+    write_with_frontmatter(&file_path, &frontmatter, &updated_body)?;
 
-    fs::write(&file_path, updated_content)?;
+    let entity = match identifier {
+        Some(id) if entity_type != "project" => format!("{}/{}", project.metadata.name, id),
+        _ => project.metadata.name.clone(),
+    };
+    record_activity("comment", entity_type, &entity, Vec::new());
 
-    println!(
+    success(&format!(
         "✓ Added comment by {} to {} '{}'",
         author,
         entity_type,
         identifier.unwrap_or(project_name)
-    );
+    ));
 
-    Ok(())
+    Ok(new_comment)
 }
 
-/// List comments on a project, milestone, or issue
-pub fn list(entity_type: &str, project_name: &str, identifier: Option<&str>) -> Result<()> {
+/// The file holding comments for a project, milestone, or issue
+/// Whether the project, milestone, or issue a comment is addressed to exists.
+/// Errors only when the workspace itself can't be read.
+pub(crate) fn target_exists(
+    entity_type: &str,
+    project_name: &str,
+    identifier: Option<&str>,
+) -> Result<bool> {
     let base_dir = get_base_directory()?;
-    let project = find_project(&base_dir, project_name)?;
+    let Ok(project) = find_project(&base_dir, project_name) else {
+        return Ok(false);
+    };
+    Ok(comment_path(&project, entity_type, identifier).is_ok_and(|path| path.exists()))
+}
 
-    // Determine the file path based on entity type
-    let file_path = match entity_type {
-        "project" => project.path.join("README.md"),
+fn comment_path(project: &Project, entity_type: &str, identifier: Option<&str>) -> Result<PathBuf> {
+    match entity_type {
+        "project" => Ok(project.path.join("README.md")),
         "milestone" => {
             let milestone_title =
                 identifier.ok_or_else(|| anyhow::anyhow!("Milestone title required"))?;
-            project
+            Ok(project
                 .path
                 .join("milestones")
-                .join(format!("{}.md", milestone_title))
+                .join(format!("{}.md", milestone_title)))
         }
         "issue" => {
             let issue_id = identifier.ok_or_else(|| anyhow::anyhow!("Issue ID required"))?;
@@ -143,10 +131,22 @@ pub fn list(entity_type: &str, project_name: &str, identifier: Option<&str>) ->
                 }
             }
 
-            found_path.ok_or_else(|| anyhow::anyhow!("Issue {} not found", issue_id))?
+            found_path.ok_or_else(|| anyhow::anyhow!("Issue {} not found", issue_id))
         }
-        _ => return Err(anyhow::anyhow!("Invalid entity type: {}", entity_type)),
-    };
+        _ => Err(anyhow::anyhow!("Invalid entity type: {}", entity_type)),
+    }
+}
+
+/// Comments on a project, milestone, or issue, oldest first
+pub fn list_comments(
+    entity_type: &str,
+    project_name: &str,
+    identifier: Option<&str>,
+) -> Result<Vec<Comment>> {
+    let base_dir = get_base_directory()?;
+    let project = find_project(&base_dir, project_name)?;
+
+    let file_path = comment_path(&project, entity_type, identifier)?;
 
     if !file_path.exists() {
         return Err(anyhow::anyhow!("{} does not exist", entity_type));
@@ -156,16 +156,47 @@ pub fn list(entity_type: &str, project_name: &str, identifier: Option<&str>) ->
     let file_content = fs::read_to_string(&file_path)?;
 
     // Extract body (skip frontmatter)
-    let body_start = if let Some(end_pos) = file_content[3..].find("\n---\n") {
-        end_pos + 7
-    } else {
-        return Err(anyhow::anyhow!("Invalid file format"));
-    };
+    let (_, body) = split_frontmatter(&file_content)
+        .with_context(|| format!("Invalid file format in {}", file_path.display()))?;
 
-    let body = file_content[body_start..].trim();
+    let mut comments = read_comments(body.trim());
+    sort_comments(&mut comments);
+    Ok(comments)
+}
 
-    // Read comments
-    let comments = read_comments(body);
+/// Work out which project and entity a `comment list` refers to. Inside a project
+/// directory the project may be left out: `comment list issue 001` then means issue 001
+/// of that project. Outside one, a lone argument is the project as before.
+fn resolve_target<'a>(
+    base_dir: &Path,
+    entity_type: &str,
+    project: Option<&'a str>,
+    identifier: Option<&'a str>,
+) -> Result<(String, Option<&'a str>)> {
+    let inferred = || infer_project(base_dir);
+
+    match (project, identifier) {
+        (Some(project), Some(identifier)) => Ok((project.to_string(), Some(identifier))),
+        (Some(only), None) if entity_type != "project" => match inferred() {
+            Some(project) => Ok((project, Some(only))),
+            None => Ok((only.to_string(), None)),
+        },
+        (Some(project), None) => Ok((project.to_string(), None)),
+        (None, _) => inferred().map(|p| (p, None)).ok_or_else(|| {
+            anyhow::anyhow!(
+                "No project given, and the current directory is not inside a project. Pass the project name"
+            )
+        }),
+    }
+}
+
+/// List comments on a project, milestone, or issue
+/// The project may be omitted when running inside its directory
+pub fn list(entity_type: &str, project: Option<&str>, identifier: Option<&str>) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let (project_name, identifier) = resolve_target(&base_dir, entity_type, project, identifier)?;
+    let project_name = project_name.as_str();
+    let comments = list_comments(entity_type, project_name, identifier)?;
 
     if comments.is_empty() {
         println!(
@@ -190,6 +221,127 @@ pub fn list(entity_type: &str, project_name: &str, identifier: Option<&str>) ->
     Ok(())
 }
 
+/// List comments across every project, milestone, and issue, oldest first
+pub fn list_all(since: Option<&str>) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let since = since.map(parse_since).transpose()?;
+
+    let comments = collect_all_comments(&base_dir, since)?;
+
+    if comments.is_empty() {
+        println!("No comments found.");
+        return Ok(());
+    }
+
+    println!("Comments across workspace:\n");
+
+    for (entity, comment) in comments {
+        println!("[{}] - {} on {}", comment.timestamp, comment.author, entity);
+        println!("{}\n", comment.content);
+    }
+
+    Ok(())
+}
+
+/// List every comment that mentions `username`, oldest first
+pub fn mentions(username: &str) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let username = username.trim_start_matches('@');
+
+    let comments = collect_mentions(&base_dir, username)?;
+
+    if comments.is_empty() {
+        println!("No comments mention @{}", username);
+        return Ok(());
+    }
+
+    println!("Comments mentioning @{}:\n", username);
+
+    for (entity, comment) in comments {
+        println!("[{}] - {} on {}", comment.timestamp, comment.author, entity);
+        println!("{}\n", comment.content);
+    }
+
+    Ok(())
+}
+
+/// Every comment in the workspace whose mentions include `username` (case-insensitive)
+fn collect_mentions(base_dir: &Path, username: &str) -> Result<Vec<(String, Comment)>> {
+    let mut comments = collect_all_comments(base_dir, None)?;
+    comments.retain(|(_, c)| c.mentions.iter().any(|m| m.eq_ignore_ascii_case(username)));
+    Ok(comments)
+}
+
+/// Gather every comment in the workspace with a label for its owning entity,
+/// sorted by timestamp and optionally limited to comments at or after `since`
+fn collect_all_comments(
+    base_dir: &Path,
+    since: Option<DateTime<Utc>>,
+) -> Result<Vec<(String, Comment)>> {
+    let mut all = Vec::new();
+
+    for project in list_projects(base_dir)? {
+        let name = &project.metadata.name;
+
+        for comment in read_comments(&project.description) {
+            all.push((format!("project {}", name), comment));
+        }
+
+        for milestone in list_milestones(&project.path)? {
+            for comment in read_comments(&milestone.description) {
+                all.push((
+                    format!("milestone {} / {}", name, milestone.metadata.title),
+                    comment,
+                ));
+            }
+        }
+
+        for issue in list_issues(&project.path)? {
+            for comment in read_comments(&issue.description) {
+                all.push((
+                    format!(
+                        "issue {}/{} ({})",
+                        name,
+                        extract_issue_id(&issue.path),
+                        issue.metadata.title
+                    ),
+                    comment,
+                ));
+            }
+        }
+    }
+
+    if let Some(since) = since {
+        all.retain(|(_, c)| comment_time(c).is_some_and(|t| t >= since));
+    }
+
+    all.sort_by(|(_, a), (_, b)| compare_comments(a, b));
+
+    Ok(all)
+}
+
+/// Sort comments chronologically; the sort is stable, so ties keep their file order
+fn sort_comments(comments: &mut [Comment]) {
+    comments.sort_by(compare_comments);
+}
+
+/// Order comments by parsed timestamp; unparseable timestamps sort after every valid one
+fn compare_comments(a: &Comment, b: &Comment) -> std::cmp::Ordering {
+    match (comment_time(a), comment_time(b)) {
+        (Some(x), Some(y)) => x.cmp(&y),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.timestamp.cmp(&b.timestamp),
+    }
+}
+
+/// Parse a comment's RFC3339 timestamp
+fn comment_time(comment: &Comment) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&comment.timestamp)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,8 +357,8 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        init(None)?;
-        project::create_project("TestProject", None, "medium")?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
 
         let result = add("project", "TestProject", None, "This is a test comment");
 
@@ -223,21 +375,81 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_comments_kept_in_timestamp_order() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let readme_path = temp_dir.path().join("TestProject/README.md");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
+        let first = add("project", "TestProject", None, "First comment");
+        env::set_current_dir(&original_dir)?;
+        first?;
+
+        // Hand-edit an older comment in after the newer one
+        let content = fs::read_to_string(&readme_path)?;
+        fs::write(
+            &readme_path,
+            format!(
+                "{}\n\n### [2020-01-01T00:00:00Z] - Old\nHand-edited comment\n",
+                content.trim_end()
+            ),
+        )?;
+
+        let mut listed =
+            read_comments(crate::parser::split_frontmatter(&fs::read_to_string(&readme_path)?)?.1);
+        assert_eq!(listed[0].content, "First comment");
+        sort_comments(&mut listed);
+        assert_eq!(listed[0].content, "Hand-edited comment");
+
+        env::set_current_dir(temp_dir.path())?;
+        let second = add("project", "TestProject", None, "Latest comment");
+        let listing = list("project", Some("TestProject"), None);
+        env::set_current_dir(&original_dir)?;
+        second?;
+        listing?;
+
+        let content = fs::read_to_string(&readme_path)?;
+        let (_, body) = crate::parser::split_frontmatter(&content)?;
+        let order: Vec<String> = read_comments(body).into_iter().map(|c| c.content).collect();
+        assert_eq!(
+            order,
+            vec!["Hand-edited comment", "First comment", "Latest comment"]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_add_comment_to_issue() -> Result<()> {
         let temp_dir = TempDir::new()?;
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        init(None)?;
-        project::create_project("TestProject", None, "medium")?;
-        issue::create_issue("TestProject", "Test Issue", "medium", None, None)?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
+        issue::create_issue(
+            "TestProject",
+            "Test Issue",
+            &issue::NewIssue {
+                priority: Some("medium"),
+                ..Default::default()
+            },
+        )?;
 
         let result = add("issue", "TestProject", Some("1"), "Issue comment");
+        let listed = list_comments("issue", "TestProject", Some("001"));
 
         env::set_current_dir(&original_dir)?;
 
-        result?;
+        let added = result?;
+        assert_eq!(added.content, "Issue comment");
+        let listed = listed?;
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].timestamp, added.timestamp);
+        assert_eq!(listed[0].content, added.content);
 
         // Verify comment was added
         // Issue filename is sanitized: "Test Issue" becomes "001-test-issue.md"
@@ -249,16 +461,98 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_add_comment_to_minimally_formatted_file() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let issues_dir = temp_dir.path().join("TestProject/issues");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
+        fs::create_dir_all(&issues_dir)?;
+        fs::write(
+            issues_dir.join("001-crlf.md"),
+            "---\r\ntitle: Hand written\r\nstatus: todo\r\npriority: low\r\n---\r\nNo blank line here",
+        )?;
+        fs::write(
+            issues_dir.join("002-bare.md"),
+            "---\ntitle: Bare\nstatus: todo\npriority: low\n---",
+        )?;
+
+        let crlf = add("issue", "TestProject", Some("1"), "Works with CRLF");
+        let bare = add("issue", "TestProject", Some("2"), "Works without a body");
+        env::set_current_dir(&original_dir)?;
+
+        crlf?;
+        bare?;
+
+        let first = crate::parser::read_issue(issues_dir.join("001-crlf.md"))?;
+        assert_eq!(first.metadata.title, "Hand written");
+        assert!(first.description.starts_with("No blank line here"));
+        assert_eq!(
+            read_comments(&first.description)[0].content,
+            "Works with CRLF"
+        );
+
+        let second = crate::parser::read_issue(issues_dir.join("002-bare.md"))?;
+        assert_eq!(second.metadata.title, "Bare");
+        assert_eq!(
+            read_comments(&second.description)[0].content,
+            "Works without a body"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_comment_bumps_updated() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let issue_path = temp_dir.path().join("TestProject/issues/001-test-issue.md");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
+        issue::create_issue(
+            "TestProject",
+            "Test Issue",
+            &issue::NewIssue {
+                tags: Some("ui"),
+                ..Default::default()
+            },
+        )?;
+
+        // Age the issue so the bump is unambiguous
+        let mut issue = crate::parser::read_issue(&issue_path)?;
+        let old = Utc::now() - chrono::Duration::days(3);
+        issue.metadata.updated = Some(old);
+        write_with_frontmatter(&issue_path, &issue.metadata, &issue.description)?;
+
+        let result = add("issue", "TestProject", Some("1"), "Still relevant");
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let issue = crate::parser::read_issue(&issue_path)?;
+        assert!(issue.metadata.updated.unwrap() > old + chrono::Duration::days(2));
+        assert_eq!(issue.metadata.title, "Test Issue");
+        assert_eq!(issue.metadata.tags, vec!["ui"]);
+        assert_eq!(read_comments(&issue.description).len(), 1);
+
+        Ok(())
+    }
+
     #[test]
     fn test_list_comments_empty() -> Result<()> {
         let temp_dir = TempDir::new()?;
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        init(None)?;
-        project::create_project("TestProject", None, "medium")?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
 
-        let result = list("project", "TestProject", None);
+        let result = list("project", Some("TestProject"), None);
 
         env::set_current_dir(&original_dir)?;
 
@@ -267,18 +561,130 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_collect_all_comments_sorted_and_filtered() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
+        issue::create_issue(
+            "TestProject",
+            "Test Issue",
+            &issue::NewIssue {
+                priority: Some("medium"),
+                ..Default::default()
+            },
+        )?;
+        env::set_current_dir(&original_dir)?;
+
+        let project_readme = temp_dir.path().join("TestProject/README.md");
+        let issue_file = temp_dir.path().join("TestProject/issues/001-test-issue.md");
+        let append = |path: &Path, text: &str| -> Result<()> {
+            let content = fs::read_to_string(path)?;
+            fs::write(path, format!("{}\n{}", content, text))?;
+            Ok(())
+        };
+        append(
+            &project_readme,
+            "\n## Comments\n\n### [2026-03-02T09:00:00Z] - Bob\nLater project note\n",
+        )?;
+        append(
+            &issue_file,
+            "\n## Comments\n\n### [2026-03-01T09:00:00Z] - Alice\nEarlier issue note\n",
+        )?;
+
+        let all = collect_all_comments(temp_dir.path(), None)?;
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].1.author, "Alice");
+        assert_eq!(all[0].0, "issue TestProject/001 (Test Issue)");
+        assert_eq!(all[1].1.author, "Bob");
+        assert_eq!(all[1].0, "project TestProject");
+
+        let recent = collect_all_comments(temp_dir.path(), Some(parse_since("2026-03-02")?))?;
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].1.author, "Bob");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_mentions() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
+        issue::create_issue("TestProject", "Test Issue", &issue::NewIssue::default())?;
+        add("project", "TestProject", None, "@Alice please review")?;
+        add("issue", "TestProject", Some("1"), "Blocked, cc @bob")?;
+        add(
+            "issue",
+            "TestProject",
+            Some("1"),
+            "Pinging alice@example.com",
+        )?;
+        env::set_current_dir(&original_dir)?;
+
+        let alice = collect_mentions(temp_dir.path(), "alice")?;
+        assert_eq!(alice.len(), 1);
+        assert_eq!(alice[0].0, "project TestProject");
+
+        let bob = collect_mentions(temp_dir.path(), "bob")?;
+        assert_eq!(bob.len(), 1);
+        assert_eq!(bob[0].0, "issue TestProject/001 (Test Issue)");
+
+        assert!(collect_mentions(temp_dir.path(), "carol")?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_infers_project_from_cwd() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
+        issue::create_issue("TestProject", "Test Issue", &issue::NewIssue::default())?;
+        add("issue", "TestProject", Some("1"), "Found it")?;
+
+        env::set_current_dir(temp_dir.path().join("TestProject/issues"))?;
+        let inside_issue = resolve_target(temp_dir.path(), "issue", Some("001"), None);
+        let inside_project = resolve_target(temp_dir.path(), "project", None, None);
+        let listing = list("issue", Some("001"), None);
+        env::set_current_dir(temp_dir.path())?;
+        let outside = resolve_target(temp_dir.path(), "issue", None, None);
+        let explicit = resolve_target(temp_dir.path(), "issue", Some("TestProject"), Some("1"));
+        env::set_current_dir(&original_dir)?;
+
+        assert_eq!(inside_issue?, ("TestProject".to_string(), Some("001")));
+        assert_eq!(inside_project?, ("TestProject".to_string(), None));
+        listing?;
+        assert!(outside
+            .unwrap_err()
+            .to_string()
+            .contains("Pass the project name"));
+        assert_eq!(explicit?, ("TestProject".to_string(), Some("1")));
+
+        Ok(())
+    }
+
     #[test]
     fn test_list_comments_with_data() -> Result<()> {
         let temp_dir = TempDir::new()?;
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        init(None)?;
-        project::create_project("TestProject", None, "medium")?;
+        init(None, false)?;
+        project::create_project("TestProject", None, Some("medium"))?;
         add("project", "TestProject", None, "First comment")?;
         add("project", "TestProject", None, "Second comment")?;
 
-        let result = list("project", "TestProject", None);
+        let result = list("project", Some("TestProject"), None);
 
         env::set_current_dir(&original_dir)?;
 
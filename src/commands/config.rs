@@ -0,0 +1,258 @@
+use anyhow::{Context, Result};
+use std::str::FromStr;
+
+use crate::display::success;
+use crate::fs::{find_workspace_root, read_config, read_config_file, write_config};
+use crate::models::{Config, Priority, Status};
+
+/// Print a single config value, addressed by a dotted key like `defaults.priority`
+pub fn config_get(key: &str) -> Result<()> {
+    let config = read_config(&find_workspace_root()?)?;
+    println!("{}", get_value(&config, key)?);
+    Ok(())
+}
+
+/// Validate and store a config value, addressed by a dotted key like `defaults.priority`
+/// Only the file is changed; environment overrides still take precedence when reading.
+pub fn config_set(key: &str, value: &str) -> Result<()> {
+    let root = find_workspace_root()?;
+    let _lock = crate::fs::lock_workspace()?;
+
+    let config = set_value(read_config_file(&root)?, key, value)?;
+    write_config(&root, &config)?;
+
+    success(&format!("✓ Set {} = {}", key, get_value(&config, key)?));
+
+    Ok(())
+}
+
+/// Whole-number keys that are left out of the file while unset, so there is no existing
+/// value to take the type from. Setting one to "" unsets it again.
+const OPTIONAL_INTEGER_KEYS: [&str; 1] = ["limits.in_progress"];
+
+/// Every leaf key in the config, for error messages
+fn known_keys(config: &Config) -> Result<Vec<String>> {
+    fn collect(prefix: &str, value: &toml::Value, keys: &mut Vec<String>) {
+        match value {
+            toml::Value::Table(table) => {
+                for (name, child) in table {
+                    let key = if prefix.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{}.{}", prefix, name)
+                    };
+                    collect(&key, child, keys);
+                }
+            }
+            _ => keys.push(prefix.to_string()),
+        }
+    }
+
+    let mut keys = Vec::new();
+    collect("", &toml::Value::try_from(config)?, &mut keys);
+    for key in OPTIONAL_INTEGER_KEYS {
+        if !keys.iter().any(|k| k == key) {
+            keys.push(key.to_string());
+        }
+    }
+    Ok(keys)
+}
+
+fn unknown_key(config: &Config, key: &str) -> anyhow::Error {
+    match known_keys(config) {
+        Ok(keys) => anyhow::anyhow!(
+            "Unknown config key '{}'. Available keys: {}",
+            key,
+            keys.join(", ")
+        ),
+        Err(e) => e,
+    }
+}
+
+fn get_value(config: &Config, key: &str) -> Result<String> {
+    let root = toml::Value::try_from(config)?;
+    let mut value = &root;
+    for part in key.split('.') {
+        value = match value.get(part) {
+            Some(child) => child,
+            None if OPTIONAL_INTEGER_KEYS.contains(&key) => return Ok(String::new()),
+            None => return Err(unknown_key(config, key)),
+        };
+    }
+
+    match value {
+        toml::Value::String(s) => Ok(s.clone()),
+        toml::Value::Table(_) => Err(unknown_key(config, key)),
+        other => Ok(other.to_string()),
+    }
+}
+
+/// Return `config` with `key` set to `value`, rejecting values the rest of Pillar can't use
+fn set_value(config: Config, key: &str, value: &str) -> Result<Config> {
+    match key {
+        "defaults.priority" => {
+            Priority::from_str(value)?;
+        }
+        "defaults.status" => {
+            Status::from_str(value)?;
+        }
+        "workspace.base_directory" if value == ".pillar" || value.starts_with(".pillar/") => {
+            return Err(anyhow::anyhow!(
+                "Base directory cannot be '.pillar' or inside '.pillar/'"
+            ));
+        }
+        _ => {}
+    }
+
+    let optional = OPTIONAL_INTEGER_KEYS.contains(&key);
+    let (parent_key, field) = key.rsplit_once('.').unwrap_or(("", key));
+    let mut root = toml::Value::try_from(&config)?;
+    let mut parent = &mut root;
+    for part in parent_key.split('.').filter(|p| !p.is_empty()) {
+        parent = parent
+            .get_mut(part)
+            .ok_or_else(|| unknown_key(&config, key))?;
+    }
+    let table = parent
+        .as_table_mut()
+        .ok_or_else(|| unknown_key(&config, key))?;
+
+    if optional && value.is_empty() {
+        table.remove(field);
+        return root
+            .try_into()
+            .with_context(|| format!("Invalid value for {}: {}", key, value));
+    }
+
+    let current = match table.get(field) {
+        Some(current) => current.clone(),
+        None if optional => toml::Value::Integer(0),
+        None => return Err(unknown_key(&config, key)),
+    };
+    let new_value = match current {
+        toml::Value::String(_) => toml::Value::String(value.to_string()),
+        toml::Value::Boolean(_) => {
+            let flag = value.parse().map_err(|_| {
+                anyhow::anyhow!("Invalid value for {}: expected true or false", key)
+            })?;
+            toml::Value::Boolean(flag)
+        }
+        toml::Value::Float(_) => {
+            let number = value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid value for {}: expected a number", key))?;
+            toml::Value::Float(number)
+        }
+        toml::Value::Integer(_) => {
+            let number: u32 = value.parse().map_err(|_| {
+                anyhow::anyhow!("Invalid value for {}: expected a whole number", key)
+            })?;
+            toml::Value::Integer(i64::from(number))
+        }
+        _ => return Err(unknown_key(&config, key)),
+    };
+    table.insert(field.to_string(), new_value);
+
+    root.try_into()
+        .with_context(|| format!("Invalid value for {}: {}", key, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init;
+    use crate::models::StatusStyle;
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_config_set_get_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        let results = [
+            config_set("defaults.priority", "high"),
+            config_set("git.auto_commit", "true"),
+            config_set("workspace.base_directory", "projects"),
+            config_set("display.status_style", "emoji"),
+            config_set("score.age_weight", "0.5"),
+            config_get("defaults.priority"),
+        ];
+        env::set_current_dir(&original_dir)?;
+
+        for result in results {
+            result?;
+        }
+
+        let config = read_config(temp_dir.path())?;
+        assert_eq!(get_value(&config, "defaults.priority")?, "high");
+        assert_eq!(get_value(&config, "git.auto_commit")?, "true");
+        assert_eq!(get_value(&config, "workspace.base_directory")?, "projects");
+        assert_eq!(get_value(&config, "workspace.version")?, "0.1.0");
+        assert!(config.git.auto_commit);
+        assert_eq!(config.display.status_style, StatusStyle::Emoji);
+        assert_eq!(config.score.age_weight, 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_set_optional_limit() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        let unset = read_config(temp_dir.path());
+        let set = config_set("limits.in_progress", "3");
+        let set_config = read_config(temp_dir.path());
+        let bad = config_set("limits.in_progress", "-1");
+        let cleared = config_set("limits.in_progress", "");
+        env::set_current_dir(&original_dir)?;
+
+        assert_eq!(get_value(&unset?, "limits.in_progress")?, "");
+        set?;
+        let set_config = set_config?;
+        assert_eq!(set_config.limits.in_progress, Some(3));
+        assert_eq!(get_value(&set_config, "limits.in_progress")?, "3");
+        assert!(bad.is_err());
+        cleared?;
+        assert_eq!(read_config(temp_dir.path())?.limits.in_progress, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_set_rejects_invalid_values() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        let bad_priority = config_set("defaults.priority", "whenever");
+        let bad_bool = config_set("git.auto_commit", "sometimes");
+        let bad_style = config_set("display.status_style", "sparkly");
+        let bad_weight = config_set("score.urgent", "lots");
+        let bad_key = config_set("defaults.colour", "red");
+        let table_key = config_get("defaults");
+        env::set_current_dir(&original_dir)?;
+
+        assert!(bad_priority.is_err());
+        assert!(bad_bool.is_err());
+        assert!(bad_style.is_err());
+        assert!(bad_weight.is_err());
+        assert!(bad_key
+            .unwrap_err()
+            .to_string()
+            .contains("defaults.priority"));
+        assert!(table_key.is_err());
+
+        let config = read_config(temp_dir.path())?;
+        assert_eq!(config.defaults.priority, "medium");
+        assert!(!config.git.auto_commit);
+
+        Ok(())
+    }
+}
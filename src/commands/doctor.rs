@@ -0,0 +1,374 @@
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::display::success;
+use crate::fs::{
+    extract_issue_id, get_base_directory, list_issues, list_milestones, list_projects,
+    record_activity, ISSUE_ID_WIDTH,
+};
+use crate::models::FieldChange;
+use crate::parser::{split_frontmatter, write_with_frontmatter};
+
+/// A problem `doctor` knows how to repair
+#[derive(Debug, Clone, PartialEq)]
+enum Problem {
+    /// `project:` in a milestone or issue doesn't name the project it is stored in
+    WrongProject {
+        path: PathBuf,
+        /// "milestone" or "issue"
+        kind: &'static str,
+        entity: String,
+        found: Option<String>,
+        expected: String,
+    },
+    /// An issue is assigned to a milestone its project doesn't have
+    MissingMilestone {
+        path: PathBuf,
+        entity: String,
+        milestone: String,
+    },
+    /// An issue file's numeric ID isn't zero-padded to `ISSUE_ID_WIDTH` digits
+    UnpaddedId {
+        path: PathBuf,
+        entity: String,
+        renamed: PathBuf,
+    },
+}
+
+impl Problem {
+    fn describe(&self, base_dir: &Path) -> String {
+        match self {
+            Problem::WrongProject {
+                path,
+                found,
+                expected,
+                ..
+            } => format!(
+                "{}: project is '{}', expected '{}'",
+                relative(base_dir, path),
+                found.as_deref().unwrap_or(""),
+                expected
+            ),
+            Problem::MissingMilestone {
+                path, milestone, ..
+            } => format!(
+                "{}: milestone '{}' does not exist",
+                relative(base_dir, path),
+                milestone
+            ),
+            Problem::UnpaddedId { path, renamed, .. } => format!(
+                "{}: issue ID should be renamed to {}",
+                relative(base_dir, path),
+                renamed.file_name().unwrap_or_default().to_string_lossy()
+            ),
+        }
+    }
+
+    /// Entity kind and identifier for the activity log
+    fn entity(&self) -> (&'static str, &str) {
+        match self {
+            Problem::WrongProject { kind, entity, .. } => (kind, entity),
+            Problem::MissingMilestone { entity, .. } | Problem::UnpaddedId { entity, .. } => {
+                ("issue", entity)
+            }
+        }
+    }
+
+    /// Repair the problem, returning the change to record
+    fn fix(&self) -> Result<FieldChange> {
+        match self {
+            Problem::WrongProject {
+                path,
+                found,
+                expected,
+                ..
+            } => {
+                set_field(path, "project", Some(expected))?;
+                Ok(FieldChange::new(
+                    "project",
+                    found.clone(),
+                    Some(expected.clone()),
+                ))
+            }
+            Problem::MissingMilestone {
+                path, milestone, ..
+            } => {
+                set_field(path, "milestone", None)?;
+                Ok(FieldChange::new("milestone", Some(milestone.clone()), None))
+            }
+            Problem::UnpaddedId { path, renamed, .. } => {
+                fs::rename(path, renamed)
+                    .with_context(|| format!("Failed to rename {}", path.display()))?;
+                crate::fs::mark_touched(path);
+                crate::fs::mark_touched(renamed);
+                Ok(FieldChange::new(
+                    "id",
+                    Some(extract_issue_id(path)),
+                    Some(extract_issue_id(renamed)),
+                ))
+            }
+        }
+    }
+}
+
+/// Check the workspace for mismatched `project:` fields, issues pointing at deleted
+/// milestones, and unpadded issue IDs. Only reports them unless `fix` is set.
+pub fn doctor(fix: bool) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let _lock = if fix {
+        Some(crate::fs::lock_workspace()?)
+    } else {
+        None
+    };
+
+    let problems = diagnose(&base_dir)?;
+    if problems.is_empty() {
+        println!("No problems found.");
+        return Ok(());
+    }
+
+    if !fix {
+        for problem in &problems {
+            println!("{}", problem.describe(&base_dir));
+        }
+        println!(
+            "\nFound {} problem(s). Run `pillar doctor --fix` to repair them.",
+            problems.len()
+        );
+        return Ok(());
+    }
+
+    for problem in &problems {
+        let change = problem.fix()?;
+        let (kind, entity) = problem.entity();
+        record_activity("fix", kind, entity, vec![change]);
+        success(&format!("✓ Fixed {}", problem.describe(&base_dir)));
+    }
+    success(&format!("✓ Repaired {} problem(s)", problems.len()));
+
+    Ok(())
+}
+
+/// Every repairable problem, with renames last so field fixes still find their files
+fn diagnose(base_dir: &Path) -> Result<Vec<Problem>> {
+    let mut problems = Vec::new();
+    let mut renames = Vec::new();
+
+    for project in list_projects(base_dir)? {
+        let expected = project.metadata.name.clone();
+        let milestones = list_milestones(&project.path)?;
+
+        for milestone in &milestones {
+            if milestone.metadata.project.as_deref() != Some(expected.as_str()) {
+                problems.push(Problem::WrongProject {
+                    path: milestone.path.clone(),
+                    kind: "milestone",
+                    entity: format!("{}/{}", expected, milestone.metadata.title),
+                    found: milestone.metadata.project.clone(),
+                    expected: expected.clone(),
+                });
+            }
+        }
+
+        let issues = list_issues(&project.path)?;
+        let mut claimed: HashSet<String> =
+            issues.iter().map(|i| extract_issue_id(&i.path)).collect();
+        for issue in &issues {
+            let entity = format!("{}/{}", expected, extract_issue_id(&issue.path));
+            if issue.metadata.project.as_deref() != Some(expected.as_str()) {
+                problems.push(Problem::WrongProject {
+                    path: issue.path.clone(),
+                    kind: "issue",
+                    entity: entity.clone(),
+                    found: issue.metadata.project.clone(),
+                    expected: expected.clone(),
+                });
+            }
+
+            if let Some(title) = &issue.metadata.milestone {
+                let lowered = title.to_lowercase();
+                if !milestones
+                    .iter()
+                    .any(|m| m.metadata.title.to_lowercase() == lowered)
+                {
+                    problems.push(Problem::MissingMilestone {
+                        path: issue.path.clone(),
+                        entity: entity.clone(),
+                        milestone: title.clone(),
+                    });
+                }
+            }
+
+            if let Some(renamed) = padded_path(&issue.path) {
+                // Leave the file alone if another issue already has (or will get) that ID
+                if claimed.insert(extract_issue_id(&renamed)) {
+                    renames.push(Problem::UnpaddedId {
+                        path: issue.path.clone(),
+                        entity: entity.clone(),
+                        renamed,
+                    });
+                }
+            }
+        }
+    }
+
+    problems.extend(renames);
+    Ok(problems)
+}
+
+/// Where an issue file with an unpadded numeric ID should live, if it needs renaming
+fn padded_path(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let id = extract_issue_id(path);
+    let number: u32 = id.parse().ok()?;
+    let padded = format!("{:0width$}", number, width = ISSUE_ID_WIDTH);
+    if padded == id {
+        return None;
+    }
+    Some(path.with_file_name(format!("{}{}", padded, &name[id.len()..])))
+}
+
+/// Set or remove one frontmatter field, leaving every other field and the body untouched
+fn set_field(path: &Path, field: &str, value: Option<&str>) -> Result<()> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read file: {}", path.display()))?;
+    let (frontmatter, body) = split_frontmatter(&content)
+        .with_context(|| format!("Invalid file format in {}", path.display()))?;
+
+    let mut frontmatter: serde_yaml::Value =
+        serde_yaml::from_str(frontmatter).context("Failed to parse frontmatter YAML")?;
+    if let serde_yaml::Value::Mapping(fields) = &mut frontmatter {
+        match value {
+            Some(value) => {
+                fields.insert(field.into(), value.into());
+            }
+            None => {
+                fields.remove(field);
+            }
+        }
+    }
+
+    write_with_frontmatter(path, &frontmatter, body)
+}
+
+fn relative(base_dir: &Path, path: &Path) -> String {
+    path.strip_prefix(base_dir)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{create_issue, create_milestone, create_project, init, NewIssue};
+    use crate::parser::{read_issue, read_milestone};
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_doctor_fixes_project_mismatch() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let project_dir = temp_dir.path().join("app");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("app", None, None)?;
+        create_milestone("app", "v1", None, None, None)?;
+        create_issue("app", "Moved by hand", &NewIssue::default())?;
+        env::set_current_dir(&original_dir)?;
+
+        let issue_path = project_dir.join("issues/001-moved-by-hand.md");
+        let milestone_path = project_dir.join("milestones/v1.md");
+        for path in [&issue_path, &milestone_path] {
+            set_field(path, "project", Some("old-home"))?;
+        }
+
+        env::set_current_dir(temp_dir.path())?;
+        let dry_run = doctor(false);
+        let after_dry_run = read_issue(&issue_path)?.metadata.project;
+        let fixed = doctor(true);
+        let problems_left = diagnose(&get_base_directory()?);
+        env::set_current_dir(&original_dir)?;
+
+        dry_run?;
+        assert_eq!(after_dry_run.as_deref(), Some("old-home"));
+        fixed?;
+        assert!(problems_left?.is_empty());
+        assert_eq!(
+            read_issue(&issue_path)?.metadata.project.as_deref(),
+            Some("app")
+        );
+        assert_eq!(
+            read_milestone(&milestone_path)?.metadata.project.as_deref(),
+            Some("app")
+        );
+
+        let fixes: Vec<_> = crate::fs::read_activity(temp_dir.path())?
+            .into_iter()
+            .filter(|e| e.action == "fix")
+            .map(|e| (e.kind, e.entity))
+            .collect();
+        assert_eq!(
+            fixes,
+            [
+                ("milestone".to_string(), "app/v1".to_string()),
+                ("issue".to_string(), "app/001".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_finds_missing_milestones_and_unpadded_ids() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let issues_dir = temp_dir.path().join("app/issues");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("app", None, None)?;
+        create_issue(
+            "app",
+            "Orphaned",
+            &NewIssue {
+                milestone: Some("gone"),
+                ..Default::default()
+            },
+        )?;
+        create_issue("app", "Short", &NewIssue::default())?;
+        env::set_current_dir(&original_dir)?;
+        fs::rename(
+            issues_dir.join("002-short.md"),
+            issues_dir.join("2-short.md"),
+        )?;
+
+        let problems = diagnose(&temp_dir.path().canonicalize()?)?;
+        assert_eq!(problems.len(), 2);
+        assert!(matches!(
+            &problems[0],
+            Problem::MissingMilestone { milestone, .. } if milestone == "gone"
+        ));
+        assert!(matches!(
+            &problems[1],
+            Problem::UnpaddedId { renamed, .. } if renamed.ends_with("002-short.md")
+        ));
+
+        for problem in &problems {
+            problem.fix()?;
+        }
+        assert_eq!(
+            read_issue(issues_dir.join("001-orphaned.md"))?
+                .metadata
+                .milestone,
+            None
+        );
+        assert!(issues_dir.join("002-short.md").exists());
+
+        Ok(())
+    }
+}
@@ -1,54 +1,118 @@
 use anyhow::{Context, Result};
+use chrono::NaiveDate;
 use std::fs::File;
 use std::io::{self, Write};
+use std::path::Path;
 
-use crate::fs::{get_base_directory, list_issues, list_milestones, list_projects};
+use crate::commands::issue::IssueFilter;
+use crate::display::success;
+use crate::fs::{find_project, get_base_directory, list_issues, list_milestones, list_projects};
+use crate::models::{Issue, Milestone, Project, Status};
 
-pub fn export(format: &str, entity_type: &str, output: Option<&str>) -> Result<()> {
+/// Export entities, optionally limited to one project and to entities matching `filter`
+/// Status and priority also apply to projects, and status to milestones
+pub fn export(
+    format: &str,
+    entity_type: &str,
+    output: Option<&str>,
+    project: Option<&str>,
+    filter: &IssueFilter,
+    fields: Option<&str>,
+) -> Result<()> {
     let base_dir = get_base_directory()?;
+    let selection = Selection {
+        base_dir: &base_dir,
+        project,
+        filter,
+    };
 
     match format.to_lowercase().as_str() {
-        "json" => export_json(entity_type, output, &base_dir),
-        "csv" => export_csv(entity_type, output, &base_dir),
+        "json" => {
+            if fields.is_some() {
+                return Err(anyhow::anyhow!("--fields is only supported for CSV export"));
+            }
+            export_json(entity_type, output, &selection)
+        }
+        "csv" => export_csv(entity_type, output, &selection, fields),
+        "mermaid" => {
+            if fields.is_some() {
+                return Err(anyhow::anyhow!("--fields is only supported for CSV export"));
+            }
+            export_mermaid(entity_type, output, &selection)
+        }
         _ => Err(anyhow::anyhow!(
-            "Unsupported format: {}. Use 'json' or 'csv'",
+            "Unsupported format: {}. Use 'json', 'csv', or 'mermaid'",
             format
         )),
     }
 }
 
-fn export_json(entity_type: &str, output: Option<&str>, base_dir: &std::path::Path) -> Result<()> {
+/// Which entities an export covers
+struct Selection<'a> {
+    base_dir: &'a Path,
+    project: Option<&'a str>,
+    filter: &'a IssueFilter,
+}
+
+impl Selection<'_> {
+    fn projects(&self) -> Result<Vec<Project>> {
+        let mut projects = self.scope()?;
+        projects.retain(|p| {
+            self.filter.status.is_none_or(|s| p.metadata.status == s)
+                && self
+                    .filter
+                    .priority
+                    .is_none_or(|pr| p.metadata.priority == pr)
+        });
+        Ok(projects)
+    }
+
+    fn milestones(&self) -> Result<Vec<Milestone>> {
+        let mut all_milestones = Vec::new();
+        for project in self.scope()? {
+            all_milestones.extend(list_milestones(&project.path)?);
+        }
+        all_milestones.retain(|m| self.filter.status.is_none_or(|s| m.metadata.status == s));
+        Ok(all_milestones)
+    }
+
+    fn issues(&self) -> Result<Vec<Issue>> {
+        let mut all_issues = Vec::new();
+        for project in self.scope()? {
+            all_issues.extend(list_issues(&project.path)?);
+        }
+        all_issues.retain(|i| self.filter.matches(i));
+        Ok(all_issues)
+    }
+
+    /// Projects whose children are exported, regardless of the projects' own metadata
+    fn scope(&self) -> Result<Vec<Project>> {
+        match self.project {
+            Some(name) => Ok(vec![find_project(self.base_dir, name)?]),
+            None => list_projects(self.base_dir),
+        }
+    }
+}
+
+fn export_json(entity_type: &str, output: Option<&str>, selection: &Selection) -> Result<()> {
     let json = match entity_type {
         "project" => {
-            let projects = list_projects(base_dir)?;
+            let projects = selection.projects()?;
             serde_json::to_string_pretty(&projects).context("Failed to serialize projects")?
         }
         "milestone" => {
-            let mut all_milestones = Vec::new();
-            for project in list_projects(base_dir)? {
-                let milestones = list_milestones(&project.path)?;
-                all_milestones.extend(milestones);
-            }
+            let all_milestones = selection.milestones()?;
             serde_json::to_string_pretty(&all_milestones)
                 .context("Failed to serialize milestones")?
         }
         "issue" => {
-            let mut all_issues = Vec::new();
-            for project in list_projects(base_dir)? {
-                let issues = list_issues(&project.path)?;
-                all_issues.extend(issues);
-            }
+            let all_issues = selection.issues()?;
             serde_json::to_string_pretty(&all_issues).context("Failed to serialize issues")?
         }
         "all" => {
-            let projects = list_projects(base_dir)?;
-            let mut all_milestones = Vec::new();
-            let mut all_issues = Vec::new();
-
-            for project in &projects {
-                all_milestones.extend(list_milestones(&project.path)?);
-                all_issues.extend(list_issues(&project.path)?);
-            }
+            let projects = selection.projects()?;
+            let all_milestones = selection.milestones()?;
+            let all_issues = selection.issues()?;
 
             let data = serde_json::json!({
                 "projects": projects,
@@ -65,86 +129,118 @@ fn export_json(entity_type: &str, output: Option<&str>, base_dir: &std::path::Pa
     Ok(())
 }
 
-fn export_csv(entity_type: &str, output: Option<&str>, base_dir: &std::path::Path) -> Result<()> {
-    let csv = match entity_type {
+/// Issue columns available to CSV export, in their default order
+const ISSUE_FIELDS: [&str; 8] = [
+    "title",
+    "status",
+    "priority",
+    "project",
+    "milestone",
+    "tags",
+    "created",
+    "updated",
+];
+
+/// Parse a comma-separated `--fields` list, rejecting unknown or repeated names
+fn parse_issue_fields(fields: &str) -> Result<Vec<&str>> {
+    let mut selected: Vec<&str> = Vec::new();
+
+    for field in fields.split(',').map(str::trim) {
+        if !ISSUE_FIELDS.contains(&field) {
+            return Err(anyhow::anyhow!(
+                "Unknown field '{}'. Available fields: {}",
+                field,
+                ISSUE_FIELDS.join(", ")
+            ));
+        }
+        if selected.contains(&field) {
+            return Err(anyhow::anyhow!(
+                "Field '{}' is listed more than once",
+                field
+            ));
+        }
+        selected.push(field);
+    }
+
+    Ok(selected)
+}
+
+/// Value of one issue field as a CSV cell (quoting is left to the CSV writer)
+fn issue_csv_value(issue: &Issue, field: &str) -> String {
+    let m = &issue.metadata;
+
+    match field {
+        "title" => m.title.clone(),
+        "status" => m.status.to_string(),
+        "priority" => m.priority.to_string(),
+        "project" => m.project.clone().unwrap_or_default(),
+        "milestone" => m.milestone.clone().unwrap_or_default(),
+        "tags" => m.tags.join(";"),
+        "created" => m.created.map(|d| d.to_rfc3339()).unwrap_or_default(),
+        "updated" => m.updated.map(|d| d.to_rfc3339()).unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+fn export_csv(
+    entity_type: &str,
+    output: Option<&str>,
+    selection: &Selection,
+    fields: Option<&str>,
+) -> Result<()> {
+    if fields.is_some() && entity_type != "issue" {
+        return Err(anyhow::anyhow!(
+            "--fields is only supported when exporting issues"
+        ));
+    }
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let rfc3339 =
+        |d: Option<chrono::DateTime<chrono::Utc>>| d.map(|d| d.to_rfc3339()).unwrap_or_default();
+
+    match entity_type {
         "project" => {
-            let projects = list_projects(base_dir)?;
-            let mut csv = String::from("name,status,priority,created,updated\n");
-            for p in projects {
-                csv.push_str(&format!(
-                    "\"{}\",{},{},{},{}\n",
-                    p.metadata.name.replace('"', "\"\""),
-                    p.metadata.status,
-                    p.metadata.priority,
-                    p.metadata
-                        .created
-                        .map(|d| d.to_rfc3339())
-                        .unwrap_or_default(),
-                    p.metadata
-                        .updated
-                        .map(|d| d.to_rfc3339())
-                        .unwrap_or_default()
-                ));
+            writer.write_record(["name", "status", "priority", "created", "updated"])?;
+            for p in selection.projects()? {
+                writer.write_record([
+                    p.metadata.name,
+                    p.metadata.status.to_string(),
+                    p.metadata.priority.to_string(),
+                    rfc3339(p.metadata.created),
+                    rfc3339(p.metadata.updated),
+                ])?;
             }
-            csv
         }
         "milestone" => {
-            let mut all_milestones = Vec::new();
-            for project in list_projects(base_dir)? {
-                all_milestones.extend(list_milestones(&project.path)?);
-            }
-
-            let mut csv = String::from("title,status,project,target_date,created,updated\n");
-            for m in all_milestones {
-                csv.push_str(&format!(
-                    "\"{}\",{},\"{}\",{},{},{}\n",
-                    m.metadata.title.replace('"', "\"\""),
-                    m.metadata.status,
-                    m.metadata.project.unwrap_or_default().replace('"', "\"\""),
+            writer.write_record([
+                "title",
+                "status",
+                "project",
+                "target_date",
+                "created",
+                "updated",
+            ])?;
+            for m in selection.milestones()? {
+                writer.write_record([
+                    m.metadata.title,
+                    m.metadata.status.to_string(),
+                    m.metadata.project.unwrap_or_default(),
                     m.metadata.target_date.unwrap_or_default(),
-                    m.metadata
-                        .created
-                        .map(|d| d.to_rfc3339())
-                        .unwrap_or_default(),
-                    m.metadata
-                        .updated
-                        .map(|d| d.to_rfc3339())
-                        .unwrap_or_default()
-                ));
+                    rfc3339(m.metadata.created),
+                    rfc3339(m.metadata.updated),
+                ])?;
             }
-            csv
         }
         "issue" => {
-            let mut all_issues = Vec::new();
-            for project in list_projects(base_dir)? {
-                all_issues.extend(list_issues(&project.path)?);
-            }
+            let columns = match fields {
+                Some(f) => parse_issue_fields(f)?,
+                None => ISSUE_FIELDS.to_vec(),
+            };
 
-            let mut csv =
-                String::from("title,status,priority,project,milestone,tags,created,updated\n");
-            for i in all_issues {
-                csv.push_str(&format!(
-                    "\"{}\",{},{},\"{}\",\"{}\",\"{}\",{},{}\n",
-                    i.metadata.title.replace('"', "\"\""),
-                    i.metadata.status,
-                    i.metadata.priority,
-                    i.metadata.project.unwrap_or_default().replace('"', "\"\""),
-                    i.metadata
-                        .milestone
-                        .unwrap_or_default()
-                        .replace('"', "\"\""),
-                    i.metadata.tags.join(";"),
-                    i.metadata
-                        .created
-                        .map(|d| d.to_rfc3339())
-                        .unwrap_or_default(),
-                    i.metadata
-                        .updated
-                        .map(|d| d.to_rfc3339())
-                        .unwrap_or_default()
-                ));
+            writer.write_record(&columns)?;
+            for i in selection.issues()? {
+                writer.write_record(columns.iter().map(|f| issue_csv_value(&i, f)))?;
             }
-            csv
         }
         "all" => {
             return Err(anyhow::anyhow!(
@@ -152,20 +248,113 @@ fn export_csv(entity_type: &str, output: Option<&str>, base_dir: &std::path::Pat
             ));
         }
         _ => return Err(anyhow::anyhow!("Invalid entity type: {}", entity_type)),
-    };
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| anyhow::anyhow!("Failed to write CSV: {}", e))?;
+    let csv = String::from_utf8(bytes).context("CSV output is not valid UTF-8")?;
 
     write_output(&csv, output)?;
     Ok(())
 }
 
-fn write_output(content: &str, output: Option<&str>) -> Result<()> {
+/// Mermaid `gantt` chart of milestones, one section per project, in a Markdown code fence.
+/// Each bar runs from the earliest `created` date of the milestone's issues (or the
+/// milestone's own `created` date) to its `target_date`; dateless milestones are skipped.
+fn export_mermaid(entity_type: &str, output: Option<&str>, selection: &Selection) -> Result<()> {
+    if entity_type != "milestone" {
+        return Err(anyhow::anyhow!(
+            "Mermaid export only supports milestones. Use --entity-type milestone"
+        ));
+    }
+
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    for project in selection.scope()? {
+        let issues = list_issues(&project.path)?;
+        let mut milestones: Vec<(NaiveDate, Milestone)> = list_milestones(&project.path)?
+            .into_iter()
+            .filter(|m| {
+                selection
+                    .filter
+                    .status
+                    .is_none_or(|s| m.metadata.status == s)
+            })
+            .filter_map(|m| {
+                let end = NaiveDate::parse_from_str(m.metadata.target_date.as_deref()?, "%Y-%m-%d");
+                Some((end.ok()?, m))
+            })
+            .collect();
+        milestones.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then_with(|| a.1.metadata.title.cmp(&b.1.metadata.title))
+        });
+
+        let tasks: Vec<String> = milestones
+            .iter()
+            .map(|(end, m)| {
+                let start = issues
+                    .iter()
+                    .filter(|i| i.metadata.milestone.as_deref() == Some(m.metadata.title.as_str()))
+                    .filter_map(|i| i.metadata.created)
+                    .min()
+                    .or(m.metadata.created)
+                    .map(|t| t.date_naive())
+                    .unwrap_or(*end)
+                    .min(*end);
+
+                let state = match m.metadata.status {
+                    Status::Completed => "done, ",
+                    Status::InProgress => "active, ",
+                    _ => "",
+                };
+                format!(
+                    "    {} :{}{}, {}",
+                    mermaid_text(&m.metadata.title),
+                    state,
+                    start.format("%Y-%m-%d"),
+                    end.format("%Y-%m-%d")
+                )
+            })
+            .collect();
+
+        if !tasks.is_empty() {
+            sections.push((project.metadata.name, tasks));
+        }
+    }
+    sections.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut chart =
+        String::from("```mermaid\ngantt\n    title Milestones\n    dateFormat YYYY-MM-DD\n");
+    for (project, tasks) in sections {
+        chart.push_str(&format!("    section {}\n", mermaid_text(&project)));
+        for task in tasks {
+            chart.push_str(&task);
+            chart.push('\n');
+        }
+    }
+    chart.push_str("```\n");
+
+    write_output(&chart, output)
+}
+
+/// Names can't contain the characters Mermaid uses to separate task fields
+fn mermaid_text(text: &str) -> String {
+    text.replace([':', ';', '#'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Write to the output file if given, otherwise stdout
+pub(crate) fn write_output(content: &str, output: Option<&str>) -> Result<()> {
     match output {
         Some(path) => {
             let mut file = File::create(path)
                 .with_context(|| format!("Failed to create output file: {}", path))?;
             file.write_all(content.as_bytes())
                 .with_context(|| format!("Failed to write to file: {}", path))?;
-            println!("Exported to: {}", path);
+            success(&format!("Exported to: {}", path));
         }
         None => {
             io::stdout()
@@ -189,15 +378,228 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        init(None)?;
+        init(None, false)?;
 
-        crate::commands::project::create_project("TestProject", None, "high")?;
+        crate::commands::project::create_project("TestProject", None, Some("high"))?;
 
-        let result = export("json", "project", None);
+        let result = export("json", "project", None, None, &IssueFilter::default(), None);
 
         env::set_current_dir(&original_dir)?;
 
         assert!(result.is_ok());
         Ok(())
     }
+
+    #[test]
+    fn test_export_only_urgent_issues() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let output = temp_dir.path().join("urgent.csv");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        crate::commands::project::create_project("alpha", None, Some("high"))?;
+        crate::commands::project::create_project("beta", None, Some("high"))?;
+        crate::commands::create_issue(
+            "alpha",
+            "Outage",
+            &crate::commands::NewIssue {
+                priority: Some("urgent"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::create_issue(
+            "alpha",
+            "Typo",
+            &crate::commands::NewIssue {
+                priority: Some("low"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::create_issue(
+            "beta",
+            "Data loss",
+            &crate::commands::NewIssue {
+                priority: Some("urgent"),
+                ..Default::default()
+            },
+        )?;
+
+        let filter = IssueFilter::new(None, Some("urgent"), None, None)?;
+        let result = export(
+            "csv",
+            "issue",
+            Some(output.to_str().unwrap()),
+            Some("alpha"),
+            &filter,
+            None,
+        );
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let csv = std::fs::read_to_string(&output)?;
+        let rows: Vec<&str> = csv.lines().skip(1).collect();
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].starts_with("Outage,backlog,urgent"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_csv_selected_fields() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let output = temp_dir.path().join("issues.csv");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        crate::commands::project::create_project("alpha", None, Some("high"))?;
+        crate::commands::create_issue(
+            "alpha",
+            "Outage",
+            &crate::commands::NewIssue {
+                priority: Some("urgent"),
+                tags: Some("ops"),
+                ..Default::default()
+            },
+        )?;
+
+        let filter = IssueFilter::default();
+        let result = export(
+            "csv",
+            "issue",
+            Some(output.to_str().unwrap()),
+            None,
+            &filter,
+            Some("status, title,tags"),
+        );
+        let unknown = export("csv", "issue", None, None, &filter, Some("title,assignee"));
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+        assert_eq!(
+            std::fs::read_to_string(&output)?,
+            "status,title,tags\nbacklog,Outage,ops\n"
+        );
+
+        let err = unknown.unwrap_err().to_string();
+        assert!(err.contains("Unknown field 'assignee'"), "{}", err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_csv_escapes_special_characters() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let output = temp_dir.path().join("issues.csv");
+        let title = "Fix \"quoted\", comma\nand newline";
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        crate::commands::project::create_project("alpha", None, Some("high"))?;
+        crate::commands::create_issue(
+            "alpha",
+            title,
+            &crate::commands::NewIssue {
+                priority: Some("medium"),
+                ..Default::default()
+            },
+        )?;
+        let result = export(
+            "csv",
+            "issue",
+            Some(output.to_str().unwrap()),
+            None,
+            &IssueFilter::default(),
+            Some("title,status"),
+        );
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let content = std::fs::read_to_string(&output)?;
+        assert!(content.contains("\"Fix \"\"quoted\"\", comma\nand newline\""));
+
+        let mut reader = csv::Reader::from_path(&output)?;
+        let records: Vec<csv::StringRecord> = reader.records().collect::<Result<_, _>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(&records[0][0], title);
+        assert_eq!(&records[0][1], "backlog");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_mermaid_gantt() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let output = temp_dir.path().join("plan.md");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        crate::commands::project::create_project("beta", None, None)?;
+        crate::commands::project::create_project("alpha", None, None)?;
+        crate::commands::create_milestone("alpha", "v1: launch", Some("2099-06-30"), None, None)?;
+        crate::commands::create_milestone("alpha", "Someday", None, None, None)?;
+        crate::commands::create_milestone(
+            "beta",
+            "Beta",
+            Some("2000-01-31"),
+            Some("completed"),
+            None,
+        )?;
+        crate::commands::create_issue(
+            "alpha",
+            "Work",
+            &crate::commands::NewIssue {
+                milestone: Some("v1: launch"),
+                ..Default::default()
+            },
+        )?;
+        let result = export(
+            "mermaid",
+            "milestone",
+            Some(output.to_str().unwrap()),
+            None,
+            &IssueFilter::default(),
+            None,
+        );
+        let issues_only = export(
+            "mermaid",
+            "issue",
+            None,
+            None,
+            &IssueFilter::default(),
+            None,
+        );
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+        assert!(issues_only.is_err());
+
+        let today = chrono::Utc::now()
+            .date_naive()
+            .format("%Y-%m-%d")
+            .to_string();
+        let chart = std::fs::read_to_string(&output)?;
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "```mermaid",
+                "gantt",
+                "    title Milestones",
+                "    dateFormat YYYY-MM-DD",
+                "    section alpha",
+                &format!("    v1 launch :{}, 2099-06-30", today),
+                "    section beta",
+                "    Beta :done, 2000-01-31, 2000-01-31",
+                "```",
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,216 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::commands::issue::{default_description, write_new_issue};
+use crate::display::success;
+use crate::fs::{find_project, get_base_directory, record_activity, render_template};
+use crate::models::{normalize_tags, FieldChange, IssueMetadata, Priority, Status};
+
+/// Create issues in a project from a CSV file with the same columns as `export`
+/// Rows that fail to import are reported and skipped; the rest are still created
+pub fn import_csv(file: &str, project_name: &str) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
+    let project = find_project(&base_dir, project_name)?;
+    let project_name = project.metadata.name;
+
+    let mut reader =
+        csv::Reader::from_path(file).with_context(|| format!("Failed to open {}", file))?;
+    let headers: Vec<String> = reader
+        .headers()
+        .with_context(|| format!("Failed to read CSV header from {}", file))?
+        .iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+    if !headers.iter().any(|h| h == "title") {
+        return Err(anyhow::anyhow!(
+            "{} has no 'title' column. Expected the columns produced by 'pillar export --format csv --entity-type issue'",
+            file
+        ));
+    }
+
+    let mut imported = 0;
+    let mut failed = 0;
+
+    for (index, record) in reader.records().enumerate() {
+        // Row 1 is the header
+        let row = index + 2;
+        let result = record
+            .map_err(anyhow::Error::from)
+            .and_then(|record| row_to_metadata(&headers, &record, &project_name))
+            .and_then(|metadata| {
+                let description = render_template(
+                    "issue",
+                    &[
+                        ("ISSUE_TITLE", &metadata.title),
+                        ("PROJECT_NAME", &project_name),
+                    ],
+                )?
+                .unwrap_or_else(|| default_description(&metadata.title));
+                let id = write_new_issue(&project.path, &metadata, &description)?;
+                Ok((id, metadata.title))
+            });
+
+        match result {
+            Ok((id, title)) => {
+                imported += 1;
+                println!("  {}/{} - {}", project_name, id, title);
+            }
+            Err(e) => {
+                failed += 1;
+                eprintln!("  Row {}: {}", row, e);
+            }
+        }
+    }
+
+    if imported > 0 {
+        record_activity(
+            "import",
+            "project",
+            &project_name,
+            vec![FieldChange::new("issues", None, Some(imported.to_string()))],
+        );
+    }
+
+    success(&format!(
+        "✓ Imported {} issue(s) into '{}' from {}",
+        imported,
+        project_name,
+        Path::new(file).display()
+    ));
+    if failed > 0 {
+        success(&format!("  Skipped {} row(s) with errors", failed));
+    }
+
+    Ok(())
+}
+
+/// Build issue metadata from one CSV row; unknown columns are ignored
+fn row_to_metadata(
+    headers: &[String],
+    record: &csv::StringRecord,
+    project_name: &str,
+) -> Result<IssueMetadata> {
+    let field = |name: &str| -> Option<&str> {
+        headers
+            .iter()
+            .position(|h| h == name)
+            .and_then(|i| record.get(i))
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    };
+    let timestamp = |name: &str| -> Result<Option<DateTime<Utc>>> {
+        field(name)
+            .map(|v| {
+                DateTime::parse_from_rfc3339(v)
+                    .map(|t| t.with_timezone(&Utc))
+                    .map_err(|_| anyhow::anyhow!("Invalid {} timestamp: {}", name, v))
+            })
+            .transpose()
+    };
+
+    let title = field("title").ok_or_else(|| anyhow::anyhow!("Missing title"))?;
+    let status = field("status")
+        .map(Status::from_str)
+        .transpose()?
+        .unwrap_or(Status::Todo);
+    let priority = field("priority")
+        .map(Priority::from_str)
+        .transpose()?
+        .unwrap_or(Priority::Medium);
+    let tags = field("tags")
+        .map(|t| normalize_tags(t.split([';', ','])))
+        .unwrap_or_default();
+
+    Ok(IssueMetadata {
+        title: title.to_string(),
+        status,
+        priority,
+        severity: None,
+        project: Some(project_name.to_string()),
+        milestone: field("milestone").map(|m| m.to_string()),
+        tags,
+        assignee: None,
+        reporter: None,
+        url: None,
+        created: Some(timestamp("created")?.unwrap_or_else(Utc::now)),
+        updated: Some(timestamp("updated")?.unwrap_or_else(Utc::now)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{create_project, init};
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_import_csv_skips_bad_rows() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let csv_path = temp_dir.path().join("issues.csv");
+        std::fs::write(
+            &csv_path,
+            "title,status,priority,project,milestone,tags,created,updated\n\
+             \"Set up CI, finally\",in-progress,high,other,v1.0,infra;ci,2026-01-02T03:04:05+00:00,\n\
+             Broken row,sideways,low,,,,,\n\
+             ,todo,low,,,,,\n\
+             Write docs,,,,,,,\n",
+        )?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, Some("medium"))?;
+        let result = import_csv(csv_path.to_str().unwrap(), "alpha");
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let mut issues = crate::fs::list_issues(temp_dir.path().join("alpha"))?;
+        issues.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(issues.len(), 2);
+
+        let ci = &issues[0].metadata;
+        assert_eq!(ci.title, "Set up CI, finally");
+        assert_eq!(ci.status, Status::InProgress);
+        assert_eq!(ci.priority, Priority::High);
+        assert_eq!(ci.project.as_deref(), Some("alpha"));
+        assert_eq!(ci.milestone.as_deref(), Some("v1.0"));
+        assert_eq!(ci.tags, vec!["infra", "ci"]);
+        assert_eq!(
+            ci.created.unwrap().to_rfc3339(),
+            "2026-01-02T03:04:05+00:00"
+        );
+
+        let docs = &issues[1].metadata;
+        assert_eq!(docs.title, "Write docs");
+        assert_eq!(docs.status, Status::Todo);
+        assert_eq!(docs.priority, Priority::Medium);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_csv_requires_title_column() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let csv_path = temp_dir.path().join("issues.csv");
+        std::fs::write(&csv_path, "name,status\nfoo,todo\n")?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, Some("medium"))?;
+        let result = import_csv(csv_path.to_str().unwrap(), "alpha");
+        env::set_current_dir(&original_dir)?;
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("no 'title' column"));
+
+        Ok(())
+    }
+}
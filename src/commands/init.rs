@@ -1,8 +1,16 @@
 use anyhow::Result;
+use chrono::{Duration, Utc};
 use std::fs;
 
-use crate::fs::ensure_dir;
-use crate::models::{Config, DefaultConfig, WorkspaceConfig};
+use crate::commands::{
+    create_issue, create_milestone, create_project, edit_issue, edit_project, IssueEdits, NewIssue,
+};
+use crate::display::success;
+use crate::fs::{ensure_dir, resolve_base_directory, WORKSPACE_ENV};
+use crate::models::{
+    Config, DefaultConfig, DisplayConfig, GitConfig, LimitsConfig, ScoreConfig, TagsConfig,
+    WorkspaceConfig, WORKSPACE_VERSION,
+};
 
 const PROJECT_TEMPLATE: &str = r#"---
 name: {{PROJECT_NAME}}
@@ -56,7 +64,7 @@ Detailed issue description.
 - [ ] Criterion 2
 "#;
 
-pub fn init(base_directory: Option<&str>) -> Result<()> {
+pub fn init(base_directory: Option<&str>, with_example: bool) -> Result<()> {
     let current_dir = std::env::current_dir()?;
     let pillar_dir = current_dir.join(".pillar");
 
@@ -82,13 +90,18 @@ pub fn init(base_directory: Option<&str>) -> Result<()> {
     // Create config file with base directory
     let config = Config {
         workspace: WorkspaceConfig {
-            version: "0.1.0".to_string(),
+            version: WORKSPACE_VERSION.to_string(),
             base_directory: base_dir.to_string(),
         },
         defaults: DefaultConfig {
             priority: "medium".to_string(),
             status: "backlog".to_string(),
         },
+        git: GitConfig::default(),
+        display: DisplayConfig::default(),
+        score: ScoreConfig::default(),
+        limits: LimitsConfig::default(),
+        tags: TagsConfig::default(),
     };
 
     let config_str = toml::to_string_pretty(&config)?;
@@ -105,18 +118,93 @@ pub fn init(base_directory: Option<&str>) -> Result<()> {
 
     // Create base directory if not current dir
     if base_dir != "." {
-        let base_path = current_dir.join(base_dir);
-        ensure_dir(&base_path)?;
+        ensure_dir(resolve_base_directory(&current_dir, base_dir))?;
     }
 
-    println!(
+    success(&format!(
         "✓ Initialized Pillar workspace in {}",
         current_dir.display()
-    );
+    ));
     if base_dir != "." {
-        println!("  Base directory: {}", base_dir);
+        success(&format!("  Base directory: {}", base_dir));
     }
 
+    if with_example {
+        success("");
+        // Pin the example to the new workspace, not a parent one or --workspace
+        let _scope = WorkspaceScope::enter(&current_dir);
+        create_example_project()?;
+    }
+
+    Ok(())
+}
+
+/// Points `PILLAR_WORKSPACE` at a root until dropped, then restores the previous value
+struct WorkspaceScope {
+    previous: Option<std::ffi::OsString>,
+}
+
+impl WorkspaceScope {
+    fn enter(root: &std::path::Path) -> Self {
+        let previous = std::env::var_os(WORKSPACE_ENV);
+        std::env::set_var(WORKSPACE_ENV, root);
+        WorkspaceScope { previous }
+    }
+}
+
+impl Drop for WorkspaceScope {
+    fn drop(&mut self) {
+        match &self.previous {
+            Some(value) => std::env::set_var(WORKSPACE_ENV, value),
+            None => std::env::remove_var(WORKSPACE_ENV),
+        }
+    }
+}
+
+/// Name of the demo project created by `init --with-example`
+const EXAMPLE_PROJECT: &str = "example-project";
+
+/// Scaffold a small demo project so `status` and `board` have something to show
+fn create_example_project() -> Result<()> {
+    let milestone = "v1.0";
+    let target_date = (Utc::now() + Duration::days(30))
+        .format("%Y-%m-%d")
+        .to_string();
+
+    create_project(EXAMPLE_PROJECT, Some("ex"), Some("medium"))?;
+    edit_project(EXAMPLE_PROJECT, Some("in-progress"), None)?;
+    create_milestone(EXAMPLE_PROJECT, milestone, Some(&target_date), None, None)?;
+    create_issue(
+        EXAMPLE_PROJECT,
+        "Explore the Pillar CLI",
+        &NewIssue {
+            priority: Some("high"),
+            milestone: Some(milestone),
+            tags: Some("onboarding"),
+            ..Default::default()
+        },
+    )?;
+    create_issue(
+        EXAMPLE_PROJECT,
+        "Create your first real project",
+        &NewIssue {
+            priority: Some("medium"),
+            milestone: Some(milestone),
+            tags: Some("onboarding"),
+            ..Default::default()
+        },
+    )?;
+    edit_issue(
+        &format!("{}/001", EXAMPLE_PROJECT),
+        &IssueEdits {
+            status: Some("in-progress"),
+            ..Default::default()
+        },
+        false,
+    )?;
+
+    success("\nTry 'pillar status' or 'pillar board' to see the example project.");
+
     Ok(())
 }
 
@@ -132,7 +220,7 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = init(None);
+        let result = init(None, false);
 
         // Restore original directory first
         env::set_current_dir(&original_dir)?;
@@ -167,7 +255,7 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = init(Some("pm"));
+        let result = init(Some("pm"), false);
         env::set_current_dir(&original_dir)?;
 
         result?;
@@ -190,11 +278,11 @@ mod tests {
         env::set_current_dir(temp_dir.path())?;
 
         // First init should succeed
-        let first_result = init(None);
+        let first_result = init(None, false);
         assert!(first_result.is_ok());
 
         // Second init should fail
-        let result = init(None);
+        let result = init(None, false);
 
         env::set_current_dir(original_dir)?;
 
@@ -209,7 +297,7 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = init(Some(".pillar"));
+        let result = init(Some(".pillar"), false);
         env::set_current_dir(&original_dir)?;
 
         assert!(result.is_err());
@@ -224,7 +312,7 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = init(None);
+        let result = init(None, false);
         env::set_current_dir(&original_dir)?;
 
         result?;
@@ -237,4 +325,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_init_with_example() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let result = init(Some("pm"), true);
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let project_path = temp_dir.path().join("pm").join(EXAMPLE_PROJECT);
+        assert_eq!(crate::fs::list_milestones(&project_path)?.len(), 1);
+
+        let issues = crate::fs::list_issues(&project_path)?;
+        assert_eq!(issues.len(), 2);
+        assert!(issues
+            .iter()
+            .any(|i| i.metadata.status == crate::models::Status::InProgress));
+
+        Ok(())
+    }
 }
@@ -1,131 +1,343 @@
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use std::process::Command;
 use std::str::FromStr;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::dates::{parse_since, parse_until};
+use crate::display::{
+    detail, format_change, format_priority, format_status, format_status_with, format_tags,
+    format_url, success, truncate,
+};
+use crate::fs::{
+    ensure_dir, extract_issue_id, find_issue, find_project, find_workspace_root, generate_issue_id,
+    get_author, get_base_directory, inbox_dir, list_all_issues, read_config, record_activity,
+    render_template, resolve_priority, resolve_status, INBOX,
+};
+use crate::models::{
+    by_priority_then_name, normalize_tag, parse_tags, FieldChange, Issue, IssueMetadata, Priority,
+    ScoreConfig, Severity, Status, StatusStyle,
+};
+use crate::parser::{
+    count_tasks, parse_tasks, set_task_done, write_comments, write_with_frontmatter,
+};
+
+/// Optional fields for `create_issue`; unset ones fall back to the workspace defaults
+#[derive(Debug, Default)]
+pub struct NewIssue<'a> {
+    pub priority: Option<&'a str>,
+    pub milestone: Option<&'a str>,
+    /// Comma-separated tag list
+    pub tags: Option<&'a str>,
+    /// Name of a `.pillar/templates/issue.<name>.md` template
+    pub template: Option<&'a str>,
+    pub severity: Option<&'a str>,
+    pub url: Option<&'a str>,
+}
 
-use crate::fs::{ensure_dir, generate_issue_id, get_base_directory, list_all_issues};
-use crate::models::{IssueMetadata, Priority, Status};
-use crate::parser::write_with_frontmatter;
-
-pub fn create_issue(
-    project_name: &str,
-    title: &str,
-    priority: &str,
-    milestone: Option<&str>,
-    tags: Option<&str>,
-) -> Result<()> {
+pub fn create_issue(project_name: &str, title: &str, options: &NewIssue) -> Result<()> {
+    let NewIssue {
+        priority,
+        milestone,
+        tags,
+        template,
+        severity,
+        url,
+    } = *options;
     let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
 
-    // Find project by name or ID
-    let project = crate::fs::find_project(&base_dir, project_name)?;
-    let project_path = project.path;
-    let actual_project_name = project.metadata.name;
-
-    let issues_dir = project_path.join("issues");
-    ensure_dir(&issues_dir)?;
-
-    // Generate issue ID
-    let issue_id = generate_issue_id(&project_path)?;
-    let sanitized_title = sanitize_filename(title);
-    let issue_path = issues_dir.join(format!("{}-{}.md", issue_id, sanitized_title));
+    // Find project by name or ID; the inbox takes issues that don't have one yet
+    let (project_path, actual_project_name) = if project_name == INBOX {
+        (inbox_dir(&find_workspace_root()?), INBOX.to_string())
+    } else {
+        let project = crate::fs::find_project(&base_dir, project_name)?;
+        (project.path, project.metadata.name)
+    };
 
-    // Parse priority
-    let priority =
-        Priority::from_str(priority).with_context(|| format!("Invalid priority: {}", priority))?;
+    let priority = resolve_priority(priority)?;
+    let severity = severity.map(Severity::from_str).transpose()?;
+    let status = resolve_status(None)?;
 
     // Parse tags
-    let tag_list = if let Some(t) = tags {
-        t.split(',').map(|s| s.trim().to_string()).collect()
-    } else {
-        Vec::new()
-    };
+    let tag_list = tags.map(parse_tags).unwrap_or_default();
 
     // Create issue metadata
     let metadata = IssueMetadata {
         title: title.to_string(),
-        status: Status::Todo,
+        status,
         priority,
+        severity,
         project: Some(actual_project_name.clone()),
         milestone: milestone.map(|s| s.to_string()),
         tags: tag_list,
+        assignee: None,
+        reporter: Some(get_author()),
+        url: url
+            .map(str::trim)
+            .filter(|u| !u.is_empty())
+            .map(str::to_string),
         created: Some(Utc::now()),
         updated: Some(Utc::now()),
     };
 
-    // Create issue description
-    let description = format!(
-        "# {}\n\n## Description\n\nDetailed issue description.\n\n## Acceptance Criteria\n\n- [ ] Criterion 1\n- [ ] Criterion 2\n",
-        title
+    // Create issue description from the workspace template
+    let template_vars = [
+        ("ISSUE_TITLE", title),
+        ("PROJECT_NAME", &actual_project_name),
+    ];
+    let rendered = if let Some(name) = template {
+        if name.is_empty() || name.contains(['/', '\\']) {
+            return Err(anyhow::anyhow!("Invalid template name: '{}'", name));
+        }
+        let template_name = format!("issue.{}", name);
+        let body = render_template(&template_name, &template_vars)?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "Template '{}' not found (expected .pillar/templates/{}.md)",
+                name,
+                template_name
+            )
+        })?;
+        Some(body)
+    } else {
+        render_template("issue", &template_vars)?
+    };
+    let description = rendered.unwrap_or_else(|| default_description(title));
+    let issue_id = write_new_issue(&project_path, &metadata, &description)?;
+
+    record_activity(
+        "create",
+        "issue",
+        &format!("{}/{}", actual_project_name, issue_id),
+        vec![FieldChange::new("title", None, Some(title.to_string()))],
     );
-    write_with_frontmatter(&issue_path, &metadata, &description)?;
 
-    println!(
+    success(&format!(
         "✓ Created issue '{}/{}' - {}",
         actual_project_name, issue_id, title
-    );
+    ));
     if let Some(m) = milestone {
-        println!("  Milestone: {}", m);
+        success(&format!("  Milestone: {}", m));
+    }
+    detail(&format!("  Priority: {}", metadata.priority));
+    if !metadata.tags.is_empty() {
+        detail(&format!("  Tags: {}", format_tags(&metadata.tags)));
+    }
+    if let Some(reporter) = &metadata.reporter {
+        detail(&format!("  Reporter: {}", reporter));
     }
 
     Ok(())
 }
 
+/// Which assignee an issue must have to match
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssigneeFilter {
+    /// Assigned to this user (case-insensitive)
+    User(String),
+    /// No assignee at all
+    Unassigned,
+}
+
+impl AssigneeFilter {
+    /// Resolve the `issue list` flags; `--me` is shorthand for `--assignee <get_author()>`
+    pub fn from_flags(assignee: Option<&str>, me: bool, unassigned: bool) -> Option<Self> {
+        if unassigned {
+            Some(AssigneeFilter::Unassigned)
+        } else if me {
+            Some(AssigneeFilter::User(get_author()))
+        } else {
+            assignee.map(|a| AssigneeFilter::User(a.to_string()))
+        }
+    }
+
+    fn matches(&self, assignee: Option<&str>) -> bool {
+        match self {
+            AssigneeFilter::User(user) => assignee.is_some_and(|a| a.eq_ignore_ascii_case(user)),
+            AssigneeFilter::Unassigned => assignee.is_none_or(|a| a.trim().is_empty()),
+        }
+    }
+}
+
+/// Metadata filters shared by `issue list` and `export`
+#[derive(Debug, Default)]
+pub struct IssueFilter {
+    pub status: Option<Status>,
+    pub priority: Option<Priority>,
+    pub severity: Option<Severity>,
+    pub milestone: Option<String>,
+    pub tag: Option<String>,
+    pub assignee: Option<AssigneeFilter>,
+    /// Only issues whose `updated` timestamp is at or after this
+    pub updated_since: Option<DateTime<Utc>>,
+    /// Only issues whose `updated` timestamp is at or before this
+    pub updated_until: Option<DateTime<Utc>>,
+    /// Only issues whose `updated` timestamp is strictly after this, for incremental sync
+    pub updated_after: Option<DateTime<Utc>>,
+}
+
+impl IssueFilter {
+    /// Parse filter values as given on the command line
+    pub fn new(
+        status: Option<&str>,
+        priority: Option<&str>,
+        milestone: Option<&str>,
+        tag: Option<&str>,
+    ) -> Result<Self> {
+        Ok(IssueFilter {
+            status: status.map(Status::from_str).transpose()?,
+            priority: priority.map(Priority::from_str).transpose()?,
+            severity: None,
+            milestone: milestone.map(|m| m.to_string()),
+            tag: tag.and_then(normalize_tag),
+            assignee: None,
+            updated_since: None,
+            updated_until: None,
+            updated_after: None,
+        })
+    }
+
+    /// Limit to issues with this severity
+    pub fn with_severity(mut self, severity: Option<&str>) -> Result<Self> {
+        self.severity = severity.map(Severity::from_str).transpose()?;
+        Ok(self)
+    }
+
+    /// Limit to issues updated within `--since`/`--until`; see `crate::dates` for the formats.
+    /// Issues without an `updated` timestamp never match a date bound.
+    pub fn with_updated_range(mut self, since: Option<&str>, until: Option<&str>) -> Result<Self> {
+        self.updated_since = since.map(parse_since).transpose()?;
+        self.updated_until = until.map(parse_until).transpose()?;
+        Ok(self)
+    }
+
+    /// Limit to issues updated strictly after an RFC3339 timestamp (`--updated-after`)
+    pub fn with_updated_after(mut self, after: Option<&str>) -> Result<Self> {
+        self.updated_after = after
+            .map(|a| {
+                DateTime::parse_from_rfc3339(a.trim())
+                    .map(|t| t.with_timezone(&Utc))
+                    .map_err(|_| {
+                        anyhow::anyhow!(
+                            "Invalid timestamp '{}'. Expected RFC3339, e.g. 2026-03-01T09:00:00Z",
+                            a
+                        )
+                    })
+            })
+            .transpose()?;
+        Ok(self)
+    }
+
+    pub fn matches(&self, issue: &Issue) -> bool {
+        self.status.is_none_or(|s| issue.metadata.status == s)
+            && self.priority.is_none_or(|p| issue.metadata.priority == p)
+            && self
+                .severity
+                .is_none_or(|s| issue.metadata.severity == Some(s))
+            && self
+                .milestone
+                .as_ref()
+                .is_none_or(|m| issue.metadata.milestone.as_ref() == Some(m))
+            && self
+                .tag
+                .as_ref()
+                .is_none_or(|t| issue.metadata.tags.contains(t))
+            && self
+                .assignee
+                .as_ref()
+                .is_none_or(|a| a.matches(issue.metadata.assignee.as_deref()))
+            && self
+                .updated_since
+                .is_none_or(|since| issue.metadata.updated.is_some_and(|u| u >= since))
+            && self
+                .updated_until
+                .is_none_or(|until| issue.metadata.updated.is_some_and(|u| u <= until))
+            && self
+                .updated_after
+                .is_none_or(|after| issue.metadata.updated.is_some_and(|u| u > after))
+    }
+}
+
+/// Issues matching the filters, highest priority first, or by descending score for
+/// `sort == "score"` (see `ScoreConfig`). Inbox issues are included unless another
+/// project is asked for. With `updated_after` set, issues come oldest update first
+/// (ties by file path) so a sync job can resume from the last one it saw.
 pub fn list_issues(
-    status_filter: Option<&str>,
-    priority_filter: Option<&str>,
     project_filter: Option<&str>,
-    milestone_filter: Option<&str>,
-    tag_filter: Option<&str>,
-) -> Result<()> {
+    filter: &IssueFilter,
+    sort: &str,
+) -> Result<Vec<Issue>> {
     let base_dir = get_base_directory()?;
     let mut issues = if let Some(proj) = project_filter {
-        let project_path = base_dir.join(proj);
-        crate::fs::list_issues(&project_path)?
+        crate::fs::list_issues(crate::fs::project_path(&base_dir, proj)?)?
     } else {
-        list_all_issues(&base_dir)?
+        let mut issues = list_all_issues(&base_dir)?;
+        issues.extend(crate::fs::list_issues(inbox_dir(&find_workspace_root()?))?);
+        issues
     };
 
-    // Parse filters
-    let status_filter = if let Some(s) = status_filter {
-        Some(Status::from_str(s)?)
-    } else {
-        None
-    };
+    issues.retain(|i| filter.matches(i));
 
-    let priority_filter = if let Some(p) = priority_filter {
-        Some(Priority::from_str(p)?)
-    } else {
-        None
-    };
+    issues.sort_by(|a, b| {
+        by_priority_then_name(
+            (a.metadata.priority, &a.metadata.title),
+            (b.metadata.priority, &b.metadata.title),
+        )
+    });
 
-    // Apply filters
-    if let Some(status) = status_filter {
-        issues.retain(|i| i.metadata.status == status);
+    if filter.updated_after.is_some() {
+        issues.sort_by(|a, b| (a.metadata.updated, &a.path).cmp(&(b.metadata.updated, &b.path)));
+    } else if sort == "score" {
+        let weights = read_config(&find_workspace_root()?)?.score;
+        sort_by_score(&mut issues, &weights, Utc::now());
     }
 
-    if let Some(priority) = priority_filter {
-        issues.retain(|i| i.metadata.priority == priority);
-    }
+    Ok(issues)
+}
 
-    if let Some(milestone) = milestone_filter {
-        issues.retain(|i| i.metadata.milestone.as_deref() == Some(milestone));
-    }
+/// Highest score first; the sort is stable, so equal scores keep their current order
+fn sort_by_score(issues: &mut [Issue], weights: &ScoreConfig, now: DateTime<Utc>) {
+    issues.sort_by(|a, b| {
+        weights
+            .score(&b.metadata, now)
+            .total_cmp(&weights.score(&a.metadata, now))
+    });
+}
 
-    if let Some(tag) = tag_filter {
-        issues.retain(|i| i.metadata.tags.contains(&tag.to_string()));
+/// `issues` as JSON objects carrying their `id` (`project/NNN`) and `project` directory,
+/// which otherwise only exist in the file path
+pub fn issues_to_json(issues: &[Issue]) -> Result<Vec<serde_json::Value>> {
+    issues
+        .iter()
+        .map(|issue| {
+            let project = crate::fs::issue_project_dir(issue);
+            let mut value = serde_json::to_value(issue)?;
+            value["id"] = format!("{}/{}", project, extract_issue_id(&issue.path)).into();
+            value["project"] = project.into();
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Print the result of `list_issues`: just the count, an aligned table, or the default listing
+/// `show_tasks` adds each issue's checklist progress to the default listing.
+pub fn print_issues(issues: &[Issue], count_only: bool, table: bool, show_tasks: bool) {
+    if count_only {
+        println!("{}", issues.len());
+        return;
     }
 
     if issues.is_empty() {
         println!("No issues found.");
-        return Ok(());
+        return;
     }
 
-    // Sort by priority (descending) then title
-    issues.sort_by(|a, b| {
-        b.metadata
-            .priority
-            .cmp(&a.metadata.priority)
-            .then_with(|| a.metadata.title.cmp(&b.metadata.title))
-    });
+    if table {
+        for line in issue_table(issues) {
+            println!("{}", line);
+        }
+        return;
+    }
 
     println!("Issues:\n");
     for issue in issues {
@@ -146,36 +358,29 @@ pub fn list_issues(
         }
 
         if !issue.metadata.tags.is_empty() {
-            println!("    Tags: {}", issue.metadata.tags.join(", "));
+            println!("    Tags: {}", format_tags(&issue.metadata.tags));
+        }
+
+        if show_tasks {
+            if let Some(progress) = task_progress(&issue.description) {
+                println!("    {}", progress);
+            }
         }
     }
+}
 
-    Ok(())
+/// Checklist progress like "3/5 tasks", or `None` for an issue without a checklist
+fn task_progress(description: &str) -> Option<String> {
+    match count_tasks(description) {
+        (_, 0) => None,
+        (done, total) => Some(format!("{}/{} tasks", done, total)),
+    }
 }
 
 pub fn show_issue(id: &str) -> Result<()> {
     let base_dir = get_base_directory()?;
 
-    // Parse ID as "project-name/001" or just "001" if in project dir
-    let (project_name, issue_id) = if let Some((proj, id)) = id.split_once('/') {
-        (proj, id)
-    } else {
-        return Err(anyhow::anyhow!(
-            "Issue ID must be in format 'project-name/001'"
-        ));
-    };
-
-    let project_path = base_dir.join(project_name);
-    if !project_path.exists() {
-        return Err(anyhow::anyhow!("Project '{}' does not exist", project_name));
-    }
-
-    // Find issue file by ID
-    let issues = crate::fs::list_issues(&project_path)?;
-    let issue = issues
-        .into_iter()
-        .find(|i| extract_issue_id(&i.path) == issue_id)
-        .ok_or_else(|| anyhow::anyhow!("Issue '{}' not found", id))?;
+    let (project_name, issue_id, issue) = resolve_issue(&base_dir, id)?;
 
     println!(
         "Issue: {}/{} - {}",
@@ -188,8 +393,28 @@ pub fn show_issue(id: &str) -> Result<()> {
         println!("Milestone: {}", milestone);
     }
 
+    if let Some(severity) = &issue.metadata.severity {
+        println!("Severity: {}", severity);
+    }
+
+    if let Some(assignee) = &issue.metadata.assignee {
+        println!("Assignee: {}", assignee);
+    }
+
+    if let Some(reporter) = &issue.metadata.reporter {
+        println!("Reporter: {}", reporter);
+    }
+
+    if let Some(url) = &issue.metadata.url {
+        println!("URL: {}", format_url(url));
+    }
+
     if !issue.metadata.tags.is_empty() {
-        println!("Tags: {}", issue.metadata.tags.join(", "));
+        println!("Tags: {}", format_tags(&issue.metadata.tags));
+    }
+
+    if let Some(progress) = task_progress(&issue.description) {
+        println!("Tasks: {}", progress);
     }
 
     println!("\n{}", issue.description);
@@ -197,73 +422,108 @@ pub fn show_issue(id: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn edit_issue(
-    id: &str,
-    status: Option<&str>,
-    priority: Option<&str>,
-    milestone: Option<&str>,
-    tags: Option<&str>,
-) -> Result<()> {
-    let base_dir = get_base_directory()?;
-
-    // Parse ID
-    let (project_name, issue_id) = if let Some((proj, id)) = id.split_once('/') {
-        (proj, id)
-    } else {
-        return Err(anyhow::anyhow!(
-            "Issue ID must be in format 'project-name/001'"
-        ));
-    };
+/// Fields for `edit_issue` to change; an empty string clears milestone, severity, or url
+#[derive(Debug, Default)]
+pub struct IssueEdits<'a> {
+    pub status: Option<&'a str>,
+    pub priority: Option<&'a str>,
+    pub milestone: Option<&'a str>,
+    /// Comma-separated tag list, replacing the current tags
+    pub tags: Option<&'a str>,
+    pub severity: Option<&'a str>,
+    pub url: Option<&'a str>,
+}
 
-    let project_path = base_dir.join(project_name);
-    if !project_path.exists() {
-        return Err(anyhow::anyhow!("Project '{}' does not exist", project_name));
-    }
+/// Apply `edits` to an issue. `force` lets a status change exceed `[limits] in_progress`.
+pub fn edit_issue(id: &str, edits: &IssueEdits, force: bool) -> Result<()> {
+    let IssueEdits {
+        status,
+        priority,
+        milestone,
+        tags,
+        severity,
+        url,
+    } = *edits;
+    let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
 
-    // Find issue
-    let issues = crate::fs::list_issues(&project_path)?;
-    let issue = issues
-        .into_iter()
-        .find(|i| extract_issue_id(&i.path) == issue_id)
-        .ok_or_else(|| anyhow::anyhow!("Issue '{}' not found", id))?;
+    let (project_name, issue_id, issue) = resolve_issue(&base_dir, id)?;
+    let full_id = format!("{}/{}", project_name, issue_id);
 
     let mut metadata = issue.metadata;
-    let mut changed = false;
+    let mut changes = Vec::new();
 
     if let Some(s) = status {
+        let previous = metadata.status;
         metadata.status = Status::from_str(s)?;
-        changed = true;
-        println!("Updated status to: {}", format_status(&metadata.status));
+        if metadata.status == Status::InProgress && previous != Status::InProgress {
+            check_in_progress_limit(&crate::fs::project_path(&base_dir, &project_name)?, force)?;
+        }
+        changes.push(FieldChange::new(
+            "status",
+            Some(previous.to_string()),
+            Some(metadata.status.to_string()),
+        ));
     }
 
     if let Some(p) = priority {
+        let previous = metadata.priority;
         metadata.priority = Priority::from_str(p)?;
-        changed = true;
-        println!(
-            "Updated priority to: {}",
-            format_priority(&metadata.priority)
-        );
+        changes.push(FieldChange::new(
+            "priority",
+            Some(previous.to_string()),
+            Some(metadata.priority.to_string()),
+        ));
+    }
+
+    if let Some(s) = severity {
+        let previous = metadata.severity.take();
+        metadata.severity = if s.is_empty() {
+            None
+        } else {
+            Some(Severity::from_str(s)?)
+        };
+        changes.push(FieldChange::new(
+            "severity",
+            previous.map(|s| s.to_string()),
+            metadata.severity.map(|s| s.to_string()),
+        ));
     }
 
     if let Some(m) = milestone {
+        let previous = metadata.milestone.take();
         metadata.milestone = if m.is_empty() {
             None
         } else {
             Some(m.to_string())
         };
-        changed = true;
-        println!("Updated milestone to: {}", m);
+        changes.push(FieldChange::new(
+            "milestone",
+            previous,
+            metadata.milestone.clone(),
+        ));
+    }
+
+    if let Some(u) = url {
+        let previous = metadata.url.take();
+        let u = u.trim();
+        metadata.url = (!u.is_empty()).then(|| u.to_string());
+        changes.push(FieldChange::new("url", previous, metadata.url.clone()));
     }
 
     if let Some(t) = tags {
-        metadata.tags = t.split(',').map(|s| s.trim().to_string()).collect();
-        changed = true;
-        println!("Updated tags to: {}", metadata.tags.join(", "));
+        let previous = metadata.tags.join(",");
+        metadata.tags = parse_tags(t);
+        changes.push(FieldChange::new(
+            "tags",
+            Some(previous),
+            Some(metadata.tags.join(",")),
+        ));
     }
 
-    if !changed {
+    if changes.is_empty() {
         return Err(anyhow::anyhow!(
-            "No changes specified. Use --status, --priority, --milestone, or --tags"
+            "No changes specified. Use --status, --priority, --severity, --milestone, --tags, or --url"
         ));
     }
 
@@ -272,53 +532,426 @@ pub fn edit_issue(
     // Write back to file
     write_with_frontmatter(&issue.path, &metadata, &issue.description)?;
 
-    println!("✓ Updated issue '{}'", id);
+    for change in &changes {
+        success(&format_change(change));
+    }
+    record_activity("edit", "issue", &full_id, changes);
+
+    success(&format!("✓ Updated issue '{}'", full_id));
+    detail(&format!("  File: {}", issue.path.display()));
 
     Ok(())
 }
 
-fn sanitize_filename(s: &str) -> String {
-    s.to_lowercase()
-        .chars()
-        .take(40) // Limit filename length
-        .collect::<String>()
-        .replace(|c: char| !c.is_alphanumeric() && c != '-' && c != '_', "-")
-        .replace("--", "-")
-        .trim_matches('-')
-        .to_string()
+/// Enforce `[limits] in_progress` before another issue in the project is started.
+/// At the limit this is an error, or only a warning when `force` is set.
+fn check_in_progress_limit(project_path: &std::path::Path, force: bool) -> Result<()> {
+    let config = read_config(&find_workspace_root()?)?;
+    let Some(limit) = config.limits.in_progress else {
+        return Ok(());
+    };
+
+    let in_progress = crate::fs::list_issues(project_path)?
+        .iter()
+        .filter(|i| i.metadata.status == Status::InProgress)
+        .count();
+    if in_progress < limit {
+        return Ok(());
+    }
+
+    let message = format!(
+        "{} already has {} in-progress issue(s), the limit is {}",
+        project_path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy(),
+        in_progress,
+        limit
+    );
+    if force {
+        eprintln!("Warning: {}", message);
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!("{}. Use --force to exceed it", message))
+    }
+}
+
+/// Check (`done`) or uncheck the `index`th checklist item of an issue, counting from 1
+/// The rest of the description, including comments, is left exactly as it was.
+pub fn set_task(id: &str, index: usize, done: bool) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
+
+    let (project_name, issue_id, issue) = resolve_issue(&base_dir, id)?;
+    let full_id = format!("{}/{}", project_name, issue_id);
+
+    let tasks = parse_tasks(&issue.description);
+    let task = index
+        .checked_sub(1)
+        .and_then(|i| tasks.get(i))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Issue '{}' has {} checklist item(s); there is no item {}",
+                full_id,
+                tasks.len(),
+                index
+            )
+        })?;
+
+    let description = set_task_done(&issue.description, task.line, done);
+    let mut metadata = issue.metadata;
+    metadata.updated = Some(Utc::now());
+    write_with_frontmatter(&issue.path, &metadata, &description)?;
+
+    let state = |done: bool| if done { "done" } else { "todo" }.to_string();
+    record_activity(
+        "edit",
+        "issue",
+        &full_id,
+        vec![FieldChange::new(
+            &format!("task {}", index),
+            Some(state(task.done)),
+            Some(state(done)),
+        )],
+    );
+
+    success(&format!(
+        "✓ Marked task {} of '{}' as {}: {}",
+        index,
+        full_id,
+        state(done),
+        task.text
+    ));
+    if let Some(progress) = task_progress(&description) {
+        success(&format!("  {}", progress));
+    }
+
+    Ok(())
 }
 
-fn extract_issue_id(path: &std::path::Path) -> String {
-    path.file_stem()
-        .and_then(|s| s.to_str())
-        .and_then(|s| s.split('-').next())
-        .unwrap_or("unknown")
-        .to_string()
+/// Move an issue to another project under a fresh ID, keeping its description and comments
+pub fn move_issue(id: &str, destination: &str) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
+
+    let (project_name, issue_id, issue) = resolve_issue(&base_dir, id)?;
+    let full_id = format!("{}/{}", project_name, issue_id);
+    let target = find_project(&base_dir, destination)
+        .with_context(|| format!("Destination project '{}' does not exist", destination))?;
+    let target_name = target.metadata.name;
+
+    if target.path == crate::fs::project_path(&base_dir, &project_name)? {
+        return Err(anyhow::anyhow!(
+            "Issue '{}' is already in project '{}'",
+            full_id,
+            target_name
+        ));
+    }
+
+    let mut metadata = issue.metadata;
+    metadata.project = Some(target_name.clone());
+    metadata.updated = Some(Utc::now());
+
+    // Milestones belong to a project, so only keep one the destination also has
+    let previous_milestone = metadata.milestone.take();
+    if let Some(title) = &previous_milestone {
+        metadata.milestone = crate::fs::list_milestones(&target.path)?
+            .into_iter()
+            .find(|m| m.metadata.title.eq_ignore_ascii_case(title))
+            .map(|m| m.metadata.title);
+    }
+
+    let new_id = write_new_issue(&target.path, &metadata, &issue.description)?;
+    std::fs::remove_file(&issue.path)
+        .with_context(|| format!("Failed to remove {}", issue.path.display()))?;
+    crate::fs::mark_touched(&issue.path);
+
+    let new_full_id = format!("{}/{}", target_name, new_id);
+    let mut changes = vec![FieldChange::new(
+        "id",
+        Some(full_id.clone()),
+        Some(new_full_id.clone()),
+    )];
+    if previous_milestone.is_some() && metadata.milestone.is_none() {
+        changes.push(FieldChange::new(
+            "milestone",
+            previous_milestone.clone(),
+            None,
+        ));
+    }
+    record_activity("move", "issue", &full_id, changes);
+
+    success(&format!("✓ Moved issue '{}' to '{}'", full_id, new_full_id));
+    match (&previous_milestone, &metadata.milestone) {
+        (Some(_), Some(kept)) => success(&format!("  Milestone: {}", kept)),
+        (Some(cleared), None) => success(&format!(
+            "  Cleared milestone '{}' ('{}' has no milestone with that title)",
+            cleared, target_name
+        )),
+        _ => {}
+    }
+
+    Ok(())
 }
 
-fn format_status(status: &Status) -> String {
-    use colored::Colorize;
+/// Create a copy of an issue in the same project as a starting point for recurring work
+/// The copy gets a new ID, fresh timestamps, status Todo, and none of the original's comments
+pub fn clone_issue(id: &str) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
+
+    let (project_name, issue_id, issue) = resolve_issue(&base_dir, id)?;
+    let full_id = format!("{}/{}", project_name, issue_id);
+    let project_path = crate::fs::project_path(&base_dir, &project_name)?;
+
+    let now = Utc::now();
+    let metadata = IssueMetadata {
+        title: format!("{} (copy)", issue.metadata.title),
+        status: Status::Todo,
+        reporter: Some(get_author()),
+        created: Some(now),
+        updated: Some(now),
+        ..issue.metadata
+    };
+    let description = write_comments(&issue.description, &[]);
+
+    let new_id = write_new_issue(&project_path, &metadata, &description)?;
+
+    let new_full_id = format!("{}/{}", project_name, new_id);
+    record_activity(
+        "clone",
+        "issue",
+        &new_full_id,
+        vec![FieldChange::new("source", None, Some(full_id.clone()))],
+    );
+
+    success(&format!(
+        "✓ Cloned issue '{}' to '{}'",
+        full_id, new_full_id
+    ));
+    success(&format!("  Title: {}", metadata.title));
+
+    Ok(())
+}
+
+/// Built-in issue body used when the workspace has no issue template
+pub(crate) fn default_description(title: &str) -> String {
+    format!(
+        "# {}\n\n## Description\n\nDetailed issue description.\n\n## Acceptance Criteria\n\n- [ ] Criterion 1\n- [ ] Criterion 2\n",
+        title
+    )
+}
+
+/// Write a new issue file under the project with the next free ID, returning that ID
+/// Callers must hold the workspace lock so concurrent creates don't pick the same ID
+pub(crate) fn write_new_issue(
+    project_path: &std::path::Path,
+    metadata: &IssueMetadata,
+    description: &str,
+) -> Result<String> {
+    let issues_dir = project_path.join("issues");
+    ensure_dir(&issues_dir)?;
+
+    let issue_id = generate_issue_id(project_path)?;
+    let sanitized_title = sanitize_filename(&metadata.title);
+    let issue_path = issues_dir.join(format!("{}-{}.md", issue_id, sanitized_title));
+    write_with_frontmatter(&issue_path, metadata, description)?;
+
+    Ok(issue_id)
+}
+
+/// Show the git commit history of an issue's file
+pub fn issue_history(id: &str) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let (_, _, issue) = resolve_issue(&base_dir, id)?;
+
+    let issue_dir = issue
+        .path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("Invalid issue path: {}", issue.path.display()))?;
+
+    let inside_repo = Command::new("git")
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .current_dir(issue_dir)
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false);
+    if !inside_repo {
+        return Err(anyhow::anyhow!(
+            "Issue history needs git, but {} is not inside a git repository",
+            issue_dir.display()
+        ));
+    }
+
+    let output = Command::new("git")
+        .args([
+            "log",
+            "--follow",
+            "--date=short",
+            "--format=%h%x09%ad%x09%s",
+            "--",
+        ])
+        .arg(&issue.path)
+        .current_dir(issue_dir)
+        .output()
+        .context("Failed to run git log")?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "git log failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let log = String::from_utf8_lossy(&output.stdout);
+    if log.trim().is_empty() {
+        println!("No commits found for issue '{}'", id);
+        return Ok(());
+    }
+
+    println!("History of {} - {}:\n", id, issue.metadata.title);
+    for line in log.lines() {
+        let mut parts = line.splitn(3, '\t');
+        let hash = parts.next().unwrap_or("");
+        let date = parts.next().unwrap_or("");
+        let subject = parts.next().unwrap_or("");
+        println!("  {} {} {}", hash, date, subject);
+    }
 
-    match status {
-        Status::Backlog => "backlog".white(),
-        Status::Todo => "todo".cyan(),
-        Status::InProgress => "in-progress".yellow(),
-        Status::Completed => "completed".green(),
-        Status::Cancelled => "cancelled".red(),
+    Ok(())
+}
+
+/// Resolve an issue ID in "project-name/001" form to its project directory name, number,
+/// and issue. The project part may be anything `find_project` accepts (name, ID, or a
+/// unique prefix). A bare "001" is looked up in the project containing the current
+/// directory, and "inbox/001" in the inbox.
+fn resolve_issue<'a>(base_dir: &std::path::Path, id: &'a str) -> Result<(String, &'a str, Issue)> {
+    let (project_name, issue_id) = match id.split_once('/') {
+        Some((project_name, issue_id)) => (project_name.to_string(), issue_id),
+        None => {
+            let project_name = crate::fs::infer_project(base_dir).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Issue ID must be in format 'project-name/001' (a bare '001' only works inside a project directory)"
+                )
+            })?;
+            (project_name, id)
+        }
+    };
+
+    let project_path = crate::fs::project_path(base_dir, &project_name)?;
+    if !project_path.exists() {
+        return Err(anyhow::anyhow!("The inbox is empty"));
+    }
+
+    // Report the canonical directory name, whatever form the caller used
+    let project_name = if project_name == INBOX {
+        project_name
+    } else {
+        project_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or(project_name)
+    };
+
+    let issue = find_issue(&project_path, issue_id)?;
+    Ok((project_name, issue_id, issue))
+}
+
+/// Turn a title into a filename slug: lowercase, with each run of other characters
+/// collapsed into a single `-`. Titles with nothing usable become `untitled`.
+///
+/// Letters and digits from any script are kept (`バグ修正` stays as is); punctuation,
+/// whitespace, and symbols such as emoji become separators. The title is cut after
+/// 40 grapheme clusters, so accented letters and combined characters are never split.
+fn sanitize_filename(s: &str) -> String {
+    let mut slug = String::new();
+    // Limit filename length
+    for grapheme in s.to_lowercase().graphemes(true).take(40) {
+        // A cluster is a letter if its base character is; any combining marks come along
+        if grapheme.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+            slug.push_str(grapheme);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    match slug.trim_end_matches('-') {
+        "" => "untitled".to_string(),
+        trimmed => trimmed.to_string(),
     }
-    .to_string()
 }
 
-fn format_priority(priority: &Priority) -> String {
+/// Longest title shown in table mode before it is cut off with an ellipsis
+const TABLE_TITLE_WIDTH: usize = 40;
+
+/// Render issues as an aligned table: ID, title, status, priority, milestone
+/// Status and priority keep their colors; padding is computed from the uncolored text
+fn issue_table(issues: &[Issue]) -> Vec<String> {
     use colored::Colorize;
 
-    match priority {
-        Priority::Low => "low".white(),
-        Priority::Medium => "medium".cyan(),
-        Priority::High => "high".yellow(),
-        Priority::Urgent => "urgent".red(),
+    let rows: Vec<[String; 5]> = issues
+        .iter()
+        .map(|issue| {
+            let title = truncate(&issue.metadata.title, TABLE_TITLE_WIDTH);
+            [
+                format!(
+                    "{}/{}",
+                    issue.metadata.project.as_deref().unwrap_or("unknown"),
+                    extract_issue_id(&issue.path)
+                ),
+                title,
+                issue.metadata.status.to_string(),
+                issue.metadata.priority.to_string(),
+                issue.metadata.milestone.clone().unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    let headers = ["ID", "TITLE", "STATUS", "PRIORITY", "MILESTONE"];
+    let mut widths = headers.map(|h| h.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let pad = |text: &str, shown: String, width: usize| {
+        format!(
+            "{}{}",
+            shown,
+            " ".repeat(width.saturating_sub(text.chars().count()))
+        )
+    };
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+    let header = headers
+        .iter()
+        .zip(widths)
+        .map(|(h, w)| format!("{:<w$}", h, w = w))
+        .collect::<Vec<_>>()
+        .join("  ");
+    lines.push(header.trim_end().bold().to_string());
+
+    for (issue, row) in issues.iter().zip(&rows) {
+        let cells = [
+            pad(&row[0], row[0].clone(), widths[0]),
+            pad(&row[1], row[1].clone(), widths[1]),
+            // Always plain text here: emoji are double-width and would break the alignment
+            pad(
+                &row[2],
+                format_status_with(&issue.metadata.status, StatusStyle::Text),
+                widths[2],
+            ),
+            pad(
+                &row[3],
+                format_priority(&issue.metadata.priority),
+                widths[3],
+            ),
+            row[4].clone(),
+        ];
+        lines.push(cells.join("  ").trim_end().to_string());
     }
-    .to_string()
+
+    lines
 }
 
 #[cfg(test)]
@@ -332,8 +965,8 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        crate::commands::init(None)?;
-        crate::commands::create_project("test-project", None, "medium")?;
+        crate::commands::init(None, false)?;
+        crate::commands::create_project("test-project", None, Some("medium"))?;
         env::set_current_dir(&original_dir)?;
 
         Ok((temp_dir, "test-project".to_string()))
@@ -348,10 +981,14 @@ mod tests {
         let result = create_issue(
             &project_name,
             "Fix critical bug",
-            "urgent",
-            Some("v1.0"),
-            Some("bug,critical"),
+            &NewIssue {
+                priority: Some("urgent"),
+                milestone: Some("v1.0"),
+                tags: Some("bug,critical"),
+                ..Default::default()
+            },
         );
+        let author = get_author();
         env::set_current_dir(&original_dir)?;
 
         result?;
@@ -359,64 +996,1031 @@ mod tests {
         let issues_dir = temp_dir.path().join(&project_name).join("issues");
         assert!(issues_dir.exists());
 
-        let issues = crate::fs::list_issues(&temp_dir.path().join(&project_name))?;
+        let issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].metadata.title, "Fix critical bug");
         assert_eq!(issues[0].metadata.priority, Priority::Urgent);
         assert_eq!(issues[0].metadata.milestone, Some("v1.0".to_string()));
         assert_eq!(issues[0].metadata.tags, vec!["bug", "critical"]);
+        assert_eq!(issues[0].metadata.reporter, Some(author));
 
         Ok(())
     }
 
     #[test]
-    fn test_edit_issue() -> Result<()> {
+    fn test_edit_issue_keeps_reporter() -> Result<()> {
         let (temp_dir, project_name) = setup_workspace_with_project()?;
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        create_issue(&project_name, "Test issue", "medium", None, None)?;
-        let result = edit_issue(
+        create_issue(&project_name, "Reported", &NewIssue::default())?;
+        let issue_path = crate::fs::list_issues(temp_dir.path().join(&project_name))?[0]
+            .path
+            .clone();
+        let mut issue = crate::parser::read_issue(&issue_path)?;
+        issue.metadata.reporter = Some("carol".to_string());
+        write_with_frontmatter(&issue.path, &issue.metadata, &issue.description)?;
+        let edited = edit_issue(
             "test-project/001",
-            Some("in-progress"),
-            Some("high"),
-            Some("v2.0"),
-            None,
+            &IssueEdits {
+                status: Some("todo"),
+                ..Default::default()
+            },
+            false,
         );
         env::set_current_dir(&original_dir)?;
 
-        result?;
+        edited?;
+        let issue = crate::parser::read_issue(&issue_path)?;
+        assert_eq!(issue.metadata.reporter.as_deref(), Some("carol"));
 
-        let issues = crate::fs::list_issues(&temp_dir.path().join(&project_name))?;
-        assert_eq!(issues[0].metadata.status, Status::InProgress);
-        assert_eq!(issues[0].metadata.priority, Priority::High);
-        assert_eq!(issues[0].metadata.milestone, Some("v2.0".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_inbox_create_list_and_move() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(INBOX, "Unsorted idea", &NewIssue::default())?;
+        create_issue(&project_name, "Filed", &NewIssue::default())?;
+        let all = list_issues(None, &IssueFilter::default(), "priority");
+        let inbox = list_issues(Some(INBOX), &IssueFilter::default(), "priority");
+        let project = list_issues(Some(&project_name), &IssueFilter::default(), "priority");
+        let stored = temp_dir
+            .path()
+            .join(".pillar/inbox/issues/001-unsorted-idea.md")
+            .exists();
+        let moved = move_issue("inbox/001", &project_name);
+        let after_move = list_issues(Some(INBOX), &IssueFilter::default(), "priority");
+        env::set_current_dir(&original_dir)?;
+
+        assert!(stored);
+        let titles = |issues: Result<Vec<Issue>>| -> Result<Vec<String>> {
+            Ok(issues?.into_iter().map(|i| i.metadata.title).collect())
+        };
+        let mut all = titles(all)?;
+        all.sort();
+        assert_eq!(all, ["Filed", "Unsorted idea"]);
+        assert_eq!(titles(inbox)?, ["Unsorted idea"]);
+        assert_eq!(titles(project)?, ["Filed"]);
+
+        moved?;
+        assert!(after_move?.is_empty());
+        let filed = find_issue(temp_dir.path().join(&project_name), "002")?;
+        assert_eq!(filed.metadata.title, "Unsorted idea");
+        assert_eq!(filed.metadata.project.as_deref(), Some("test-project"));
 
         Ok(())
     }
 
     #[test]
-    fn test_sanitize_filename() {
-        assert_eq!(sanitize_filename("Fix critical bug"), "fix-critical-bug");
-        assert_eq!(
-            sanitize_filename("Add new feature: authentication"),
-            "add-new-feature-authentication"
+    fn test_project_id_and_prefix_resolve_for_issues() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+        let project_id = crate::parser::read_project(temp_dir.path().join(&project_name))?
+            .metadata
+            .project_id
+            .expect("projects get a default ID");
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(&project_name, "Reachable", &NewIssue::default())?;
+        let by_id = list_issues(Some(&project_id), &IssueFilter::default(), "priority");
+        let by_prefix = list_issues(Some("test"), &IssueFilter::default(), "priority");
+        let unknown = list_issues(Some("nope"), &IssueFilter::default(), "priority");
+        let full_id = format!("{}/001", project_id);
+        let resolved = resolve_issue(temp_dir.path(), &full_id);
+        let edited = edit_issue(
+            "test/001",
+            &IssueEdits {
+                status: Some("in-progress"),
+                ..Default::default()
+            },
+            false,
         );
-        assert_eq!(sanitize_filename("Bug #123"), "bug-123");
+        env::set_current_dir(&original_dir)?;
+
+        assert_eq!(by_id?.len(), 1);
+        assert_eq!(by_prefix?.len(), 1);
+        assert!(unknown.is_err());
+        let (resolved_project, number, issue) = resolved?;
+        assert_eq!(resolved_project, project_name);
+        assert_eq!(number, "001");
+        assert_eq!(issue.metadata.title, "Reachable");
+        edited?;
+
+        Ok(())
     }
 
     #[test]
-    fn test_list_issues_with_filters() -> Result<()> {
+    fn test_issue_url() -> Result<()> {
         let (temp_dir, project_name) = setup_workspace_with_project()?;
         let original_dir = env::current_dir()?;
+        let issue_path = temp_dir
+            .path()
+            .join(&project_name)
+            .join("issues/001-mirrored.md");
+        let edit_url = |url| {
+            edit_issue(
+                "test-project/001",
+                &IssueEdits {
+                    url: Some(url),
+                    ..Default::default()
+                },
+                false,
+            )
+        };
 
         env::set_current_dir(temp_dir.path())?;
-        create_issue(&project_name, "Issue 1", "high", None, Some("bug"))?;
-        create_issue(&project_name, "Issue 2", "low", None, Some("feature"))?;
-        let result = list_issues(None, Some("high"), None, None, None);
+        create_issue(
+            &project_name,
+            "Mirrored",
+            &NewIssue {
+                url: Some(" https://tracker.example.com/T-1 "),
+                ..Default::default()
+            },
+        )?;
+        let created = crate::parser::read_issue(&issue_path);
+        let changed = edit_url("https://tracker.example.com/T-2")
+            .and_then(|_| crate::parser::read_issue(&issue_path));
+        let cleared = edit_url("").and_then(|_| crate::parser::read_issue(&issue_path));
         env::set_current_dir(&original_dir)?;
 
-        result?;
+        assert_eq!(
+            created?.metadata.url.as_deref(),
+            Some("https://tracker.example.com/T-1")
+        );
+        assert_eq!(
+            changed?.metadata.url.as_deref(),
+            Some("https://tracker.example.com/T-2")
+        );
+        assert_eq!(cleared?.metadata.url, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_issue() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        crate::commands::create_project("other", None, Some("medium"))?;
+        create_issue(
+            "other",
+            "Existing",
+            &NewIssue {
+                priority: Some("low"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            &project_name,
+            "Misfiled",
+            &NewIssue {
+                priority: Some("high"),
+                tags: Some("bug"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::comment::add("issue", &project_name, Some("001"), "Belongs elsewhere")?;
+        let result = move_issue("test-project/001", "other");
+        let missing = move_issue("other/001", "nowhere");
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+        assert!(missing.is_err());
+
+        let source = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        assert!(source.is_empty());
+
+        let moved = find_issue(temp_dir.path().join("other"), "002")?;
+        assert_eq!(moved.metadata.title, "Misfiled");
+        assert_eq!(moved.metadata.project.as_deref(), Some("other"));
+        assert_eq!(moved.metadata.priority, Priority::High);
+        assert_eq!(moved.metadata.tags, vec!["bug"]);
+        assert!(moved.description.contains("Belongs elsewhere"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_issue_keeps_only_matching_milestone() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        crate::commands::create_project("other", None, Some("medium"))?;
+        crate::commands::create_milestone("other", "Launch", None, None, None)?;
+        for (title, milestone) in [("Shared", "launch"), ("Local", "v1.0")] {
+            create_issue(
+                &project_name,
+                title,
+                &NewIssue {
+                    milestone: Some(milestone),
+                    ..Default::default()
+                },
+            )?;
+        }
+        let results = [
+            move_issue("test-project/001", "other"),
+            move_issue("test-project/002", "other"),
+        ];
+        env::set_current_dir(&original_dir)?;
+
+        for result in results {
+            result?;
+        }
+
+        let shared = find_issue(temp_dir.path().join("other"), "001")?;
+        assert_eq!(shared.metadata.milestone.as_deref(), Some("Launch"));
+        let local = find_issue(temp_dir.path().join("other"), "002")?;
+        assert_eq!(local.metadata.milestone, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone_issue() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(
+            &project_name,
+            "Weekly report",
+            &NewIssue {
+                priority: Some("high"),
+                milestone: Some("v1.0"),
+                tags: Some("chore"),
+                ..Default::default()
+            },
+        )?;
+        edit_issue(
+            "test-project/001",
+            &IssueEdits {
+                status: Some("completed"),
+                ..Default::default()
+            },
+            false,
+        )?;
+        crate::commands::comment::add("issue", &project_name, Some("001"), "Sent for week 1")?;
+        let result = clone_issue("test-project/001");
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let project_path = temp_dir.path().join(&project_name);
+        let original = find_issue(&project_path, "001")?;
+        let clone = find_issue(&project_path, "002")?;
+
+        assert_eq!(clone.metadata.title, "Weekly report (copy)");
+        assert_eq!(clone.metadata.status, Status::Todo);
+        assert_eq!(clone.metadata.priority, Priority::High);
+        assert_eq!(clone.metadata.milestone.as_deref(), Some("v1.0"));
+        assert_eq!(clone.metadata.tags, vec!["chore"]);
+        assert!(clone.metadata.created > original.metadata.created);
+        assert!(clone.description.contains("# Weekly report"));
+        assert!(crate::parser::read_comments(&clone.description).is_empty());
+        assert_eq!(crate::parser::read_comments(&original.description).len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_issue_table_aligns_and_truncates() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+        let long_title = "A very long issue title that will not fit in the table column";
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(
+            &project_name,
+            "Short",
+            &NewIssue {
+                priority: Some("low"),
+                milestone: Some("v1.0"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            &project_name,
+            long_title,
+            &NewIssue {
+                priority: Some("high"),
+                ..Default::default()
+            },
+        )?;
+        let result = list_issues(None, &IssueFilter::default(), "priority");
+        env::set_current_dir(&original_dir)?;
+
+        // Sorted by priority, so the long high-priority title comes first
+        let issues = result?;
+        print_issues(&issues, false, true, false);
+        let lines = issue_table(&issues);
+        assert_eq!(lines.len(), 3);
+
+        let title_column = lines[0].find("TITLE").unwrap();
+        assert_eq!(lines[1].find("A very long"), Some(title_column));
+        assert_eq!(lines[2].find("Short"), Some(title_column));
+
+        let shown: String = long_title.chars().take(TABLE_TITLE_WIDTH - 1).collect();
+        assert!(lines[1].contains(&format!("{}…", shown)));
+        assert!(!lines[1].contains(long_title));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_issue() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(
+            &project_name,
+            "Test issue",
+            &NewIssue {
+                priority: Some("medium"),
+                ..Default::default()
+            },
+        )?;
+        let result = edit_issue(
+            "test-project/001",
+            &IssueEdits {
+                status: Some("in-progress"),
+                priority: Some("high"),
+                milestone: Some("v2.0"),
+                ..Default::default()
+            },
+            false,
+        );
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        assert_eq!(issues[0].metadata.status, Status::InProgress);
+        assert_eq!(issues[0].metadata.priority, Priority::High);
+        assert_eq!(issues[0].metadata.milestone, Some("v2.0".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_issue_uses_workspace_template() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        std::fs::write(
+            temp_dir.path().join(".pillar/templates/issue.md"),
+            "---\ntitle: {{ISSUE_TITLE}}\n---\n\n# {{ISSUE_TITLE}}\n\nFiled under {{PROJECT_NAME}}.\n\n## Steps to Reproduce\n",
+        )?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let result = create_issue(
+            &project_name,
+            "Crash on save",
+            &NewIssue {
+                priority: Some("high"),
+                ..Default::default()
+            },
+        );
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        assert_eq!(
+            issues[0].description,
+            "# Crash on save\n\nFiled under test-project.\n\n## Steps to Reproduce"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_issue_with_named_template() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        std::fs::write(
+            temp_dir.path().join(".pillar/templates/issue.bug.md"),
+            "---\ntitle: {{ISSUE_TITLE}}\n---\n\n# Bug: {{ISSUE_TITLE}}\n\n## Expected\n\n## Actual\n",
+        )?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let result = create_issue(
+            &project_name,
+            "Login fails",
+            &NewIssue {
+                priority: Some("high"),
+                template: Some("bug"),
+                ..Default::default()
+            },
+        );
+        let missing = create_issue(
+            &project_name,
+            "Other",
+            &NewIssue {
+                priority: Some("high"),
+                template: Some("feature"),
+                ..Default::default()
+            },
+        );
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+        let err = missing.unwrap_err().to_string();
+        assert!(err.contains("Template 'feature' not found"));
+        assert!(err.contains("issue.feature.md"));
+
+        let issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].description,
+            "# Bug: Login fails\n\n## Expected\n\n## Actual"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_issue_without_template_uses_builtin() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        std::fs::remove_file(temp_dir.path().join(".pillar/templates/issue.md"))?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let result = create_issue(
+            &project_name,
+            "Plain issue",
+            &NewIssue {
+                priority: Some("low"),
+                ..Default::default()
+            },
+        );
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        assert!(issues[0].description.starts_with("# Plain issue"));
+        assert!(issues[0].description.contains("## Acceptance Criteria"));
+        // The default criteria are an unchecked checklist
+        assert_eq!(
+            task_progress(&issues[0].description).as_deref(),
+            Some("0/2 tasks")
+        );
+        assert_eq!(task_progress("No checklist"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sanitize_filename() {
+        assert_eq!(sanitize_filename("Fix critical bug"), "fix-critical-bug");
+        assert_eq!(
+            sanitize_filename("Add new feature: authentication"),
+            "add-new-feature-authentication"
+        );
+        assert_eq!(sanitize_filename("Bug #123"), "bug-123");
+        assert_eq!(sanitize_filename("a   b"), "a-b");
+        assert_eq!(sanitize_filename("a -- b"), "a-b");
+        assert_eq!(sanitize_filename("a / / b"), "a-b");
+        assert_eq!(sanitize_filename("!!!"), "untitled");
+        assert_eq!(
+            sanitize_filename("-- Leading and trailing --"),
+            "leading-and-trailing"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_filename_unicode() {
+        assert_eq!(
+            sanitize_filename("ログイン画面のバグを修正"),
+            "ログイン画面のバグを修正"
+        );
+        assert_eq!(sanitize_filename("検索、フィルター"), "検索-フィルター");
+        assert_eq!(sanitize_filename("Ship it 🚀 now"), "ship-it-now");
+        assert_eq!(sanitize_filename("👨‍👩‍👧 family"), "family");
+        assert_eq!(sanitize_filename("Café crème"), "café-crème");
+
+        // "e" + combining acute accent stays one unit, even at the length limit
+        let decomposed = format!("{}e\u{301}", "a".repeat(39));
+        assert_eq!(sanitize_filename(&decomposed), decomposed);
+        let long = "日本語".repeat(20);
+        assert_eq!(sanitize_filename(&long).chars().count(), 40);
+    }
+
+    #[test]
+    fn test_list_issues_with_filters() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(
+            &project_name,
+            "Issue 1",
+            &NewIssue {
+                priority: Some("high"),
+                tags: Some("bug"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            &project_name,
+            "Issue 2",
+            &NewIssue {
+                priority: Some("low"),
+                tags: Some("feature"),
+                ..Default::default()
+            },
+        )?;
+        let high = list_issues(
+            None,
+            &IssueFilter::new(None, Some("high"), None, None)?,
+            "priority",
+        );
+        let tagged = list_issues(
+            Some(&project_name),
+            &IssueFilter::new(None, None, None, Some("Feature"))?,
+            "priority",
+        );
+        let all = list_issues(None, &IssueFilter::default(), "priority");
+        env::set_current_dir(&original_dir)?;
+
+        let high = high?;
+        assert_eq!(high.len(), 1);
+        assert_eq!(high[0].metadata.title, "Issue 1");
+
+        let tagged = tagged?;
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged[0].metadata.title, "Issue 2");
+
+        let titles: Vec<_> = all?.into_iter().map(|i| i.metadata.title).collect();
+        assert_eq!(titles, vec!["Issue 1", "Issue 2"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_issue_severity() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(
+            &project_name,
+            "Crash",
+            &NewIssue {
+                severity: Some("critical"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(&project_name, "Typo", &NewIssue::default())?;
+        let invalid = create_issue(
+            &project_name,
+            "Bad",
+            &NewIssue {
+                severity: Some("awful"),
+                ..Default::default()
+            },
+        );
+        edit_issue(
+            "test-project/002",
+            &IssueEdits {
+                severity: Some("minor"),
+                ..Default::default()
+            },
+            false,
+        )?;
+        let critical = list_issues(
+            None,
+            &IssueFilter::default().with_severity(Some("critical"))?,
+            "priority",
+        );
+        let minor = list_issues(
+            None,
+            &IssueFilter::default().with_severity(Some("minor"))?,
+            "priority",
+        );
+        edit_issue(
+            "test-project/002",
+            &IssueEdits {
+                severity: Some(""),
+                ..Default::default()
+            },
+            false,
+        )?;
+        env::set_current_dir(&original_dir)?;
+
+        assert!(invalid.is_err());
+        assert_eq!(critical?[0].metadata.title, "Crash");
+        assert_eq!(minor?[0].metadata.title, "Typo");
+
+        let issues_dir = temp_dir.path().join(&project_name).join("issues");
+        let crash = crate::parser::read_issue(issues_dir.join("001-crash.md"))?;
+        assert_eq!(crash.metadata.severity, Some(Severity::Critical));
+        let typo = crate::parser::read_issue(issues_dir.join("002-typo.md"))?;
+        assert_eq!(typo.metadata.severity, None);
+        assert!(!std::fs::read_to_string(&typo.path)?.contains("severity"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_bare_issue_number_inside_project() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(&project_name, "Nested", &NewIssue::default())?;
+        let base_dir = get_base_directory()?;
+        let explicit = resolve_issue(&base_dir, "test-project/001");
+        let bare_outside = resolve_issue(&base_dir, "001");
+        env::set_current_dir(temp_dir.path().join(&project_name).join("issues"))?;
+        let bare_inside = resolve_issue(&base_dir, "001");
+        let shown = show_issue("001");
+        let explicit_inside = resolve_issue(&base_dir, "test-project/001");
+        env::set_current_dir(&original_dir)?;
+
+        let (project, number, issue) = explicit?;
+        assert_eq!((project.as_str(), number), ("test-project", "001"));
+        assert_eq!(issue.metadata.title, "Nested");
+
+        let (project, number, issue) = bare_inside?;
+        assert_eq!((project.as_str(), number), ("test-project", "001"));
+        assert_eq!(issue.metadata.title, "Nested");
+        shown?;
+        assert_eq!(explicit_inside?.2.metadata.title, "Nested");
+
+        let message = bare_outside.unwrap_err().to_string();
+        assert!(message.contains("project-name/001"), "{}", message);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_task() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(&project_name, "Checklist", &NewIssue::default())?;
+        crate::commands::comment::add(
+            "issue",
+            &project_name,
+            Some("001"),
+            "- [ ] not a criterion",
+        )?;
+        let checked = set_task("test-project/001", 1, true);
+        let out_of_range = set_task("test-project/001", 3, true);
+        let zero = set_task("test-project/001", 0, true);
+        env::set_current_dir(&original_dir)?;
+
+        checked?;
+        assert!(out_of_range
+            .unwrap_err()
+            .to_string()
+            .contains("has 2 checklist item(s)"));
+        assert!(zero.is_err());
+
+        let issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        let description = &issues[0].description;
+        assert_eq!(count_tasks(description), (1, 2));
+        assert!(description.contains("- [x] Criterion 1\n- [ ] Criterion 2"));
+        // The comment is untouched
+        assert_eq!(crate::parser::read_comments(description).len(), 1);
+        assert!(description.contains("- [ ] not a criterion"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_issue_in_progress_limit() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        let mut config = read_config(temp_dir.path())?;
+        config.limits.in_progress = Some(1);
+        crate::fs::write_config(temp_dir.path(), &config)?;
+
+        env::set_current_dir(temp_dir.path())?;
+        for title in ["First", "Second", "Third"] {
+            create_issue(&project_name, title, &NewIssue::default())?;
+        }
+        let under_limit = edit_issue(
+            "test-project/001",
+            &IssueEdits {
+                status: Some("in-progress"),
+                ..Default::default()
+            },
+            false,
+        );
+        let at_limit = edit_issue(
+            "test-project/002",
+            &IssueEdits {
+                status: Some("in-progress"),
+                ..Default::default()
+            },
+            false,
+        );
+        let forced = edit_issue(
+            "test-project/003",
+            &IssueEdits {
+                status: Some("in-progress"),
+                ..Default::default()
+            },
+            true,
+        );
+        env::set_current_dir(&original_dir)?;
+
+        under_limit?;
+        let message = at_limit.unwrap_err().to_string();
+        assert!(message.contains("limit is 1"), "{}", message);
+        assert!(message.contains("--force"), "{}", message);
+        forced?;
+
+        let issues_dir = temp_dir.path().join(&project_name).join("issues");
+        let second = crate::parser::read_issue(issues_dir.join("002-second.md"))?;
+        assert_eq!(second.metadata.status, Status::Backlog);
+        let third = crate::parser::read_issue(issues_dir.join("003-third.md"))?;
+        assert_eq!(third.metadata.status, Status::InProgress);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_updated_range() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        for title in ["Old", "Recent", "Undated"] {
+            create_issue(&project_name, title, &NewIssue::default())?;
+        }
+        env::set_current_dir(&original_dir)?;
+
+        let issues_dir = temp_dir.path().join(&project_name).join("issues");
+        for (file, updated) in [
+            ("001-old.md", Some("2026-03-01T09:00:00Z")),
+            ("002-recent.md", Some("2026-03-05T18:30:00Z")),
+            ("003-undated.md", None),
+        ] {
+            let mut issue = crate::parser::read_issue(issues_dir.join(file))?;
+            issue.metadata.updated = updated.map(parse_since).transpose()?;
+            write_with_frontmatter(&issue.path, &issue.metadata, &issue.description)?;
+        }
+
+        let list = |since, until| -> Result<Vec<String>> {
+            let filter = IssueFilter::default().with_updated_range(since, until)?;
+            env::set_current_dir(temp_dir.path())?;
+            let issues = list_issues(None, &filter, "priority");
+            env::set_current_dir(&original_dir)?;
+            Ok(issues?.into_iter().map(|i| i.metadata.title).collect())
+        };
+
+        assert_eq!(list(Some("2026-03-02"), None)?, vec!["Recent"]);
+        assert_eq!(list(None, Some("2026-03-01"))?, vec!["Old"]);
+        assert_eq!(
+            list(Some("2026-03-01"), Some("2026-03-05"))?,
+            vec!["Old", "Recent"]
+        );
+        assert!(list(Some("2026-03-06"), None)?.is_empty());
+        assert_eq!(list(None, None)?.len(), 3);
+        assert!(list(Some("last week"), None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_updated_after() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        for (title, priority) in [("First", "urgent"), ("Second", "low"), ("Third", "high")] {
+            create_issue(
+                &project_name,
+                title,
+                &NewIssue {
+                    priority: Some(priority),
+                    ..Default::default()
+                },
+            )?;
+        }
+        env::set_current_dir(&original_dir)?;
+
+        let issues_dir = temp_dir.path().join(&project_name).join("issues");
+        for (file, updated) in [
+            ("001-first.md", "2026-03-01T09:00:00.000001Z"),
+            ("003-third.md", "2026-03-01T09:00:00.000002Z"),
+            ("002-second.md", "2026-03-01T09:00:00.000003Z"),
+        ] {
+            let mut issue = crate::parser::read_issue(issues_dir.join(file))?;
+            issue.metadata.updated = Some(DateTime::parse_from_rfc3339(updated)?.into());
+            write_with_frontmatter(&issue.path, &issue.metadata, &issue.description)?;
+        }
+
+        let list = |after| -> Result<Vec<String>> {
+            let filter = IssueFilter::default().with_updated_after(Some(after))?;
+            env::set_current_dir(temp_dir.path())?;
+            let issues = list_issues(None, &filter, "priority");
+            env::set_current_dir(&original_dir)?;
+            Ok(issues?.into_iter().map(|i| i.metadata.title).collect())
+        };
+
+        // Strictly after, in update order rather than by priority
+        assert_eq!(
+            list("2026-03-01T09:00:00.000001Z")?,
+            vec!["Third", "Second"]
+        );
+        assert_eq!(
+            list("2026-03-01T09:00:00Z")?,
+            vec!["First", "Third", "Second"]
+        );
+        assert_eq!(list("2026-03-01T10:00:00.000002+01:00")?, vec!["Second"]);
+        assert!(list("2026-03-01T09:00:00.000003Z")?.is_empty());
+        assert!(list("2026-03-01").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_by_assignee() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+        let me = get_author();
+
+        env::set_current_dir(temp_dir.path())?;
+        for title in ["Mine", "Theirs", "Nobody's"] {
+            create_issue(&project_name, title, &NewIssue::default())?;
+        }
+        env::set_current_dir(&original_dir)?;
+
+        let issues_dir = temp_dir.path().join(&project_name).join("issues");
+        for (file, assignee) in [
+            ("001-mine.md", me.to_uppercase()),
+            ("002-theirs.md", "someone-else".to_string()),
+        ] {
+            let mut issue = crate::parser::read_issue(issues_dir.join(file))?;
+            issue.metadata.assignee = Some(assignee);
+            write_with_frontmatter(&issue.path, &issue.metadata, &issue.description)?;
+        }
+
+        let list = |filter| {
+            env::set_current_dir(temp_dir.path())?;
+            let filter = IssueFilter {
+                assignee: filter,
+                ..IssueFilter::default()
+            };
+            let issues = list_issues(None, &filter, "priority");
+            env::set_current_dir(&original_dir)?;
+            issues.map(|issues| {
+                issues
+                    .into_iter()
+                    .map(|i| i.metadata.title)
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(
+            list(AssigneeFilter::from_flags(None, true, false))?,
+            vec!["Mine"]
+        );
+        assert_eq!(
+            list(AssigneeFilter::from_flags(None, false, true))?,
+            vec!["Nobody's"]
+        );
+        assert_eq!(
+            list(AssigneeFilter::from_flags(
+                Some("Someone-Else"),
+                false,
+                false
+            ))?,
+            vec!["Theirs"]
+        );
+        assert_eq!(list(None)?.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_issue_history() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_issue(
+            &project_name,
+            "Tracked issue",
+            &NewIssue {
+                priority: Some("medium"),
+                ..Default::default()
+            },
+        )?;
+        let without_git = issue_history("test-project/001");
+        env::set_current_dir(&original_dir)?;
+
+        let err = without_git.unwrap_err().to_string();
+        assert!(err.contains("not inside a git repository"), "{}", err);
+
+        let git = |args: &[&str]| -> Result<()> {
+            Command::new("git")
+                .args(args)
+                .current_dir(temp_dir.path())
+                .output()?;
+            Ok(())
+        };
+        git(&["init", "-q"])?;
+        git(&["config", "user.name", "Test"])?;
+        git(&["config", "user.email", "test@example.com"])?;
+        git(&["add", "-A"])?;
+        git(&["commit", "-q", "-m", "Add tracked issue"])?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let with_git = issue_history("test-project/001");
+        env::set_current_dir(&original_dir)?;
+
+        with_git
+    }
+
+    #[test]
+    fn test_create_uses_configured_default_priority() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        let config_path = temp_dir.path().join(".pillar/config.toml");
+        let config = std::fs::read_to_string(&config_path)?;
+        std::fs::write(
+            &config_path,
+            config.replace("priority = \"medium\"", "priority = \"high\""),
+        )?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let defaulted = create_issue(&project_name, "Defaulted", &NewIssue::default());
+        let explicit = create_issue(
+            &project_name,
+            "Explicit",
+            &NewIssue {
+                priority: Some("low"),
+                ..Default::default()
+            },
+        );
+        let project = crate::commands::create_project("second", None, None);
+        env::set_current_dir(&original_dir)?;
+
+        defaulted?;
+        explicit?;
+        project?;
+
+        let mut issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        issues.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(issues[0].metadata.priority, Priority::High);
+        assert_eq!(issues[1].metadata.priority, Priority::Low);
+
+        let second = crate::fs::find_project(temp_dir.path(), "second")?;
+        assert_eq!(second.metadata.priority, Priority::High);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_uses_configured_default_status() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+        let config_path = temp_dir.path().join(".pillar/config.toml");
+        let config = std::fs::read_to_string(&config_path)?;
+        assert!(config.contains("status = \"backlog\""));
+
+        env::set_current_dir(temp_dir.path())?;
+        let backlog = create_issue(&project_name, "Backlog item", &NewIssue::default());
+        std::fs::write(
+            &config_path,
+            config.replace("status = \"backlog\"", "status = \"todo\""),
+        )?;
+        let todo = create_issue(&project_name, "Todo item", &NewIssue::default());
+        std::fs::write(
+            &config_path,
+            config.replace("status = \"backlog\"", "status = \"someday\""),
+        )?;
+        let invalid = create_issue(&project_name, "Broken", &NewIssue::default());
+        env::set_current_dir(&original_dir)?;
+
+        backlog?;
+        todo?;
+        let err = invalid.unwrap_err().to_string();
+        assert!(err.contains("Invalid default status 'someday'"), "{}", err);
+
+        let mut issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        issues.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].metadata.status, Status::Backlog);
+        assert_eq!(issues[1].metadata.status, Status::Todo);
+
         Ok(())
     }
 }
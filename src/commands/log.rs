@@ -0,0 +1,161 @@
+use anyhow::Result;
+
+use crate::fs::{find_workspace_root, normalize_issue_entity, read_activity};
+use crate::models::ActivityEntry;
+
+/// Show the workspace activity log, oldest first
+pub fn show_log(entity: Option<&str>, limit: Option<usize>) -> Result<()> {
+    let root = find_workspace_root()?;
+    let entries = filter_entries(read_activity(&root)?, entity, limit);
+
+    if entries.is_empty() {
+        println!("No activity recorded.");
+        return Ok(());
+    }
+
+    for entry in entries {
+        let mut line = format!(
+            "{}  {}  {} {} {}",
+            entry.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            entry.author,
+            entry.action,
+            entry.kind,
+            entry.entity
+        );
+        if !entry.changes.is_empty() {
+            let changes: Vec<String> = entry.changes.iter().map(|c| c.to_string()).collect();
+            line.push_str(&format!(": {}", changes.join(", ")));
+        }
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+/// Keep entries for `entity` (or anything inside it, e.g. a project's issues),
+/// then only the most recent `limit` of those
+fn filter_entries(
+    mut entries: Vec<ActivityEntry>,
+    entity: Option<&str>,
+    limit: Option<usize>,
+) -> Vec<ActivityEntry> {
+    if let Some(entity) = entity {
+        let padded = normalize_issue_entity(entity);
+        let prefix = format!("{}/", entity);
+        entries
+            .retain(|e| e.entity == entity || e.entity == padded || e.entity.starts_with(&prefix));
+    }
+
+    if let Some(limit) = limit {
+        let skip = entries.len().saturating_sub(limit);
+        entries.drain(..skip);
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{create_issue, create_project, edit_issue, init, IssueEdits, NewIssue};
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_mutations_are_logged_and_filtered() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, Some("medium"))?;
+        create_project("beta", None, Some("medium"))?;
+        create_issue(
+            "alpha",
+            "First",
+            &NewIssue {
+                priority: Some("medium"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            "beta",
+            "Second",
+            &NewIssue {
+                priority: Some("medium"),
+                ..Default::default()
+            },
+        )?;
+        edit_issue(
+            "alpha/001",
+            &IssueEdits {
+                status: Some("completed"),
+                ..Default::default()
+            },
+            false,
+        )?;
+        env::set_current_dir(&original_dir)?;
+
+        let entries = read_activity(temp_dir.path())?;
+        assert_eq!(entries.len(), 5);
+
+        let alpha = filter_entries(entries.clone(), Some("alpha"), None);
+        let summary: Vec<(&str, &str)> = alpha
+            .iter()
+            .map(|e| (e.action.as_str(), e.entity.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("create", "alpha"),
+                ("create", "alpha/001"),
+                ("edit", "alpha/001")
+            ]
+        );
+        assert_eq!(
+            alpha[2].changes[0].to_string(),
+            "status backlog -> completed"
+        );
+
+        let last = filter_entries(entries, None, Some(2));
+        assert_eq!(last.len(), 2);
+        assert_eq!(last[1].action, "edit");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpadded_issue_ids_are_logged_padded() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let issues_dir = temp_dir.path().join("alpha/issues");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, None)?;
+        create_issue("alpha", "First", &NewIssue::default())?;
+        std::fs::rename(
+            issues_dir.join("001-first.md"),
+            issues_dir.join("1-first.md"),
+        )?;
+        let edited = edit_issue(
+            "alpha/1",
+            &IssueEdits {
+                status: Some("todo"),
+                ..Default::default()
+            },
+            false,
+        );
+        env::set_current_dir(&original_dir)?;
+
+        edited?;
+        let entries = filter_entries(read_activity(temp_dir.path())?, Some("alpha/1"), None);
+        let summary: Vec<(&str, &str)> = entries
+            .iter()
+            .map(|e| (e.action.as_str(), e.entity.as_str()))
+            .collect();
+        assert_eq!(summary, [("create", "alpha/001"), ("edit", "alpha/001")]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::{Context, Result};
+use clap::{Command, CommandFactory};
+use std::path::Path;
+
+use crate::cli::Cli;
+use crate::display::success;
+use crate::fs::ensure_dir;
+
+/// Write a roff man page for `pillar` and every subcommand into `dir`
+pub fn generate_manpages(dir: &Path) -> Result<()> {
+    ensure_dir(dir)?;
+
+    let mut command = Cli::command();
+    command.build();
+
+    let written = write_pages(&command, "pillar", dir)?;
+
+    success(&format!(
+        "✓ Wrote {} man pages to {}",
+        written,
+        dir.display()
+    ));
+
+    Ok(())
+}
+
+/// Render `command` as `<name>.1`, then recurse with names like `pillar-issue-create`
+/// Returns the number of pages written
+fn write_pages(command: &Command, name: &str, dir: &Path) -> Result<usize> {
+    let mut buffer = Vec::new();
+    clap_mangen::Man::new(command.clone())
+        .title(name)
+        .render(&mut buffer)
+        .with_context(|| format!("Failed to render man page for {}", name))?;
+
+    let path = dir.join(format!("{}.1", name));
+    std::fs::write(&path, buffer).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let mut written = 1;
+    for sub in command.get_subcommands() {
+        if sub.get_name() == "help" {
+            continue;
+        }
+        written += write_pages(sub, &format!("{}-{}", name, sub.get_name()), dir)?;
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_manpages() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let out = temp_dir.path().join("man");
+
+        generate_manpages(&out)?;
+
+        assert!(out.join("pillar.1").exists());
+        assert!(out.join("pillar-issue.1").exists());
+        assert!(!out.join("pillar-help.1").exists());
+
+        let page = std::fs::read_to_string(out.join("pillar-issue-create.1"))?;
+        assert!(page.contains(".TH pillar-issue-create"));
+        assert!(page.contains("template"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::display::success;
+use crate::fs::{
+    find_workspace_root, parse_workspace_version, read_config, read_config_file, write_config,
+};
+use crate::models::WORKSPACE_VERSION;
+
+/// One step of the on-disk format history
+struct Migration {
+    /// Workspace version after this step has run
+    version: &'static str,
+    description: &'static str,
+    apply: fn(&Path) -> Result<()>,
+}
+
+/// Every format change, oldest first. The last entry must be `WORKSPACE_VERSION`.
+const MIGRATIONS: &[Migration] = &[Migration {
+    version: "0.1.0",
+    description: "baseline format (no file changes)",
+    apply: baseline,
+}];
+
+fn baseline(_workspace_root: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Upgrade the workspace files in place to the format this build writes
+pub fn migrate() -> Result<()> {
+    let root = find_workspace_root()?;
+    let _lock = crate::fs::lock_workspace()?;
+
+    let from = read_config(&root)?.workspace.version;
+    let applied = migrate_workspace(&root)?;
+
+    if applied.is_empty() {
+        println!("Workspace is already at version {}", from);
+        return Ok(());
+    }
+
+    for (version, description) in &applied {
+        success(&format!("  -> {}: {}", version, description));
+    }
+    success(&format!(
+        "✓ Migrated workspace from {} to {}",
+        from, WORKSPACE_VERSION
+    ));
+
+    Ok(())
+}
+
+/// Run every migration newer than the workspace's version, bumping the version after each
+/// so an interrupted run resumes where it stopped. Returns the steps that ran.
+fn migrate_workspace(root: &Path) -> Result<Vec<(&'static str, &'static str)>> {
+    let mut config = read_config_file(root)?;
+    let current = parse_workspace_version(&config.workspace.version)?;
+    let latest = parse_workspace_version(WORKSPACE_VERSION)?;
+
+    if current > latest {
+        return Err(anyhow::anyhow!(
+            "Workspace version {} is newer than this version of pillar supports ({}). Upgrade pillar instead",
+            current,
+            latest
+        ));
+    }
+
+    let mut applied = Vec::new();
+    for migration in MIGRATIONS {
+        let target = parse_workspace_version(migration.version)?;
+        if target <= parse_workspace_version(&config.workspace.version)? {
+            continue;
+        }
+
+        (migration.apply)(root)
+            .with_context(|| format!("Migration to {} failed", migration.version))?;
+        config.workspace.version = migration.version.to_string();
+        write_config(root, &config)?;
+        applied.push((migration.version, migration.description));
+    }
+
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init;
+    use std::env;
+    use tempfile::TempDir;
+
+    fn set_version(root: &Path, version: &str) -> Result<()> {
+        let mut config = read_config_file(root)?;
+        config.workspace.version = version.to_string();
+        write_config(root, &config)
+    }
+
+    #[test]
+    fn test_migrations_end_at_current_version() {
+        assert_eq!(MIGRATIONS.last().unwrap().version, WORKSPACE_VERSION);
+        for pair in MIGRATIONS.windows(2) {
+            assert!(
+                parse_workspace_version(pair[0].version).unwrap()
+                    < parse_workspace_version(pair[1].version).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_migrate_old_workspace_to_current() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        set_version(temp_dir.path(), "0.0.1")?;
+        let first = migrate();
+        let second = migrate_workspace(temp_dir.path());
+        env::set_current_dir(&original_dir)?;
+
+        first?;
+        assert!(second?.is_empty());
+        assert_eq!(
+            read_config(temp_dir.path())?.workspace.version,
+            WORKSPACE_VERSION
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_refuses_newer_workspace() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        env::set_current_dir(&original_dir)?;
+        set_version(temp_dir.path(), "99.0.0")?;
+
+        let err = migrate_workspace(temp_dir.path()).unwrap_err();
+        assert!(err.to_string().contains("newer"));
+        assert_eq!(read_config(temp_dir.path())?.workspace.version, "99.0.0");
+
+        Ok(())
+    }
+}
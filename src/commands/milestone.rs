@@ -1,13 +1,31 @@
-use anyhow::Result;
-use chrono::Utc;
+use anyhow::{Context, Result};
+use chrono::{Months, NaiveDate, Utc};
+use serde::Serialize;
 use std::str::FromStr;
 
-use crate::fs::{ensure_dir, get_base_directory};
-use crate::models::{MilestoneMetadata, Status};
+use crate::commands::export::write_output;
+use crate::dates::{parse_date, resolve_date};
+use crate::display::{detail, format_change, format_status, success};
+use crate::fs::{ensure_dir, get_base_directory, record_activity, render_template, resolve_status};
+use crate::models::{FieldChange, Issue, Milestone, MilestoneMetadata, Priority, Status};
 use crate::parser::write_with_frontmatter;
 
-pub fn create_milestone(project_name: &str, title: &str, date: Option<&str>) -> Result<()> {
+pub fn create_milestone(
+    project_name: &str,
+    title: &str,
+    date: Option<&str>,
+    status: Option<&str>,
+    priority: Option<&str>,
+) -> Result<()> {
     let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
+
+    let date = date.map(resolve_date).transpose()?;
+    let date = date.as_deref();
+    let status = resolve_status(status)?;
+    let priority = priority
+        .map(|p| Priority::from_str(p).with_context(|| format!("Invalid priority: {}", p)))
+        .transpose()?;
 
     // Find project by name or ID
     let project = crate::fs::find_project(&base_dir, project_name)?;
@@ -17,40 +35,143 @@ pub fn create_milestone(project_name: &str, title: &str, date: Option<&str>) ->
     let milestones_dir = project_path.join("milestones");
     ensure_dir(&milestones_dir)?;
 
-    // Create sanitized filename from title
-    let filename = sanitize_filename(title);
-    let milestone_path = milestones_dir.join(format!("{}.md", filename));
-
-    if milestone_path.exists() {
+    let lowered = title.to_lowercase();
+    if crate::fs::list_milestones(&project_path)?
+        .iter()
+        .any(|m| m.metadata.title.to_lowercase() == lowered)
+    {
         return Err(anyhow::anyhow!("Milestone '{}' already exists", title));
     }
 
+    let milestone_path = unique_milestone_path(&milestones_dir, title);
+
     // Create milestone metadata
     let metadata = MilestoneMetadata {
         title: title.to_string(),
-        status: Status::Backlog,
+        status,
+        priority,
         target_date: date.map(|s| s.to_string()),
         project: Some(actual_project_name.clone()),
         created: Some(Utc::now()),
         updated: Some(Utc::now()),
     };
 
-    // Create milestone description
-    let description = format!("# {}\n\nMilestone description and objectives.\n", title);
+    // Create milestone description from the workspace template
+    let description = render_template(
+        "milestone",
+        &[
+            ("MILESTONE_TITLE", title),
+            ("TARGET_DATE", date.unwrap_or("")),
+            ("PROJECT_NAME", &actual_project_name),
+        ],
+    )?
+    .unwrap_or_else(|| format!("# {}\n\nMilestone description and objectives.\n", title));
     write_with_frontmatter(&milestone_path, &metadata, &description)?;
 
-    println!(
+    record_activity(
+        "create",
+        "milestone",
+        &format!("{}/{}", actual_project_name, title),
+        Vec::new(),
+    );
+
+    success(&format!(
         "✓ Created milestone '{}' in project '{}'",
         title, actual_project_name
-    );
+    ));
     if let Some(d) = date {
-        println!("  Target date: {}", d);
+        success(&format!("  Target date: {}", d));
+    }
+    if let Some(p) = &metadata.priority {
+        success(&format!("  Priority: {}", p));
+    }
+    detail(&format!("  File: {}", milestone_path.display()));
+
+    Ok(())
+}
+
+/// Longest series `create_milestone_series` will generate in one go (five years of months)
+const MAX_SERIES_LENGTH: u32 = 60;
+
+/// Create `count` milestones spaced a month or quarter apart, starting at `start_date`.
+/// `{n}` in the title is replaced by the 1-based position; otherwise " <n>" is appended.
+pub fn create_milestone_series(
+    project_name: &str,
+    title: &str,
+    start_date: &str,
+    every: &str,
+    count: u32,
+    status: Option<&str>,
+    priority: Option<&str>,
+) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let start = parse_date(start_date, Utc::now().date_naive())?;
+    let plan = series_plan(title, start, every, count)?;
+
+    // Check every title up front so a clash doesn't leave half a series behind
+    let project = crate::fs::find_project(&base_dir, project_name)?;
+    let existing = crate::fs::list_milestones(&project.path)?;
+    if let Some((clash, _)) = plan.iter().find(|(title, _)| {
+        existing
+            .iter()
+            .any(|m| m.metadata.title.eq_ignore_ascii_case(title))
+    }) {
+        return Err(anyhow::anyhow!("Milestone '{}' already exists", clash));
+    }
+
+    for (title, date) in &plan {
+        let date = date.format("%Y-%m-%d").to_string();
+        create_milestone(project_name, title, Some(&date), status, priority)?;
     }
 
     Ok(())
 }
 
-pub fn list_milestones(project_filter: Option<&str>) -> Result<()> {
+/// Titles and target dates for a milestone series. Each date is computed from the start
+/// date, so a series starting on the 31st stays on month ends instead of drifting.
+fn series_plan(
+    title: &str,
+    start: NaiveDate,
+    every: &str,
+    count: u32,
+) -> Result<Vec<(String, NaiveDate)>> {
+    let step = match every {
+        "month" => 1,
+        "quarter" => 3,
+        other => {
+            return Err(anyhow::anyhow!(
+                "Invalid cadence '{}'. Expected month or quarter",
+                other
+            ))
+        }
+    };
+
+    if count == 0 || count > MAX_SERIES_LENGTH {
+        return Err(anyhow::anyhow!(
+            "Invalid count {}. Expected between 1 and {}",
+            count,
+            MAX_SERIES_LENGTH
+        ));
+    }
+
+    (0..count)
+        .map(|i| {
+            let n = (i + 1).to_string();
+            let title = if title.contains("{n}") {
+                title.replace("{n}", &n)
+            } else {
+                format!("{} {}", title, n)
+            };
+            let date = start
+                .checked_add_months(Months::new(i * step))
+                .ok_or_else(|| anyhow::anyhow!("Target date out of range for '{}'", title))?;
+            Ok((title, date))
+        })
+        .collect()
+}
+
+/// Milestones with their project names, ordered by target date, priority, then title
+pub fn list_milestones(project_filter: Option<&str>) -> Result<Vec<(String, Milestone)>> {
     let base_dir = get_base_directory()?;
     let projects = if let Some(name) = project_filter {
         vec![crate::fs::find_project(&base_dir, name)?]
@@ -67,22 +188,46 @@ pub fn list_milestones(project_filter: Option<&str>) -> Result<()> {
         }
     }
 
-    if all_milestones.is_empty() {
-        println!("No milestones found.");
-        return Ok(());
-    }
-
-    // Sort by target date then title
+    // Sort by target date, then priority (highest first, unset last), then title
     all_milestones.sort_by(|a, b| {
         let date_a = a.1.metadata.target_date.as_deref().unwrap_or("9999-12-31");
         let date_b = b.1.metadata.target_date.as_deref().unwrap_or("9999-12-31");
         date_a
             .cmp(date_b)
+            .then_with(|| b.1.metadata.priority.cmp(&a.1.metadata.priority))
             .then_with(|| a.1.metadata.title.cmp(&b.1.metadata.title))
     });
 
+    Ok(all_milestones)
+}
+
+/// The result of `list_milestones` as JSON objects with `id` (`project/title`) and `project`
+pub fn milestones_to_json(milestones: &[(String, Milestone)]) -> Result<Vec<serde_json::Value>> {
+    milestones
+        .iter()
+        .map(|(project, milestone)| {
+            let mut value = serde_json::to_value(milestone)?;
+            value["id"] = format!("{}/{}", project, milestone.metadata.title).into();
+            value["project"] = project.clone().into();
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Print the result of `list_milestones`, or just the count
+pub fn print_milestones(milestones: &[(String, Milestone)], count_only: bool) {
+    if count_only {
+        println!("{}", milestones.len());
+        return;
+    }
+
+    if milestones.is_empty() {
+        println!("No milestones found.");
+        return;
+    }
+
     println!("Milestones:\n");
-    for (project_name, milestone) in all_milestones {
+    for (project_name, milestone) in milestones {
         let target = milestone
             .metadata
             .target_date
@@ -95,9 +240,10 @@ pub fn list_milestones(project_filter: Option<&str>) -> Result<()> {
             format_status(&milestone.metadata.status)
         );
         println!("    Target: {}", target);
+        if let Some(priority) = &milestone.metadata.priority {
+            println!("    Priority: {}", priority);
+        }
     }
-
-    Ok(())
 }
 
 pub fn edit_milestone(
@@ -105,51 +251,287 @@ pub fn edit_milestone(
     title: &str,
     status: Option<&str>,
     date: Option<&str>,
+    new_title: Option<&str>,
 ) -> Result<()> {
     let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
 
     // Find project by name or ID
     let project = crate::fs::find_project(&base_dir, project_name)?;
     let project_path = project.path;
+    let actual_project_name = project.metadata.name;
 
-    // Find the milestone by title
-    let milestones = crate::fs::list_milestones(&project_path)?;
-    let milestone = milestones
-        .into_iter()
-        .find(|m| m.metadata.title == title)
-        .ok_or_else(|| anyhow::anyhow!("Milestone '{}' not found", title))?;
+    let milestone = find_milestone(&project_path, title)?;
+    let title = milestone.metadata.title.clone();
+    let title = title.as_str();
 
     let mut metadata = milestone.metadata;
-    let mut changed = false;
+    let mut description = milestone.description;
+    let mut milestone_path = milestone.path.clone();
+    let mut changes = Vec::new();
+
+    if let Some(new_title) = new_title {
+        if new_title.trim().is_empty() {
+            return Err(anyhow::anyhow!("Milestone title cannot be empty"));
+        }
+
+        let lowered = new_title.to_lowercase();
+        if crate::fs::list_milestones(&project_path)?
+            .iter()
+            .any(|m| m.path != milestone.path && m.metadata.title.to_lowercase() == lowered)
+        {
+            return Err(anyhow::anyhow!("Milestone '{}' already exists", new_title));
+        }
+
+        let milestones_dir = project_path.join("milestones");
+        let new_path = milestones_dir.join(format!("{}.md", sanitize_filename(new_title)));
+        let new_path = if new_path == milestone.path {
+            new_path
+        } else {
+            unique_milestone_path(&milestones_dir, new_title)
+        };
+
+        // Keep the generated heading in sync with the title
+        let old_heading = format!("# {}", title);
+        if description.lines().next() == Some(old_heading.as_str()) {
+            description = format!("# {}{}", new_title, &description[old_heading.len()..]);
+        }
+
+        changes.push(FieldChange::new(
+            "title",
+            Some(metadata.title.clone()),
+            Some(new_title.to_string()),
+        ));
+        metadata.title = new_title.to_string();
+        milestone_path = new_path;
+    }
 
     if let Some(s) = status {
+        let previous = metadata.status;
         metadata.status = Status::from_str(s)?;
-        changed = true;
-        println!("Updated status to: {}", format_status(&metadata.status));
+        changes.push(FieldChange::new(
+            "status",
+            Some(previous.to_string()),
+            Some(metadata.status.to_string()),
+        ));
     }
 
     if let Some(d) = date {
-        metadata.target_date = Some(d.to_string());
-        changed = true;
-        println!("Updated target date to: {}", d);
+        let previous = metadata.target_date.clone();
+        if d.is_empty() {
+            metadata.target_date = None;
+        } else {
+            metadata.target_date = Some(resolve_date(d)?);
+        }
+        changes.push(FieldChange::new(
+            "target_date",
+            previous,
+            metadata.target_date.clone(),
+        ));
     }
 
-    if !changed {
+    if changes.is_empty() {
         return Err(anyhow::anyhow!(
-            "No changes specified. Use --status or --date"
+            "No changes specified. Use --status, --date, or --title"
         ));
     }
 
     metadata.updated = Some(Utc::now());
 
-    // Write back to file
-    write_with_frontmatter(&milestone.path, &metadata, &milestone.description)?;
+    // Write back to file, moving it if the title changed
+    write_with_frontmatter(&milestone_path, &metadata, &description)?;
+    if milestone_path != milestone.path {
+        std::fs::remove_file(&milestone.path)?;
+        crate::fs::mark_touched(&milestone.path);
+    }
 
-    println!("✓ Updated milestone '{}'", title);
+    // Only once every input has been validated and the milestone itself is written
+    if metadata.title != title {
+        let renamed = rename_milestone_references(&project_path, title, &metadata.title)?;
+        if renamed > 0 {
+            success(&format!("  Updated milestone on {} issue(s)", renamed));
+        }
+    }
+
+    for change in &changes {
+        success(&format_change(change));
+    }
+    record_activity(
+        "edit",
+        "milestone",
+        &format!("{}/{}", actual_project_name, metadata.title),
+        changes,
+    );
+
+    success(&format!("✓ Updated milestone '{}'", metadata.title));
 
     Ok(())
 }
 
+/// Remaining open issues on one day of a milestone burndown
+#[derive(Debug, PartialEq, Serialize)]
+struct BurndownPoint {
+    date: NaiveDate,
+    /// Issues created on or before this day
+    total: usize,
+    /// Issues completed or cancelled on or before this day
+    closed: usize,
+    remaining: usize,
+}
+
+/// Emit per-day burndown data for a milestone as CSV or JSON
+///
+/// Pillar only keeps each issue's latest `updated` timestamp, so a completed or
+/// cancelled issue counts as closed from its last update. An issue edited after
+/// it was completed therefore shows up as closing later than it really did.
+pub fn burndown(project_name: &str, title: &str, format: &str, output: Option<&str>) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let project = crate::fs::find_project(&base_dir, project_name)?;
+
+    let title = find_milestone(&project.path, title)?.metadata.title;
+    let title = title.as_str();
+
+    let issues: Vec<Issue> = crate::fs::list_issues(&project.path)?
+        .into_iter()
+        .filter(|i| i.metadata.milestone.as_deref() == Some(title))
+        .collect();
+    let points = burndown_points(&issues, Utc::now().date_naive());
+
+    let content = match format.to_lowercase().as_str() {
+        "json" => format!("{}\n", serde_json::to_string_pretty(&points)?),
+        "csv" => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(["date", "total", "closed", "remaining"])?;
+            for p in &points {
+                writer.write_record([
+                    p.date.to_string(),
+                    p.total.to_string(),
+                    p.closed.to_string(),
+                    p.remaining.to_string(),
+                ])?;
+            }
+            let bytes = writer
+                .into_inner()
+                .map_err(|e| anyhow::anyhow!("Failed to write CSV: {}", e))?;
+            String::from_utf8(bytes)?
+        }
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Unsupported format: {}. Use 'json' or 'csv'",
+                format
+            ))
+        }
+    };
+
+    write_output(&content, output)
+}
+
+/// One point per day from the first issue's creation through `today`
+fn burndown_points(issues: &[Issue], today: NaiveDate) -> Vec<BurndownPoint> {
+    let created = |i: &Issue| i.metadata.created.map(|c| c.date_naive());
+    let closed = |i: &Issue| match i.metadata.status {
+        Status::Completed | Status::Cancelled => {
+            Some(i.metadata.updated.map(|u| u.date_naive()).unwrap_or(today))
+        }
+        _ => None,
+    };
+
+    let Some(start) = issues.iter().filter_map(created).min() else {
+        return Vec::new();
+    };
+
+    start
+        .iter_days()
+        .take_while(|day| *day <= today)
+        .map(|day| {
+            // Issues without a created date count from the start
+            let total = issues
+                .iter()
+                .filter(|i| created(i).is_none_or(|c| c <= day))
+                .count();
+            let closed = issues
+                .iter()
+                .filter(|i| created(i).is_none_or(|c| c <= day))
+                .filter(|i| closed(i).is_some_and(|c| c <= day))
+                .count();
+            BurndownPoint {
+                date: day,
+                total,
+                closed,
+                remaining: total - closed,
+            }
+        })
+        .collect()
+}
+
+/// Find a milestone by title, falling back to a case-insensitive match
+pub(crate) fn find_milestone(project_path: &std::path::Path, title: &str) -> Result<Milestone> {
+    let milestones = crate::fs::list_milestones(project_path)?;
+
+    let (mut exact, others): (Vec<Milestone>, Vec<Milestone>) = milestones
+        .into_iter()
+        .partition(|m| m.metadata.title == title);
+    if !exact.is_empty() {
+        return Ok(exact.remove(0));
+    }
+
+    let lowered = title.to_lowercase();
+    let mut matches: Vec<Milestone> = others
+        .into_iter()
+        .filter(|m| m.metadata.title.to_lowercase() == lowered)
+        .collect();
+    match matches.len() {
+        0 => Err(anyhow::anyhow!("Milestone '{}' not found", title)),
+        1 => Ok(matches.remove(0)),
+        _ => Err(anyhow::anyhow!(
+            "Milestone '{}' matches several milestones that differ only in case: {}",
+            title,
+            matches
+                .iter()
+                .map(|m| m.metadata.title.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Point every issue in a project that references `old_title` at `new_title`
+/// Returns the number of issues rewritten
+fn rename_milestone_references(
+    project_path: &std::path::Path,
+    old_title: &str,
+    new_title: &str,
+) -> Result<usize> {
+    let mut renamed = 0;
+
+    for issue in crate::fs::list_issues(project_path)? {
+        if issue.metadata.milestone.as_deref() != Some(old_title) {
+            continue;
+        }
+
+        let mut metadata = issue.metadata;
+        metadata.milestone = Some(new_title.to_string());
+        metadata.updated = Some(Utc::now());
+        write_with_frontmatter(&issue.path, &metadata, &issue.description)?;
+        renamed += 1;
+    }
+
+    Ok(renamed)
+}
+
+/// Pick `<slug>.md` for a title, adding `-2`, `-3`, ... when a different milestone
+/// already uses that slug (e.g. "v1.0" and "v1,0" both sanitize to `v1-0`)
+fn unique_milestone_path(milestones_dir: &std::path::Path, title: &str) -> std::path::PathBuf {
+    let slug = sanitize_filename(title);
+    let mut path = milestones_dir.join(format!("{}.md", slug));
+    let mut suffix = 2;
+    while path.exists() {
+        path = milestones_dir.join(format!("{}-{}.md", slug, suffix));
+        suffix += 1;
+    }
+    path
+}
+
 fn sanitize_filename(s: &str) -> String {
     s.to_lowercase()
         .replace(|c: char| !c.is_alphanumeric() && c != '-' && c != '_', "-")
@@ -158,19 +540,6 @@ fn sanitize_filename(s: &str) -> String {
         .to_string()
 }
 
-fn format_status(status: &Status) -> String {
-    use colored::Colorize;
-
-    match status {
-        Status::Backlog => "backlog".white(),
-        Status::Todo => "todo".cyan(),
-        Status::InProgress => "in-progress".yellow(),
-        Status::Completed => "completed".green(),
-        Status::Cancelled => "cancelled".red(),
-    }
-    .to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,8 +551,8 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        crate::commands::init(None)?;
-        crate::commands::create_project("test-project", None, "medium")?;
+        crate::commands::init(None, false)?;
+        crate::commands::create_project("test-project", None, Some("medium"))?;
         env::set_current_dir(&original_dir)?;
 
         Ok((temp_dir, "test-project".to_string()))
@@ -195,7 +564,7 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = create_milestone(&project_name, "v1.0", Some("2025-12-31"));
+        let result = create_milestone(&project_name, "v1.0", Some("2025-12-31"), None, None);
         env::set_current_dir(&original_dir)?;
 
         result?;
@@ -225,12 +594,13 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        create_milestone(&project_name, "v1.0", Some("2025-12-31"))?;
+        create_milestone(&project_name, "v1.0", Some("2025-12-31"), None, None)?;
         let result = edit_milestone(
             &project_name,
             "v1.0",
             Some("in-progress"),
             Some("2026-01-15"),
+            None,
         );
         env::set_current_dir(&original_dir)?;
 
@@ -252,6 +622,175 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_edit_milestone_case_insensitive_title() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_milestone(&project_name, "Beta Release", None, None, None)?;
+        let result = edit_milestone(
+            &project_name.to_uppercase(),
+            "beta release",
+            Some("completed"),
+            None,
+            None,
+        );
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let milestone = find_milestone(&temp_dir.path().join(&project_name), "BETA RELEASE")?;
+        assert_eq!(milestone.metadata.title, "Beta Release");
+        assert_eq!(milestone.metadata.status, Status::Completed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_milestone_slug_collision() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let first = create_milestone(&project_name, "v1.0", None, None, None);
+        let second = create_milestone(&project_name, "v1,0", None, None, None);
+        let third = create_milestone(&project_name, "v1 0", None, None, None);
+        let duplicate = create_milestone(&project_name, "V1.0", None, None, None);
+        env::set_current_dir(&original_dir)?;
+
+        first?;
+        second?;
+        third?;
+        assert!(duplicate.is_err());
+
+        let milestones_dir = temp_dir.path().join(&project_name).join("milestones");
+        let title_at = |file: &str| -> Result<String> {
+            Ok(crate::parser::read_milestone(milestones_dir.join(file))?
+                .metadata
+                .title)
+        };
+        assert_eq!(title_at("v1-0.md")?, "v1.0");
+        assert_eq!(title_at("v1-0-2.md")?, "v1,0");
+        assert_eq!(title_at("v1-0-3.md")?, "v1 0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_milestone_date_validation() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let bad_create = create_milestone(&project_name, "v1.0", Some("2025-13-45"), None, None);
+        let words = create_milestone(&project_name, "v1.0", Some("next week"), None, None);
+        create_milestone(&project_name, "v1.0", Some("2025-12-31"), None, None)?;
+        let bad_edit = edit_milestone(&project_name, "v1.0", None, Some("31/12/2025"), None);
+        let cleared = edit_milestone(&project_name, "v1.0", None, Some(""), None);
+        env::set_current_dir(&original_dir)?;
+
+        assert!(bad_create
+            .unwrap_err()
+            .to_string()
+            .contains("Expected YYYY-MM-DD"));
+        assert!(words.is_err());
+        assert!(bad_edit.is_err());
+        cleared?;
+
+        let milestone = crate::parser::read_milestone(
+            temp_dir
+                .path()
+                .join(&project_name)
+                .join("milestones")
+                .join("v1-0.md"),
+        )?;
+        assert_eq!(milestone.metadata.target_date, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_milestone_updates_issues() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_milestone(&project_name, "v1.0", None, None, None)?;
+        for title in ["First", "Second"] {
+            crate::commands::create_issue(
+                &project_name,
+                title,
+                &crate::commands::NewIssue {
+                    priority: Some("medium"),
+                    milestone: Some("v1.0"),
+                    ..Default::default()
+                },
+            )?;
+        }
+        crate::commands::create_issue(
+            &project_name,
+            "Other",
+            &crate::commands::NewIssue {
+                priority: Some("medium"),
+                milestone: Some("v2.0"),
+                ..Default::default()
+            },
+        )?;
+        let result = edit_milestone(&project_name, "v1.0", None, None, Some("Launch"));
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let milestones_dir = temp_dir.path().join(&project_name).join("milestones");
+        assert!(!milestones_dir.join("v1-0.md").exists());
+        let milestone = crate::parser::read_milestone(milestones_dir.join("launch.md"))?;
+        assert_eq!(milestone.metadata.title, "Launch");
+        assert!(milestone.description.starts_with("# Launch"));
+
+        let issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        let launch = issues
+            .iter()
+            .filter(|i| i.metadata.milestone.as_deref() == Some("Launch"))
+            .count();
+        assert_eq!(launch, 2);
+        assert!(issues
+            .iter()
+            .any(|i| i.metadata.milestone.as_deref() == Some("v2.0")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_milestone_with_invalid_status_leaves_issues() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_milestone(&project_name, "v1.0", None, None, None)?;
+        crate::commands::create_issue(
+            &project_name,
+            "First",
+            &crate::commands::NewIssue {
+                priority: Some("medium"),
+                milestone: Some("v1.0"),
+                ..Default::default()
+            },
+        )?;
+        let result = edit_milestone(&project_name, "v1.0", Some("bogus"), None, Some("Launch"));
+        env::set_current_dir(&original_dir)?;
+
+        assert!(result.is_err());
+
+        let milestones_dir = temp_dir.path().join(&project_name).join("milestones");
+        assert!(milestones_dir.join("v1-0.md").exists());
+        assert!(!milestones_dir.join("launch.md").exists());
+        let issues = crate::fs::list_issues(temp_dir.path().join(&project_name))?;
+        assert_eq!(issues[0].metadata.milestone.as_deref(), Some("v1.0"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_sanitize_filename() {
         assert_eq!(sanitize_filename("v1.0"), "v1-0");
@@ -268,7 +807,202 @@ mod tests {
         let result = list_milestones(None);
         env::set_current_dir(&original_dir)?;
 
+        let milestones = result?;
+        assert!(milestones.is_empty());
+        print_milestones(&milestones, false);
+        Ok(())
+    }
+
+    #[test]
+    fn test_series_plan() -> Result<()> {
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+        let quarters = series_plan("Q{n} 2025", date("2025-03-31"), "quarter", 4)?;
+        assert_eq!(
+            quarters,
+            vec![
+                ("Q1 2025".to_string(), date("2025-03-31")),
+                ("Q2 2025".to_string(), date("2025-06-30")),
+                ("Q3 2025".to_string(), date("2025-09-30")),
+                ("Q4 2025".to_string(), date("2025-12-31")),
+            ]
+        );
+
+        let months = series_plan("Sprint", date("2025-11-15"), "month", 3)?;
+        assert_eq!(months[0], ("Sprint 1".to_string(), date("2025-11-15")));
+        assert_eq!(months[2], ("Sprint 3".to_string(), date("2026-01-15")));
+
+        assert!(series_plan("Sprint", date("2025-01-01"), "month", 0).is_err());
+        assert!(series_plan("Sprint", date("2025-01-01"), "month", 61).is_err());
+        assert!(series_plan("Sprint", date("2025-01-01"), "fortnight", 2).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_milestone_series() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_milestone(&project_name, "Q3 2025", None, None, None)?;
+        let clash = create_milestone_series(
+            &project_name,
+            "Q{n} 2025",
+            "2025-03-31",
+            "quarter",
+            4,
+            None,
+            None,
+        );
+        let bad_date =
+            create_milestone_series(&project_name, "M", "2025-02-30", "month", 2, None, None);
+        let created = create_milestone_series(
+            &project_name,
+            "Sprint",
+            "2025-01-31",
+            "month",
+            2,
+            Some("todo"),
+            None,
+        );
+        env::set_current_dir(&original_dir)?;
+
+        assert!(clash.unwrap_err().to_string().contains("Q3 2025"));
+        assert!(bad_date.is_err());
+        created?;
+
+        let project_path = temp_dir.path().join(&project_name);
+        let mut milestones = crate::fs::list_milestones(&project_path)?;
+        milestones.sort_by(|a, b| a.metadata.title.cmp(&b.metadata.title));
+        let summary: Vec<_> = milestones
+            .iter()
+            .map(|m| {
+                (
+                    m.metadata.title.as_str(),
+                    m.metadata.target_date.as_deref(),
+                    m.metadata.status,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Q3 2025", None, Status::Backlog),
+                ("Sprint 1", Some("2025-01-31"), Status::Todo),
+                ("Sprint 2", Some("2025-02-28"), Status::Todo),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_milestone_with_status_and_priority() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let result = create_milestone(
+            &project_name,
+            "Sprint 1",
+            None,
+            Some("in-progress"),
+            Some("high"),
+        );
+        let plain = create_milestone(&project_name, "Sprint 2", None, None, None);
+        let invalid = create_milestone(&project_name, "Sprint 3", None, Some("someday"), None);
+        env::set_current_dir(&original_dir)?;
+
         result?;
+        plain?;
+        assert!(invalid.is_err());
+
+        let project_path = temp_dir.path().join(&project_name);
+        let mut milestones = crate::fs::list_milestones(&project_path)?;
+        milestones.sort_by(|a, b| a.metadata.title.cmp(&b.metadata.title));
+        assert_eq!(milestones.len(), 2);
+        assert_eq!(milestones[0].metadata.status, Status::InProgress);
+        assert_eq!(milestones[0].metadata.priority, Some(Priority::High));
+        assert_eq!(milestones[1].metadata.status, Status::Backlog);
+        assert_eq!(milestones[1].metadata.priority, None);
+
+        // Files without a priority stay free of the field
+        let raw = std::fs::read_to_string(&milestones[1].path)?;
+        assert!(!raw.contains("priority"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_burndown_points() {
+        use crate::models::{IssueMetadata, Priority};
+        use chrono::TimeZone;
+
+        let at = |day: u32| Some(Utc.with_ymd_and_hms(2026, 3, day, 12, 0, 0).unwrap());
+        let issue = |created, updated, status| Issue {
+            metadata: IssueMetadata {
+                title: "Issue".to_string(),
+                status,
+                priority: Priority::Medium,
+                severity: None,
+                project: None,
+                milestone: Some("v1".to_string()),
+                tags: Vec::new(),
+                assignee: None,
+                reporter: None,
+                url: None,
+                created,
+                updated,
+            },
+            description: String::new(),
+            path: Default::default(),
+        };
+        let issues = vec![
+            issue(at(1), at(3), Status::Completed),
+            issue(at(1), at(1), Status::InProgress),
+            issue(at(2), at(4), Status::Cancelled),
+        ];
+
+        let points = burndown_points(&issues, NaiveDate::from_ymd_opt(2026, 3, 4).unwrap());
+        let remaining: Vec<usize> = points.iter().map(|p| p.remaining).collect();
+        assert_eq!(remaining, vec![2, 3, 2, 1]);
+        assert_eq!(points[0].date, NaiveDate::from_ymd_opt(2026, 3, 1).unwrap());
+        assert_eq!(points[3].total, 3);
+        assert_eq!(points[3].closed, 2);
+
+        assert!(burndown_points(&[], NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_burndown_writes_csv() -> Result<()> {
+        let (temp_dir, project_name) = setup_workspace_with_project()?;
+        let original_dir = env::current_dir()?;
+        let output = temp_dir.path().join("burndown.csv");
+
+        env::set_current_dir(temp_dir.path())?;
+        create_milestone(&project_name, "v1", None, None, None)?;
+        crate::commands::create_issue(
+            &project_name,
+            "Task",
+            &crate::commands::NewIssue {
+                priority: Some("medium"),
+                milestone: Some("v1"),
+                ..Default::default()
+            },
+        )?;
+        let result = burndown(&project_name, "v1", "csv", Some(output.to_str().unwrap()));
+        let missing = burndown(&project_name, "v2", "csv", None);
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+        assert!(missing.is_err());
+
+        let csv = std::fs::read_to_string(&output)?;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "date,total,closed,remaining");
+        assert!(lines[1].ends_with(",1,0,1"));
+
         Ok(())
     }
 }
@@ -1,16 +1,30 @@
 pub mod comment;
+pub mod config;
+pub mod doctor;
 pub mod export;
+pub mod import;
 pub mod init;
 pub mod issue;
+pub mod log;
+pub mod manpages;
+pub mod migrate;
 pub mod milestone;
+pub mod next;
 pub mod project;
+pub mod release;
+pub mod schema;
 pub mod search;
+pub mod stats;
+pub mod tag;
 pub mod view;
 pub mod webui;
 
 pub use init::init;
-pub use issue::{create_issue, edit_issue, list_issues, show_issue};
+pub use issue::{
+    clone_issue, create_issue, edit_issue, issue_history, list_issues, move_issue, show_issue,
+    IssueEdits, NewIssue,
+};
 pub use milestone::{create_milestone, edit_milestone, list_milestones};
-pub use project::{create_project, edit_project, list_projects, show_project};
+pub use project::{create_project, edit_project, list_projects, rename_project, show_project};
 pub use view::{board, status};
 pub use webui::run_ui;
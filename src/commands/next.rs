@@ -0,0 +1,136 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+
+use crate::commands::show_issue;
+use crate::fs::{
+    extract_issue_id, find_project, get_base_directory, issue_project_dir, list_all_issues,
+    list_issues,
+};
+use crate::models::{by_priority_then_name, Issue, Status};
+
+/// Show the issue to work on next: the highest-priority Todo or In Progress issue,
+/// oldest first among equal priorities.
+/// Pillar has no issue dependencies, so no issue is ever considered blocked.
+pub fn next(project: Option<&str>) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let issues = match project {
+        Some(project) => list_issues(find_project(&base_dir, project)?.path)?,
+        None => list_all_issues(&base_dir)?,
+    };
+
+    let Some(issue) = pick_next(issues) else {
+        println!("Nothing to work on: no todo or in-progress issues.");
+        return Ok(());
+    };
+
+    show_issue(&format!(
+        "{}/{}",
+        issue_project_dir(&issue),
+        extract_issue_id(&issue.path)
+    ))
+}
+
+/// The most urgent actionable issue, if any
+fn pick_next(issues: Vec<Issue>) -> Option<Issue> {
+    issues
+        .into_iter()
+        .filter(|i| matches!(i.metadata.status, Status::Todo | Status::InProgress))
+        .min_by(compare_actionable)
+}
+
+/// The standard list order, with age standing in for the name: highest priority first,
+/// then oldest `created` (issues without one last), then title
+fn compare_actionable(a: &Issue, b: &Issue) -> Ordering {
+    by_priority_then_name(
+        (a.metadata.priority, &age_key(a)),
+        (b.metadata.priority, &age_key(b)),
+    )
+    .then_with(|| a.metadata.title.cmp(&b.metadata.title))
+}
+
+/// `created`, ordered oldest first with issues that have none after every timestamp
+fn age_key(issue: &Issue) -> (bool, Option<DateTime<Utc>>) {
+    (issue.metadata.created.is_none(), issue.metadata.created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{IssueMetadata, Priority};
+    use chrono::{TimeZone, Utc};
+    use std::path::PathBuf;
+
+    fn issue(title: &str, status: Status, priority: Priority, created_day: Option<u32>) -> Issue {
+        Issue {
+            metadata: IssueMetadata {
+                title: title.to_string(),
+                status,
+                priority,
+                severity: None,
+                project: None,
+                milestone: None,
+                tags: vec![],
+                assignee: None,
+                reporter: None,
+                url: None,
+                created: created_day.map(|d| Utc.with_ymd_and_hms(2026, 1, d, 9, 0, 0).unwrap()),
+                updated: None,
+            },
+            description: String::new(),
+            path: PathBuf::from(format!("p/issues/{}.md", title)),
+        }
+    }
+
+    #[test]
+    fn test_pick_next() {
+        let pick = |issues: Vec<Issue>| pick_next(issues).map(|i| i.metadata.title);
+
+        // Completed, backlog, and cancelled issues are never picked
+        assert_eq!(
+            pick(vec![
+                issue("done", Status::Completed, Priority::Urgent, Some(1)),
+                issue("later", Status::Backlog, Priority::Urgent, Some(1)),
+                issue("dropped", Status::Cancelled, Priority::Urgent, Some(1)),
+            ]),
+            None
+        );
+
+        assert_eq!(
+            pick(vec![
+                issue("low", Status::Todo, Priority::Low, Some(1)),
+                issue("new-high", Status::Todo, Priority::High, Some(20)),
+                issue("undated-high", Status::Todo, Priority::High, None),
+                issue("old-high", Status::InProgress, Priority::High, Some(5)),
+            ]),
+            Some("old-high".to_string())
+        );
+
+        // Same priority and age: the title decides
+        assert_eq!(
+            pick(vec![
+                issue("b", Status::Todo, Priority::Medium, Some(3)),
+                issue("a", Status::Todo, Priority::Medium, Some(3)),
+            ]),
+            Some("a".to_string())
+        );
+    }
+
+    #[test]
+    fn test_next_rejects_unknown_project() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let original_dir = std::env::current_dir()?;
+
+        std::env::set_current_dir(temp_dir.path())?;
+        crate::commands::init(None, false)?;
+        crate::commands::create_project("alpha", None, None)?;
+        let typo = next(Some("alpah"));
+        let known = next(Some("alpha"));
+        std::env::set_current_dir(&original_dir)?;
+
+        assert!(typo.is_err());
+        known?;
+
+        Ok(())
+    }
+}
@@ -2,14 +2,33 @@ use anyhow::{Context, Result};
 use chrono::Utc;
 use std::str::FromStr;
 
-use crate::fs::{ensure_dir, get_base_directory, list_projects as list_all};
-use crate::models::{Priority, ProjectMetadata, Status};
+use crate::display::{format_change, format_priority, format_status, success};
+use crate::fs::{
+    ensure_dir, get_base_directory, list_projects as list_all, record_activity, render_template,
+    resolve_priority, resolve_status,
+};
+use crate::models::{
+    by_priority_then_name, FieldChange, Issue, Priority, Project, ProjectMetadata, Status,
+};
 use crate::parser::write_with_frontmatter;
 
-pub fn create_project(name: &str, project_id: Option<&str>, priority: &str) -> Result<()> {
+/// `inbox` names the issues without a project, so no project can be called that
+fn check_not_reserved(name: &str) -> Result<()> {
+    if name.eq_ignore_ascii_case(crate::fs::INBOX) {
+        return Err(anyhow::anyhow!(
+            "'{}' is reserved for issues without a project",
+            name
+        ));
+    }
+    Ok(())
+}
+
+pub fn create_project(name: &str, project_id: Option<&str>, priority: Option<&str>) -> Result<()> {
     let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
     let project_path = base_dir.join(name);
 
+    check_not_reserved(name)?;
     if project_path.exists() {
         return Err(anyhow::anyhow!("Project '{}' already exists", name));
     }
@@ -39,37 +58,46 @@ pub fn create_project(name: &str, project_id: Option<&str>, priority: &str) -> R
     ensure_dir(project_path.join("milestones"))?;
     ensure_dir(project_path.join("issues"))?;
 
-    // Parse priority
-    let priority =
-        Priority::from_str(priority).with_context(|| format!("Invalid priority: {}", priority))?;
+    let priority = resolve_priority(priority)?;
+    let status = resolve_status(None)?;
 
     // Create project metadata
     let metadata = ProjectMetadata {
         name: name.to_string(),
         project_id,
-        status: Status::Backlog,
+        status,
         priority,
         created: Some(Utc::now()),
         updated: Some(Utc::now()),
     };
 
-    // Create README.md
-    let description = format!(
-        "# {}\n\nProject description goes here.\n\n## Goals\n\n- Goal 1\n- Goal 2\n",
-        name
-    );
+    // Create README.md from the workspace template
+    let description = render_template("project", &[("PROJECT_NAME", name)])?.unwrap_or_else(|| {
+        format!(
+            "# {}\n\nProject description goes here.\n\n## Goals\n\n- Goal 1\n- Goal 2\n",
+            name
+        )
+    });
     write_with_frontmatter(project_path.join("README.md"), &metadata, &description)?;
 
+    record_activity("create", "project", name, Vec::new());
+
     let id_display = metadata.project_id.as_ref().unwrap();
-    println!(
+    success(&format!(
         "✓ Created project '{}' (ID: {}) at {}",
         name,
         id_display,
         project_path.display()
-    );
-    println!("\nNext steps:");
-    println!("  pillar milestone create {} <milestone-name>", id_display);
-    println!("  pillar issue create {} <issue-title>", id_display);
+    ));
+    success("\nNext steps:");
+    success(&format!(
+        "  pillar milestone create {} <milestone-name>",
+        id_display
+    ));
+    success(&format!(
+        "  pillar issue create {} <issue-title>",
+        id_display
+    ));
 
     Ok(())
 }
@@ -113,7 +141,11 @@ fn generate_default_project_id(name: &str) -> String {
     }
 }
 
-pub fn list_projects(status_filter: Option<&str>, priority_filter: Option<&str>) -> Result<()> {
+/// Projects matching the filters, highest priority first
+pub fn list_projects(
+    status_filter: Option<&str>,
+    priority_filter: Option<&str>,
+) -> Result<Vec<Project>> {
     let base_dir = get_base_directory()?;
     let mut projects = list_all(&base_dir)?;
 
@@ -139,19 +171,28 @@ pub fn list_projects(status_filter: Option<&str>, priority_filter: Option<&str>)
         projects.retain(|p| p.metadata.priority == priority);
     }
 
+    projects.sort_by(|a, b| {
+        by_priority_then_name(
+            (a.metadata.priority, &a.metadata.name),
+            (b.metadata.priority, &b.metadata.name),
+        )
+    });
+
+    Ok(projects)
+}
+
+/// Print the result of `list_projects`, or just the count
+pub fn print_projects(projects: &[Project], count_only: bool) {
+    if count_only {
+        println!("{}", projects.len());
+        return;
+    }
+
     if projects.is_empty() {
         println!("No projects found.");
-        return Ok(());
+        return;
     }
 
-    // Sort by priority (descending) then name
-    projects.sort_by(|a, b| {
-        b.metadata
-            .priority
-            .cmp(&a.metadata.priority)
-            .then_with(|| a.metadata.name.cmp(&b.metadata.name))
-    });
-
     println!("Projects:\n");
     for project in projects {
         let issues = crate::fs::list_issues(&project.path).unwrap_or_default();
@@ -176,8 +217,6 @@ pub fn list_projects(status_filter: Option<&str>, priority_filter: Option<&str>)
             milestones.len()
         );
     }
-
-    Ok(())
 }
 
 pub fn show_project(name: &str) -> Result<()> {
@@ -196,6 +235,9 @@ pub fn show_project(name: &str) -> Result<()> {
     println!("Project: {}{}", project.metadata.name, id_display);
     println!("Status: {}", format_status(&project.metadata.status));
     println!("Priority: {}", format_priority(&project.metadata.priority));
+    if !issues.is_empty() {
+        println!("Issues by priority: {}", priority_breakdown(&issues));
+    }
     println!("\n{}", project.description);
 
     // Show milestones
@@ -256,27 +298,33 @@ pub fn show_project(name: &str) -> Result<()> {
 
 pub fn edit_project(name: &str, status: Option<&str>, priority: Option<&str>) -> Result<()> {
     let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
     let project = crate::fs::find_project(&base_dir, name)?;
 
     let mut metadata = project.metadata;
-    let mut changed = false;
+    let mut changes = Vec::new();
 
     if let Some(s) = status {
+        let previous = metadata.status;
         metadata.status = Status::from_str(s)?;
-        changed = true;
-        println!("Updated status to: {}", format_status(&metadata.status));
+        changes.push(FieldChange::new(
+            "status",
+            Some(previous.to_string()),
+            Some(metadata.status.to_string()),
+        ));
     }
 
     if let Some(p) = priority {
+        let previous = metadata.priority;
         metadata.priority = Priority::from_str(p)?;
-        changed = true;
-        println!(
-            "Updated priority to: {}",
-            format_priority(&metadata.priority)
-        );
+        changes.push(FieldChange::new(
+            "priority",
+            Some(previous.to_string()),
+            Some(metadata.priority.to_string()),
+        ));
     }
 
-    if !changed {
+    if changes.is_empty() {
         return Err(anyhow::anyhow!(
             "No changes specified. Use --status or --priority"
         ));
@@ -291,34 +339,109 @@ pub fn edit_project(name: &str, status: Option<&str>, priority: Option<&str>) ->
         &project.description,
     )?;
 
-    println!("✓ Updated project '{}'", name);
+    for change in &changes {
+        success(&format_change(change));
+    }
+    record_activity("edit", "project", &metadata.name, changes);
+
+    success(&format!("✓ Updated project '{}'", name));
 
     Ok(())
 }
 
-fn format_status(status: &Status) -> String {
-    use colored::Colorize;
+/// Summarize issue counts per priority, highest first, e.g. "2 urgent, 1 low"
+fn priority_breakdown(issues: &[Issue]) -> String {
+    Priority::ALL
+        .iter()
+        .rev()
+        .filter_map(|priority| {
+            let count = issues
+                .iter()
+                .filter(|i| i.metadata.priority == *priority)
+                .count();
+            (count > 0).then(|| format!("{} {}", count, format_priority(priority)))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn rename_project(old_name: &str, new_name: &str) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
+    let project = crate::fs::find_project(&base_dir, old_name)?;
+
+    if new_name.trim().is_empty() || new_name.starts_with('.') || new_name.contains(['/', '\\']) {
+        return Err(anyhow::anyhow!("Invalid project name: '{}'", new_name));
+    }
+    check_not_reserved(new_name)?;
 
-    match status {
-        Status::Backlog => "backlog".white(),
-        Status::Todo => "todo".cyan(),
-        Status::InProgress => "in-progress".yellow(),
-        Status::Completed => "completed".green(),
-        Status::Cancelled => "cancelled".red(),
+    let new_path = base_dir.join(new_name);
+    if new_path.exists() {
+        return Err(anyhow::anyhow!("Project '{}' already exists", new_name));
     }
-    .to_string()
-}
 
-fn format_priority(priority: &Priority) -> String {
-    use colored::Colorize;
+    let previous_name = project.metadata.name.clone();
+    let mut metadata = project.metadata;
 
-    match priority {
-        Priority::Low => "low".white(),
-        Priority::Medium => "medium".cyan(),
-        Priority::High => "high".yellow(),
-        Priority::Urgent => "urgent".red(),
+    // Only regenerate the project ID if it was derived from the old name
+    if metadata.project_id.as_deref() == Some(generate_default_project_id(&previous_name).as_str())
+    {
+        let new_id = generate_default_project_id(new_name);
+        let in_use = list_all(&base_dir)?.iter().any(|p| {
+            p.path != project.path && p.metadata.project_id.as_deref() == Some(new_id.as_str())
+        });
+        if !in_use {
+            metadata.project_id = Some(new_id);
+        }
     }
-    .to_string()
+
+    std::fs::rename(&project.path, &new_path).with_context(|| {
+        format!(
+            "Failed to move {} to {}",
+            project.path.display(),
+            new_path.display()
+        )
+    })?;
+    crate::fs::mark_touched(&project.path);
+    crate::fs::mark_touched(&new_path);
+
+    metadata.name = new_name.to_string();
+    metadata.updated = Some(Utc::now());
+    write_with_frontmatter(new_path.join("README.md"), &metadata, &project.description)?;
+
+    // Rewrite the project reference in every child entity
+    for milestone in crate::fs::list_milestones(&new_path)? {
+        let mut child = milestone.metadata;
+        child.project = Some(new_name.to_string());
+        child.updated = Some(Utc::now());
+        write_with_frontmatter(&milestone.path, &child, &milestone.description)?;
+    }
+    for issue in crate::fs::list_issues(&new_path)? {
+        let mut child = issue.metadata;
+        child.project = Some(new_name.to_string());
+        child.updated = Some(Utc::now());
+        write_with_frontmatter(&issue.path, &child, &issue.description)?;
+    }
+
+    record_activity(
+        "rename",
+        "project",
+        new_name,
+        vec![FieldChange::new(
+            "name",
+            Some(previous_name.clone()),
+            Some(new_name.to_string()),
+        )],
+    );
+
+    success(&format!(
+        "✓ Renamed project '{}' to '{}' (ID: {})",
+        previous_name,
+        new_name,
+        metadata.project_id.as_deref().unwrap_or("none")
+    ));
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -333,7 +456,7 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = crate::commands::init(None);
+        let result = crate::commands::init(None, false);
         env::set_current_dir(&original_dir)?;
 
         result?;
@@ -346,7 +469,7 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let create_result = create_project("test-project", None, "high");
+        let create_result = create_project("test-project", None, Some("high"));
         env::set_current_dir(&original_dir)?;
 
         create_result?;
@@ -367,14 +490,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_create_project_uses_workspace_template() -> Result<()> {
+        let temp_dir = setup_workspace()?;
+        let original_dir = env::current_dir()?;
+
+        std::fs::write(
+            temp_dir.path().join(".pillar/templates/project.md"),
+            "---\nname: {{PROJECT_NAME}}\n---\n\n# {{PROJECT_NAME}}\n\n## Team\n\nTBD\n",
+        )?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let result = create_project("templated", None, Some("medium"));
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let project = read_project(temp_dir.path().join("templated"))?;
+        assert_eq!(project.description, "# templated\n\n## Team\n\nTBD");
+
+        Ok(())
+    }
+
     #[test]
     fn test_create_duplicate_project_fails() -> Result<()> {
         let temp_dir = setup_workspace()?;
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let _ = create_project("test-project", None, "medium");
-        let result = create_project("test-project", None, "medium");
+        let _ = create_project("test-project", None, Some("medium"));
+        let result = create_project("test-project", None, Some("medium"));
         env::set_current_dir(&original_dir)?;
 
         assert!(result.is_err());
@@ -388,7 +533,7 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let _ = create_project("test-project", None, "medium");
+        let _ = create_project("test-project", None, Some("medium"));
         let edit_result = edit_project("test-project", Some("in-progress"), Some("urgent"));
         env::set_current_dir(&original_dir)?;
 
@@ -402,6 +547,108 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_priority_breakdown() -> Result<()> {
+        colored::control::set_override(false);
+
+        let temp_dir = setup_workspace()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_project("triage", None, Some("medium"))?;
+        for (title, priority) in [
+            ("a", "urgent"),
+            ("b", "low"),
+            ("c", "urgent"),
+            ("d", "high"),
+        ] {
+            crate::commands::create_issue(
+                "triage",
+                title,
+                &crate::commands::NewIssue {
+                    priority: Some(priority),
+                    ..Default::default()
+                },
+            )?;
+        }
+        let result = show_project("triage");
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let issues = crate::fs::list_issues(temp_dir.path().join("triage"))?;
+        assert_eq!(priority_breakdown(&issues), "2 urgent, 1 high, 1 low");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_project() -> Result<()> {
+        let temp_dir = setup_workspace()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_project("old-name", None, Some("medium"))?;
+        create_project("taken", None, Some("medium"))?;
+        crate::commands::create_milestone("old-name", "v1", None, None, None)?;
+        crate::commands::create_issue(
+            "old-name",
+            "Child",
+            &crate::commands::NewIssue {
+                priority: Some("medium"),
+                milestone: Some("v1"),
+                ..Default::default()
+            },
+        )?;
+        let clash = rename_project("old-name", "taken");
+        let reserved = [
+            create_project("Inbox", None, None),
+            rename_project("old-name", "inbox"),
+        ];
+        let result = rename_project("old-name", "fresh-start");
+        env::set_current_dir(&original_dir)?;
+
+        assert!(clash.is_err());
+        for attempt in reserved {
+            assert!(attempt.unwrap_err().to_string().contains("reserved"));
+        }
+        result?;
+
+        assert!(!temp_dir.path().join("old-name").exists());
+        let project = read_project(temp_dir.path().join("fresh-start"))?;
+        assert_eq!(project.metadata.name, "fresh-start");
+        // Auto-derived ID follows the new name
+        assert_eq!(project.metadata.project_id.as_deref(), Some("fs"));
+
+        let issues = crate::fs::list_issues(temp_dir.path().join("fresh-start"))?;
+        assert_eq!(issues[0].metadata.project.as_deref(), Some("fresh-start"));
+        let milestones = crate::fs::list_milestones(temp_dir.path().join("fresh-start"))?;
+        assert_eq!(
+            milestones[0].metadata.project.as_deref(),
+            Some("fresh-start")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_project_keeps_custom_id() -> Result<()> {
+        let temp_dir = setup_workspace()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        create_project("alpha", Some("core"), Some("medium"))?;
+        let result = rename_project("core", "beta");
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let project = read_project(temp_dir.path().join("beta"))?;
+        assert_eq!(project.metadata.project_id.as_deref(), Some("core"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_list_empty_projects() -> Result<()> {
         let temp_dir = setup_workspace()?;
@@ -412,7 +659,9 @@ mod tests {
         env::set_current_dir(original_dir)?;
 
         // Should not panic with empty workspace
-        result?;
+        let projects = result?;
+        assert!(projects.is_empty());
+        print_projects(&projects, false);
 
         Ok(())
     }
@@ -0,0 +1,180 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+use crate::commands::export::write_output;
+use crate::commands::issue::IssueFilter;
+use crate::commands::list_issues;
+use crate::commands::milestone::find_milestone;
+use crate::fs::{extract_issue_id, find_project, get_base_directory};
+use crate::models::{Issue, Milestone, Priority};
+
+/// Heading for issues without a tag when grouping by tag
+const UNTAGGED: &str = "Other";
+
+/// Write changelog-style notes for the completed issues of a milestone, grouped
+/// by each issue's first tag or by priority (`group_by` is "tag" or "priority")
+pub fn release_notes(
+    project: &str,
+    milestone: &str,
+    group_by: &str,
+    output: Option<&str>,
+) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let project = find_project(&base_dir, project)?;
+    let milestone = find_milestone(&project.path, milestone)?;
+    let dir_name = project
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| project.metadata.name.clone());
+
+    let filter = IssueFilter::new(
+        Some("completed"),
+        None,
+        Some(&milestone.metadata.title),
+        None,
+    )?;
+    let issues = list_issues(Some(&dir_name), &filter, "priority")?;
+
+    write_output(
+        &render_release_notes(&dir_name, &milestone, &issues, group_by),
+        output,
+    )
+}
+
+/// Markdown for the given issues; they keep their order within each group
+fn render_release_notes(
+    project_dir: &str,
+    milestone: &Milestone,
+    issues: &[Issue],
+    group_by: &str,
+) -> String {
+    let mut out = match &milestone.metadata.target_date {
+        Some(date) => format!("## {} ({})\n", milestone.metadata.title, date),
+        None => format!("## {}\n", milestone.metadata.title),
+    };
+
+    if issues.is_empty() {
+        out.push_str("\nNo completed issues.\n");
+        return out;
+    }
+
+    let groups: Vec<(String, Vec<&Issue>)> = if group_by == "priority" {
+        Priority::ALL
+            .iter()
+            .rev()
+            .map(|p| {
+                let members = issues
+                    .iter()
+                    .filter(|i| i.metadata.priority == *p)
+                    .collect();
+                (capitalize(&p.to_string()), members)
+            })
+            .collect()
+    } else {
+        let mut by_tag: BTreeMap<String, Vec<&Issue>> = BTreeMap::new();
+        let mut untagged = Vec::new();
+        for issue in issues {
+            match issue.metadata.tags.first() {
+                Some(tag) => by_tag.entry(capitalize(tag)).or_default().push(issue),
+                None => untagged.push(issue),
+            }
+        }
+        by_tag
+            .into_iter()
+            .chain(std::iter::once((UNTAGGED.to_string(), untagged)))
+            .collect()
+    };
+
+    for (heading, members) in groups.into_iter().filter(|(_, m)| !m.is_empty()) {
+        out.push_str(&format!("\n### {}\n\n", heading));
+        for issue in members {
+            out.push_str(&format!(
+                "- {} ({}/{})\n",
+                issue.metadata.title,
+                project_dir,
+                extract_issue_id(&issue.path)
+            ));
+        }
+    }
+
+    out
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{
+        create_issue, create_milestone, create_project, edit_issue, init, IssueEdits, NewIssue,
+    };
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_release_notes_list_only_completed_issues() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let notes_path = temp_dir.path().join("NOTES.md");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("app", None, None)?;
+        create_milestone("app", "v1.0", Some("2026-06-01"), None, None)?;
+        for (title, priority, tags) in [
+            ("Fix crash on save", "urgent", Some("bug")),
+            ("Add dark mode", "medium", Some("feature")),
+            ("Update docs", "low", None),
+            ("Still open", "high", Some("bug")),
+        ] {
+            create_issue(
+                "app",
+                title,
+                &NewIssue {
+                    priority: Some(priority),
+                    milestone: Some("v1.0"),
+                    tags,
+                    ..Default::default()
+                },
+            )?;
+        }
+        create_issue(
+            "app",
+            "Other milestone",
+            &NewIssue {
+                tags: Some("bug"),
+                ..Default::default()
+            },
+        )?;
+        for id in ["app/001", "app/002", "app/003", "app/005"] {
+            edit_issue(
+                id,
+                &IssueEdits {
+                    status: Some("completed"),
+                    ..Default::default()
+                },
+                false,
+            )?;
+        }
+        let result = release_notes("app", "V1.0", "tag", notes_path.to_str());
+        env::set_current_dir(&original_dir)?;
+        result?;
+
+        assert_eq!(
+            std::fs::read_to_string(&notes_path)?,
+            "## v1.0 (2026-06-01)\n\
+             \n### Bug\n\n- Fix crash on save (app/001)\n\
+             \n### Feature\n\n- Add dark mode (app/002)\n\
+             \n### Other\n\n- Update docs (app/003)\n"
+        );
+
+        Ok(())
+    }
+}
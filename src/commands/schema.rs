@@ -0,0 +1,78 @@
+use anyhow::{Context, Result};
+use schemars::schema_for;
+
+use crate::models::{IssueMetadata, MilestoneMetadata, ProjectMetadata};
+
+/// Print the JSON Schema of an entity's frontmatter, or of all three keyed by entity type
+pub fn schema(entity: Option<&str>) -> Result<()> {
+    let schema = match entity {
+        Some(entity) => entity_schema(entity)?,
+        None => serde_json::json!({
+            "project": entity_schema("project")?,
+            "milestone": entity_schema("milestone")?,
+            "issue": entity_schema("issue")?,
+        }),
+    };
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&schema).context("Failed to serialize schema")?
+    );
+    Ok(())
+}
+
+/// JSON Schema for the frontmatter of one entity type
+fn entity_schema(entity: &str) -> Result<serde_json::Value> {
+    let schema = match entity {
+        "project" => schema_for!(ProjectMetadata),
+        "milestone" => schema_for!(MilestoneMetadata),
+        "issue" => schema_for!(IssueMetadata),
+        _ => return Err(anyhow::anyhow!("Invalid entity type: {}", entity)),
+    };
+
+    serde_json::to_value(schema).context("Failed to serialize schema")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_schema() -> Result<()> {
+        let schema = entity_schema("issue")?;
+        let properties = &schema["properties"];
+
+        assert_eq!(schema["title"], "IssueMetadata");
+        for field in [
+            "title", "status", "priority", "severity", "tags", "assignee",
+        ] {
+            assert!(properties.get(field).is_some(), "missing {}", field);
+        }
+
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert!(required.contains(&"title"));
+        assert!(!required.contains(&"severity"));
+
+        let status = serde_json::to_string(&schema["$defs"]["Status"])?;
+        assert!(status.contains("in-progress"));
+
+        assert!(entity_schema("comment").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_and_milestone_schemas() -> Result<()> {
+        assert!(entity_schema("project")?["properties"]
+            .get("project_id")
+            .is_some());
+        assert!(entity_schema("milestone")?["properties"]
+            .get("target_date")
+            .is_some());
+        Ok(())
+    }
+}
@@ -1,143 +1,244 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::fs::{get_base_directory, list_issues, list_milestones, list_projects};
+use std::path::Path;
 
-pub fn search(query: &str, entity_type: &str) -> Result<()> {
+use crate::display::{format_priority, format_status};
+use crate::fs::{
+    extract_issue_id, get_base_directory, list_issues, list_milestones, list_projects,
+};
+use crate::models::{Issue, Milestone, Project};
+
+/// Entities matching a search query, grouped by type
+/// A `None` group means that entity type wasn't searched
+struct SearchResults {
+    projects: Option<Vec<Project>>,
+    milestones: Option<Vec<(String, Milestone)>>,
+    issues: Option<Vec<Issue>>,
+}
+
+impl SearchResults {
+    fn is_empty(&self) -> bool {
+        self.projects.as_ref().is_none_or(Vec::is_empty)
+            && self.milestones.as_ref().is_none_or(Vec::is_empty)
+            && self.issues.as_ref().is_none_or(Vec::is_empty)
+    }
+
+    /// e.g. "Found 3 projects, 2 milestones, 7 issues", listing only the searched types
+    fn summary(&self) -> String {
+        let counts = [
+            self.projects.as_ref().map(|v| plural(v.len(), "project")),
+            self.milestones
+                .as_ref()
+                .map(|v| plural(v.len(), "milestone")),
+            self.issues.as_ref().map(|v| plural(v.len(), "issue")),
+        ];
+        let counts: Vec<String> = counts.into_iter().flatten().collect();
+        format!("Found {}", counts.join(", "))
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+pub fn search(query: &str, entity_type: &str, count_only: bool) -> Result<()> {
     let base_dir = get_base_directory()?;
-    let query_lower = query.to_lowercase();
-    let mut found_any = false;
+    let results = find_matches(&base_dir, query, entity_type)?;
 
-    // Search projects
-    if entity_type == "all" || entity_type == "project" {
-        let projects = list_projects(&base_dir)?;
-        let mut found_projects = false;
+    if count_only {
+        println!("{}", results.summary());
+        return Ok(());
+    }
 
-        for project in &projects {
-            let matches = project.metadata.name.to_lowercase().contains(&query_lower)
-                || project.description.to_lowercase().contains(&query_lower);
+    if results.is_empty() {
+        println!("No results found for query: {}", query.bold());
+        return Ok(());
+    }
 
-            if matches {
-                if !found_projects {
-                    println!("{}", "Projects:".bold().green());
-                    found_projects = true;
-                    found_any = true;
-                }
-                println!(
-                    "  {} [{}] [{}]",
-                    project.metadata.name.bold(),
-                    project.metadata.status,
-                    project.metadata.priority
-                );
-            }
+    if let Some(projects) = results.projects.as_ref().filter(|v| !v.is_empty()) {
+        println!("{}", "Projects:".bold().green());
+        for project in projects {
+            println!("{}", project_line(project));
         }
+        println!();
+    }
 
-        if found_projects {
-            println!();
+    if let Some(milestones) = results.milestones.as_ref().filter(|v| !v.is_empty()) {
+        println!("{}", "Milestones:".bold().green());
+        for (project_name, milestone) in milestones {
+            println!("{}", milestone_line(project_name, milestone));
+            if let Some(date) = &milestone.metadata.target_date {
+                println!("    Target: {}", date);
+            }
         }
+        println!();
     }
 
-    // Search milestones
-    if entity_type == "all" || entity_type == "milestone" {
-        let projects = list_projects(&base_dir)?;
-        let mut found_milestones = false;
+    if let Some(issues) = results.issues.as_ref().filter(|v| !v.is_empty()) {
+        println!("{}", "Issues:".bold().green());
+        for issue in issues {
+            println!("{}", issue_line(issue));
 
-        for project in &projects {
-            let milestones = list_milestones(&project.path)?;
+            if let Some(milestone) = &issue.metadata.milestone {
+                println!("    Milestone: {}", milestone);
+            }
 
-            for milestone in milestones {
-                let matches = milestone
-                    .metadata
-                    .title
-                    .to_lowercase()
-                    .contains(&query_lower)
-                    || milestone.description.to_lowercase().contains(&query_lower);
-
-                if matches {
-                    if !found_milestones {
-                        println!("{}", "Milestones:".bold().green());
-                        found_milestones = true;
-                        found_any = true;
-                    }
-                    println!(
-                        "  {} / {} [{}]",
-                        project.metadata.name,
-                        milestone.metadata.title.bold(),
-                        milestone.metadata.status
-                    );
-                    if let Some(date) = &milestone.metadata.target_date {
-                        println!("    Target: {}", date);
-                    }
-                }
+            if !issue.metadata.tags.is_empty() {
+                println!("    Tags: {}", issue.metadata.tags.join(", "));
             }
         }
+        println!();
+    }
+
+    println!("{}", results.summary());
+
+    Ok(())
+}
+
+/// Result lines use the same colored status and priority labels as `list` and `board`
+fn project_line(project: &Project) -> String {
+    format!(
+        "  {} [{}] [{}]",
+        project.metadata.name.bold(),
+        format_status(&project.metadata.status),
+        format_priority(&project.metadata.priority)
+    )
+}
 
-        if found_milestones {
-            println!();
+fn milestone_line(project_name: &str, milestone: &Milestone) -> String {
+    format!(
+        "  {} / {} [{}]",
+        project_name,
+        milestone.metadata.title.bold(),
+        format_status(&milestone.metadata.status)
+    )
+}
+
+fn issue_line(issue: &Issue) -> String {
+    format!(
+        "  {}/{} - {} [{}] [{}]",
+        issue.metadata.project.as_deref().unwrap_or("Unknown"),
+        extract_issue_id(&issue.path),
+        issue.metadata.title.bold(),
+        format_status(&issue.metadata.status),
+        format_priority(&issue.metadata.priority)
+    )
+}
+
+/// The milestones and issues of each project (empty when not wanted), in project order
+/// Projects are split across one scoped thread per CPU, since large workspaces spend
+/// most of a search reading and parsing files.
+fn load_project_entities(
+    projects: &[Project],
+    milestones: bool,
+    issues: bool,
+) -> Result<Vec<(Vec<Milestone>, Vec<Issue>)>> {
+    let load = |project: &Project| -> Result<(Vec<Milestone>, Vec<Issue>)> {
+        Ok((
+            if milestones {
+                list_milestones(&project.path)?
+            } else {
+                Vec::new()
+            },
+            if issues {
+                list_issues(&project.path)?
+            } else {
+                Vec::new()
+            },
+        ))
+    };
+
+    let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = projects.len().div_ceil(workers).max(1);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = projects
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(load).collect::<Result<Vec<_>>>()))
+            .collect();
+
+        let mut loaded = Vec::with_capacity(projects.len());
+        for handle in handles {
+            loaded.extend(handle.join().expect("search worker panicked")?);
         }
+        Ok(loaded)
+    })
+}
+
+/// Case-insensitive substring match on titles, descriptions, and issue tags
+fn find_matches(base_dir: &Path, query: &str, entity_type: &str) -> Result<SearchResults> {
+    if !matches!(entity_type, "all" | "project" | "milestone" | "issue") {
+        return Err(anyhow::anyhow!(
+            "Invalid entity type: {}. Use project, milestone, issue, or all",
+            entity_type
+        ));
     }
 
-    // Search issues
-    if entity_type == "all" || entity_type == "issue" {
-        let projects = list_projects(&base_dir)?;
-        let mut found_issues = false;
-
-        for project in &projects {
-            let issues = list_issues(&project.path)?;
-
-            for issue in issues {
-                let matches = issue.metadata.title.to_lowercase().contains(&query_lower)
-                    || issue.description.to_lowercase().contains(&query_lower)
-                    || issue
-                        .metadata
-                        .tags
-                        .iter()
-                        .any(|t| t.to_lowercase().contains(&query_lower));
-
-                if matches {
-                    if !found_issues {
-                        println!("{}", "Issues:".bold().green());
-                        found_issues = true;
-                        found_any = true;
-                    }
-
-                    // Extract ID from path
-                    let filename = issue.path.file_stem().unwrap().to_str().unwrap();
-                    let id_parts: Vec<&str> = filename.splitn(2, '-').collect();
-                    let id = id_parts[0];
-
-                    let project_name = issue.metadata.project.as_deref().unwrap_or("Unknown");
-
-                    println!(
-                        "  {}/{} - {} [{}] [{}]",
-                        project_name,
-                        id,
-                        issue.metadata.title.bold(),
-                        issue.metadata.status,
-                        issue.metadata.priority
-                    );
-
-                    if let Some(milestone) = &issue.metadata.milestone {
-                        println!("    Milestone: {}", milestone);
-                    }
-
-                    if !issue.metadata.tags.is_empty() {
-                        println!("    Tags: {}", issue.metadata.tags.join(", "));
-                    }
-                }
+    let query_lower = query.to_lowercase();
+    let wants = |kind: &str| entity_type == "all" || entity_type == kind;
+
+    // One snapshot of the project list, shared by every section below
+    let projects = list_projects(base_dir)?;
+
+    // Search projects
+    let matched_projects = wants("project").then(|| {
+        projects
+            .iter()
+            .filter(|project| {
+                project.metadata.name.to_lowercase().contains(&query_lower)
+                    || project.description.to_lowercase().contains(&query_lower)
+            })
+            .cloned()
+            .collect()
+    });
+
+    // Load every project's milestones and issues in one pass, then filter in project order
+    let loaded = load_project_entities(&projects, wants("milestone"), wants("issue"))?;
+
+    let mut found_milestones = Vec::new();
+    let mut found_issues = Vec::new();
+    for (project, (milestones, issues)) in projects.iter().zip(loaded) {
+        for milestone in milestones {
+            let matches = milestone
+                .metadata
+                .title
+                .to_lowercase()
+                .contains(&query_lower)
+                || milestone.description.to_lowercase().contains(&query_lower);
+
+            if matches {
+                found_milestones.push((project.metadata.name.clone(), milestone));
             }
         }
 
-        if found_issues {
-            println!();
+        for issue in issues {
+            let matches = issue.metadata.title.to_lowercase().contains(&query_lower)
+                || issue.description.to_lowercase().contains(&query_lower)
+                || issue
+                    .metadata
+                    .tags
+                    .iter()
+                    .any(|t| t.to_lowercase().contains(&query_lower));
+
+            if matches {
+                found_issues.push(issue);
+            }
         }
     }
 
-    if !found_any {
-        println!("No results found for query: {}", query.bold());
-    }
+    let matched_milestones = wants("milestone").then_some(found_milestones);
+    let matched_issues = wants("issue").then_some(found_issues);
 
-    Ok(())
+    Ok(SearchResults {
+        projects: matched_projects,
+        milestones: matched_milestones,
+        issues: matched_issues,
+    })
 }
 
 #[cfg(test)]
@@ -153,24 +254,273 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        init(None)?;
+        init(None, false)?;
 
         // Create test data
-        crate::commands::project::create_project("TestProject", None, "high")?;
+        crate::commands::project::create_project("TestProject", None, Some("high"))?;
         crate::commands::issue::create_issue(
             "TestProject",
             "Fix bug in search",
-            "high",
-            None,
-            Some("bug,search"),
+            &crate::commands::issue::NewIssue {
+                priority: Some("high"),
+                tags: Some("bug,search"),
+                ..Default::default()
+            },
         )?;
 
         // Test search
-        let result = search("search", "all");
+        let result = search("search", "all", false);
 
         env::set_current_dir(&original_dir)?;
 
         assert!(result.is_ok());
         Ok(())
     }
+
+    #[test]
+    fn test_search_summary_counts() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        crate::commands::project::create_project("Parser", None, Some("high"))?;
+        crate::commands::project::create_project("parser-tools", None, Some("low"))?;
+        crate::commands::milestone::create_milestone("Parser", "Parser rewrite", None, None, None)?;
+        crate::commands::issue::create_issue(
+            "Parser",
+            "Tokenizer",
+            &crate::commands::issue::NewIssue {
+                tags: Some("parser"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::issue::create_issue(
+            "Parser",
+            "Parse numbers",
+            &crate::commands::issue::NewIssue::default(),
+        )?;
+        crate::commands::issue::create_issue(
+            "Parser",
+            "Docs",
+            &crate::commands::issue::NewIssue::default(),
+        )?;
+        let printed = search("PARSE", "all", true);
+        env::set_current_dir(&original_dir)?;
+
+        printed?;
+
+        let all = find_matches(temp_dir.path(), "PARSE", "all")?;
+        assert_eq!(all.summary(), "Found 2 projects, 1 milestone, 2 issues");
+
+        let issues_only = find_matches(temp_dir.path(), "parse", "issue")?;
+        assert_eq!(issues_only.summary(), "Found 2 issues");
+
+        let nothing = find_matches(temp_dir.path(), "zzz", "all")?;
+        assert!(nothing.is_empty());
+        assert!(find_matches(temp_dir.path(), "parse", "comment").is_err());
+        assert_eq!(
+            nothing.summary(),
+            "Found 0 projects, 0 milestones, 0 issues"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_result_lines_use_shared_formatters() -> Result<()> {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        crate::commands::project::create_project("Lines", None, Some("urgent"))?;
+        crate::commands::milestone::create_milestone("Lines", "Beta", None, None, None)?;
+        crate::commands::issue::create_issue(
+            "Lines",
+            "Wrap",
+            &crate::commands::issue::NewIssue {
+                priority: Some("high"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::issue::edit_issue(
+            "Lines/001",
+            &crate::commands::issue::IssueEdits {
+                status: Some("in-progress"),
+                ..Default::default()
+            },
+            false,
+        )?;
+        env::set_current_dir(&original_dir)?;
+
+        let results = find_matches(temp_dir.path(), "", "all")?;
+        let project = &results.projects.unwrap()[0];
+        assert_eq!(project_line(project), "  Lines [backlog] [urgent]");
+        let (project_name, milestone) = &results.milestones.unwrap()[0];
+        assert_eq!(
+            milestone_line(project_name, milestone),
+            "  Lines / Beta [backlog]"
+        );
+        let issue = &results.issues.unwrap()[0];
+        assert_eq!(issue_line(issue), "  Lines/001 - Wrap [in-progress] [high]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_combined_search_matches_per_type_searches() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        crate::commands::project::create_project("Alpha", None, None)?;
+        crate::commands::project::create_project("Beta alpha", None, None)?;
+        crate::commands::milestone::create_milestone("Alpha", "Alpha 1", None, None, None)?;
+        crate::commands::milestone::create_milestone("Beta alpha", "Launch", None, None, None)?;
+        crate::commands::issue::create_issue(
+            "Alpha",
+            "Alpha bug",
+            &crate::commands::issue::NewIssue::default(),
+        )?;
+        crate::commands::issue::create_issue(
+            "Beta alpha",
+            "Other",
+            &crate::commands::issue::NewIssue {
+                tags: Some("alpha"),
+                ..Default::default()
+            },
+        )?;
+        env::set_current_dir(&original_dir)?;
+
+        // The projects are read once and shared by all three sections, so searching
+        // everything gives exactly what the separate searches give
+        let all = find_matches(temp_dir.path(), "alpha", "all")?;
+        let names = |projects: Vec<Project>| -> Vec<String> {
+            projects.into_iter().map(|p| p.metadata.name).collect()
+        };
+        let milestone_titles = |milestones: Vec<(String, Milestone)>| -> Vec<(String, String)> {
+            milestones
+                .into_iter()
+                .map(|(p, m)| (p, m.metadata.title))
+                .collect()
+        };
+        let issue_paths = |issues: Vec<Issue>| -> Vec<std::path::PathBuf> {
+            issues.into_iter().map(|i| i.path).collect()
+        };
+
+        assert_eq!(all.summary(), "Found 2 projects, 1 milestone, 2 issues");
+        assert_eq!(
+            names(all.projects.unwrap()),
+            names(
+                find_matches(temp_dir.path(), "alpha", "project")?
+                    .projects
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            milestone_titles(all.milestones.unwrap()),
+            milestone_titles(
+                find_matches(temp_dir.path(), "alpha", "milestone")?
+                    .milestones
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            issue_paths(all.issues.unwrap()),
+            issue_paths(
+                find_matches(temp_dir.path(), "alpha", "issue")?
+                    .issues
+                    .unwrap()
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_large_workspace() -> Result<()> {
+        use crate::models::{IssueMetadata, MilestoneMetadata, Priority, ProjectMetadata, Status};
+        use crate::parser::write_with_frontmatter;
+
+        let temp_dir = TempDir::new()?;
+        for p in 0..40 {
+            let project_dir = temp_dir.path().join(format!("project-{:02}", p));
+            std::fs::create_dir_all(project_dir.join("milestones"))?;
+            std::fs::create_dir_all(project_dir.join("issues"))?;
+            let project = ProjectMetadata {
+                name: format!("project-{:02}", p),
+                project_id: None,
+                status: Status::Todo,
+                priority: Priority::Medium,
+                created: None,
+                updated: None,
+            };
+            write_with_frontmatter(project_dir.join("README.md"), &project, "")?;
+
+            for m in 0..2 {
+                let milestone = MilestoneMetadata {
+                    title: format!("Needle release {}", m),
+                    status: Status::Todo,
+                    priority: None,
+                    target_date: None,
+                    project: None,
+                    created: None,
+                    updated: None,
+                };
+                let path = project_dir.join(format!("milestones/needle-{}.md", m));
+                write_with_frontmatter(path, &milestone, "")?;
+            }
+
+            for i in 1..=25 {
+                let issue = IssueMetadata {
+                    title: format!("Issue {}", i),
+                    status: Status::Todo,
+                    priority: Priority::Low,
+                    severity: None,
+                    project: Some(format!("project-{:02}", p)),
+                    milestone: None,
+                    tags: if i % 5 == 0 {
+                        vec!["needle".to_string()]
+                    } else {
+                        vec![]
+                    },
+                    assignee: None,
+                    reporter: None,
+                    url: None,
+                    created: None,
+                    updated: None,
+                };
+                let path = project_dir.join(format!("issues/{:03}-issue.md", i));
+                write_with_frontmatter(path, &issue, "")?;
+            }
+        }
+
+        let results = find_matches(temp_dir.path(), "needle", "all")?;
+        assert_eq!(
+            results.summary(),
+            "Found 0 projects, 80 milestones, 200 issues"
+        );
+
+        // Results keep the order of a plain project-by-project scan
+        let mut expected = Vec::new();
+        for project in list_projects(temp_dir.path())? {
+            for issue in list_issues(&project.path)? {
+                if issue.metadata.tags.contains(&"needle".to_string()) {
+                    expected.push(issue.path);
+                }
+            }
+        }
+        let paths: Vec<_> = results
+            .issues
+            .unwrap()
+            .into_iter()
+            .map(|i| i.path)
+            .collect();
+        assert_eq!(paths, expected);
+
+        Ok(())
+    }
 }
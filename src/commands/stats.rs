@@ -0,0 +1,227 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::fs::{extract_issue_id, get_base_directory, list_all_issues, list_projects};
+use crate::models::{Priority, Status};
+
+/// Aggregate workspace metrics
+#[derive(Debug, Serialize)]
+struct Stats {
+    projects: usize,
+    milestones: usize,
+    issues: usize,
+    issues_by_status: BTreeMap<String, usize>,
+    issues_by_priority: BTreeMap<String, usize>,
+    average_issues_per_project: f64,
+    /// Percentage of non-cancelled issues that are completed
+    completion_rate: f64,
+    oldest_open_issue: Option<OldestIssue>,
+}
+
+#[derive(Debug, Serialize)]
+struct OldestIssue {
+    id: String,
+    title: String,
+    created: DateTime<Utc>,
+}
+
+/// Print workspace statistics, as text or JSON
+pub fn stats(json: bool) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let stats = compute_stats(&base_dir)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    println!("{}\n", "Workspace Statistics".bold());
+    println!("Projects:   {}", stats.projects);
+    println!("Milestones: {}", stats.milestones);
+    println!("Issues:     {}", stats.issues);
+
+    println!("\n{}", "Issues by status:".bold());
+    for status in Status::ALL {
+        let key = status.to_string();
+        println!("  {:<12} {}", key, stats.issues_by_status[&key]);
+    }
+
+    println!("\n{}", "Issues by priority:".bold());
+    for priority in Priority::ALL.iter().rev() {
+        let key = priority.to_string();
+        println!("  {:<12} {}", key, stats.issues_by_priority[&key]);
+    }
+
+    println!();
+    println!(
+        "Average issues per project: {:.1}",
+        stats.average_issues_per_project
+    );
+    println!("Completion rate: {:.1}%", stats.completion_rate);
+
+    if let Some(oldest) = &stats.oldest_open_issue {
+        println!(
+            "Oldest open issue: {} - {} (created {})",
+            oldest.id,
+            oldest.title,
+            oldest.created.format("%Y-%m-%d")
+        );
+    }
+
+    Ok(())
+}
+
+fn compute_stats(base_dir: &Path) -> Result<Stats> {
+    let projects = list_projects(base_dir)?;
+    let issues = list_all_issues(base_dir)?;
+
+    let milestones = projects
+        .iter()
+        .map(|p| crate::fs::list_milestones(&p.path).map(|m| m.len()))
+        .sum::<Result<usize>>()?;
+
+    let issues_by_status = Status::ALL
+        .iter()
+        .map(|s| {
+            let count = issues.iter().filter(|i| i.metadata.status == *s).count();
+            (s.to_string(), count)
+        })
+        .collect();
+    let issues_by_priority = Priority::ALL
+        .iter()
+        .map(|p| {
+            let count = issues.iter().filter(|i| i.metadata.priority == *p).count();
+            (p.to_string(), count)
+        })
+        .collect();
+
+    let average_issues_per_project = if projects.is_empty() {
+        0.0
+    } else {
+        issues.len() as f64 / projects.len() as f64
+    };
+
+    let completed = issues
+        .iter()
+        .filter(|i| i.metadata.status == Status::Completed)
+        .count();
+    let countable = issues
+        .iter()
+        .filter(|i| i.metadata.status != Status::Cancelled)
+        .count();
+    let completion_rate = if countable == 0 {
+        0.0
+    } else {
+        completed as f64 * 100.0 / countable as f64
+    };
+
+    let oldest_open_issue = issues
+        .iter()
+        .filter(|i| !matches!(i.metadata.status, Status::Completed | Status::Cancelled))
+        .filter_map(|i| i.metadata.created.map(|created| (i, created)))
+        .min_by_key(|(_, created)| *created)
+        .map(|(i, created)| OldestIssue {
+            id: format!(
+                "{}/{}",
+                i.metadata.project.as_deref().unwrap_or("unknown"),
+                extract_issue_id(&i.path)
+            ),
+            title: i.metadata.title.clone(),
+            created,
+        });
+
+    Ok(Stats {
+        projects: projects.len(),
+        milestones,
+        issues: issues.len(),
+        issues_by_status,
+        issues_by_priority,
+        average_issues_per_project,
+        completion_rate,
+        oldest_open_issue,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{
+        create_issue, create_milestone, create_project, edit_issue, init, IssueEdits, NewIssue,
+    };
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_compute_stats() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, Some("medium"))?;
+        create_project("beta", None, Some("medium"))?;
+        create_milestone("alpha", "v1", None, None, None)?;
+        create_issue(
+            "alpha",
+            "Oldest",
+            &NewIssue {
+                priority: Some("high"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            "alpha",
+            "Done",
+            &NewIssue {
+                priority: Some("low"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            "beta",
+            "Dropped",
+            &NewIssue {
+                priority: Some("low"),
+                ..Default::default()
+            },
+        )?;
+        edit_issue(
+            "alpha/002",
+            &IssueEdits {
+                status: Some("completed"),
+                ..Default::default()
+            },
+            false,
+        )?;
+        edit_issue(
+            "beta/001",
+            &IssueEdits {
+                status: Some("cancelled"),
+                ..Default::default()
+            },
+            false,
+        )?;
+        let printed = stats(true);
+        env::set_current_dir(&original_dir)?;
+
+        printed?;
+
+        let stats = compute_stats(temp_dir.path())?;
+        assert_eq!(stats.projects, 2);
+        assert_eq!(stats.milestones, 1);
+        assert_eq!(stats.issues, 3);
+        assert_eq!(stats.issues_by_status["completed"], 1);
+        assert_eq!(stats.issues_by_status["backlog"], 1);
+        assert_eq!(stats.issues_by_status["todo"], 0);
+        assert_eq!(stats.issues_by_priority["low"], 2);
+        assert_eq!(stats.average_issues_per_project, 1.5);
+        assert_eq!(stats.completion_rate, 50.0);
+        assert_eq!(stats.oldest_open_issue.unwrap().id, "alpha/001");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use anyhow::Result;
+use chrono::Utc;
+use std::collections::HashMap;
+
+use crate::display::success;
+use crate::fs::{get_base_directory, list_all_issues, record_activity};
+use crate::models::{normalize_tag, FieldChange, Issue};
+use crate::parser::write_with_frontmatter;
+
+/// List every tag used by an issue, most used first
+pub fn list_tags() -> Result<()> {
+    let base_dir = get_base_directory()?;
+    let issues = list_all_issues(&base_dir)?;
+    let counts = tag_counts(&issues);
+
+    if counts.is_empty() {
+        println!("No tags found.");
+        return Ok(());
+    }
+
+    println!("Tags:\n");
+    for (tag, count) in counts {
+        println!("  {} ({})", tag, count);
+    }
+
+    Ok(())
+}
+
+/// Rename a tag on every issue that has it
+pub fn rename_tag(old: &str, new: &str) -> Result<()> {
+    let old = normalize_tag(old).ok_or_else(|| anyhow::anyhow!("Tag name cannot be empty"))?;
+    let old = old.as_str();
+    let new = normalize_tag(new).ok_or_else(|| anyhow::anyhow!("New tag name cannot be empty"))?;
+    let new = new.as_str();
+    if old == new {
+        return Err(anyhow::anyhow!("Tag is already named '{}'", new));
+    }
+
+    let base_dir = get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
+
+    let mut renamed = 0;
+    for issue in list_all_issues(&base_dir)? {
+        let Some(tags) = rename_in(&issue.metadata.tags, old, new) else {
+            continue;
+        };
+
+        let mut metadata = issue.metadata;
+        metadata.tags = tags;
+        metadata.updated = Some(Utc::now());
+        write_with_frontmatter(&issue.path, &metadata, &issue.description)?;
+        renamed += 1;
+    }
+
+    if renamed == 0 {
+        return Err(anyhow::anyhow!("No issues are tagged '{}'", old));
+    }
+
+    record_activity(
+        "rename",
+        "tag",
+        old,
+        vec![FieldChange::new(
+            "name",
+            Some(old.to_string()),
+            Some(new.to_string()),
+        )],
+    );
+
+    success(&format!(
+        "✓ Renamed tag '{}' to '{}' on {} issue(s)",
+        old, new, renamed
+    ));
+
+    Ok(())
+}
+
+/// Count issues per tag, sorted by count (descending) then name
+fn tag_counts(issues: &[Issue]) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for issue in issues {
+        for tag in &issue.metadata.tags {
+            *counts.entry(tag.as_str()).or_default() += 1;
+        }
+    }
+
+    let mut counts: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(tag, count)| (tag.to_string(), count))
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Replace `old` (already normalized) with `new` in place, dropping it instead if `new`
+/// is already present. Tags are compared normalized, so a hand-edited "Bug" matches "bug".
+/// Returns None when `old` isn't in the list
+fn rename_in(tags: &[String], old: &str, new: &str) -> Option<Vec<String>> {
+    let is_old = |tag: &str| normalize_tag(tag).as_deref() == Some(old);
+    if !tags.iter().any(|t| is_old(t)) {
+        return None;
+    }
+
+    let mut renamed: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = if is_old(tag) { new } else { tag.as_str() };
+        if !renamed.iter().any(|t| t == tag) {
+            renamed.push(tag.to_string());
+        }
+    }
+
+    Some(renamed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{create_issue, create_project, init, NewIssue};
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_rename_in_preserves_order_and_dedupes() {
+        let tags = |list: &[&str]| list.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            rename_in(&tags(&["ui", "back-end", "bug"]), "back-end", "backend"),
+            Some(tags(&["ui", "backend", "bug"]))
+        );
+        assert_eq!(
+            rename_in(&tags(&["backend", "ui", "back-end"]), "back-end", "backend"),
+            Some(tags(&["backend", "ui"]))
+        );
+        assert_eq!(
+            rename_in(&tags(&["back-end", "ui", "backend"]), "back-end", "backend"),
+            Some(tags(&["backend", "ui"]))
+        );
+        assert_eq!(rename_in(&tags(&["ui"]), "back-end", "backend"), None);
+        assert_eq!(
+            rename_in(&tags(&["Back-End", "ui"]), "back-end", "backend"),
+            Some(tags(&["backend", "ui"]))
+        );
+    }
+
+    #[test]
+    fn test_rename_tag_across_issues() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, Some("medium"))?;
+        create_issue(
+            "alpha",
+            "One",
+            &NewIssue {
+                priority: Some("medium"),
+                tags: Some("back-end,bug"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            "alpha",
+            "Two",
+            &NewIssue {
+                priority: Some("medium"),
+                tags: Some("backend,back-end"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            "alpha",
+            "Three",
+            &NewIssue {
+                priority: Some("medium"),
+                tags: Some("ui"),
+                ..Default::default()
+            },
+        )?;
+        let result = rename_tag(" Back-End ", "backend");
+        let missing = rename_tag("nope", "other");
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+        assert!(missing.is_err());
+
+        let issues = list_all_issues(temp_dir.path())?;
+        assert_eq!(
+            tag_counts(&issues),
+            vec![
+                ("backend".to_string(), 2),
+                ("bug".to_string(), 1),
+                ("ui".to_string(), 1)
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -1,30 +1,170 @@
 use anyhow::Result;
-use colored::Colorize;
+use colored::{Color, Colorize};
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+
+use crate::display::{format_priority, format_status, terminal_width, truncate};
+use crate::fs::{
+    find_workspace_root, get_base_directory, issue_project_dir, list_all_issues, list_projects,
+    read_config,
+};
+use crate::models::{Issue, Priority, Project, Status};
+
+/// Print `porcelain_lines` for scripts; see there for the format
+pub fn status_porcelain(project_filter: Option<&str>) -> Result<()> {
+    let base_dir = get_base_directory()?;
+    for line in porcelain_lines(&base_dir, project_filter)? {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// One tab-separated line per entity, never colored. The format is stable across
+/// releases; new fields are only ever appended to the end of a line.
+///
+/// ```text
+/// project    <project>               <status>  <priority>
+/// milestone  <project>/<title>       <status>  <target date or ->
+/// issue      <project>/<number>      <status>  <priority>
+/// ```
+///
+/// `<project>` is the project's directory name, as used in issue IDs. Projects are
+/// sorted by that name, each followed by its milestones (by title) and issues (by number).
+fn porcelain_lines(
+    base_dir: &std::path::Path,
+    project_filter: Option<&str>,
+) -> Result<Vec<String>> {
+    let mut projects = match project_filter {
+        Some(name) => vec![crate::fs::find_project(base_dir, name)?],
+        None => list_projects(base_dir)?,
+    };
+    let dir_name = |path: &std::path::Path| {
+        path.file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+    projects.sort_by_key(|p| dir_name(&p.path));
+
+    let mut lines = Vec::new();
+    for project in projects {
+        let name = dir_name(&project.path);
+        lines.push(format!(
+            "project\t{}\t{}\t{}",
+            name, project.metadata.status, project.metadata.priority
+        ));
+
+        let mut milestones = crate::fs::list_milestones(&project.path)?;
+        milestones.sort_by(|a, b| a.metadata.title.cmp(&b.metadata.title));
+        for milestone in milestones {
+            lines.push(format!(
+                "milestone\t{}/{}\t{}\t{}",
+                name,
+                milestone.metadata.title,
+                milestone.metadata.status,
+                milestone.metadata.target_date.as_deref().unwrap_or("-")
+            ));
+        }
+
+        let mut issues = crate::fs::list_issues(&project.path)?;
+        issues.sort_by(|a, b| a.path.cmp(&b.path));
+        for issue in issues {
+            lines.push(format!(
+                "issue\t{}/{}\t{}\t{}",
+                name,
+                crate::fs::extract_issue_id(&issue.path),
+                issue.metadata.status,
+                issue.metadata.priority
+            ));
+        }
+    }
+
+    Ok(lines)
+}
+
+/// Redraw the status overview every `interval` seconds until Ctrl-C
+/// When stdout isn't a terminal there is nothing to redraw, so it renders once.
+pub fn watch_status(project_filter: Option<&str>, interval: u64) -> Result<()> {
+    if !std::io::stdout().is_terminal() {
+        return status(project_filter);
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    runtime.block_on(async {
+        // Polled across iterations, so a Ctrl-C during a redraw isn't missed
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
+
+        loop {
+            // Clear the screen and move the cursor to the top left
+            print!("\x1b[2J\x1b[H");
+            status(project_filter)?;
+            println!(
+                "\n{}",
+                format!("Refreshing every {}s. Press Ctrl-C to stop.", interval).dimmed()
+            );
+            std::io::stdout().flush()?;
+
+            tokio::select! {
+                _ = &mut ctrl_c => break,
+                _ = tokio::time::sleep(Duration::from_secs(interval)) => {}
+            }
+        }
+
+        println!();
+        Ok(())
+    })
+}
 
-use crate::fs::{get_base_directory, list_all_issues, list_projects};
-use crate::models::Status;
+/// The projects `status` reports on, sorted by name, each with its issues loaded once
+/// so every section reuses them
+fn status_projects(
+    base_dir: &std::path::Path,
+    project_filter: Option<&str>,
+) -> Result<Vec<(Project, Vec<Issue>)>> {
+    let mut projects = if let Some(name) = project_filter {
+        vec![crate::fs::find_project(base_dir, name)?]
+    } else {
+        list_projects(base_dir)?
+    };
+    projects.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
 
-pub fn status() -> Result<()> {
+    Ok(projects
+        .into_iter()
+        .map(|p| {
+            let issues = crate::fs::list_issues(&p.path).unwrap_or_default();
+            (p, issues)
+        })
+        .collect())
+}
+
+pub fn status(project_filter: Option<&str>) -> Result<()> {
     let base_dir = get_base_directory()?;
-    let projects = list_projects(&base_dir)?;
+    let project_issues = status_projects(&base_dir, project_filter)?;
+
+    // Always say which workspace this is, so an empty one is easy to tell from the wrong one
+    let root = find_workspace_root()?;
+    let config = read_config(&root)?;
+    println!("{}", "Workspace Status".bold());
+    println!("  Workspace: {}", root.display());
+    println!("  Base directory: {}", base_dir.display());
+    println!("  Version: {}\n", config.workspace.version);
 
-    if projects.is_empty() {
+    if project_issues.is_empty() {
         println!("No projects in workspace.");
         return Ok(());
     }
 
-    println!("{}\n", "Workspace Status".bold());
-
     // Show active projects
-    let active_projects: Vec<_> = projects
+    let active_projects: Vec<_> = project_issues
         .iter()
-        .filter(|p| p.metadata.status == Status::InProgress)
+        .filter(|(p, _)| p.metadata.status == Status::InProgress)
         .collect();
 
     if !active_projects.is_empty() {
         println!("{}", "Active Projects:".bold());
-        for project in active_projects {
-            let issues = crate::fs::list_issues(&project.path).unwrap_or_default();
+        for (project, issues) in active_projects {
             let in_progress = issues
                 .iter()
                 .filter(|i| i.metadata.status == Status::InProgress)
@@ -37,30 +177,42 @@ pub fn status() -> Result<()> {
         println!();
     }
 
-    // Show in-progress issues
-    let all_issues = list_all_issues(&base_dir)?;
-    let in_progress_issues: Vec<_> = all_issues
+    // Show in-progress issues grouped by project
+    let in_progress_groups: Vec<_> = project_issues
         .iter()
-        .filter(|i| i.metadata.status == Status::InProgress)
+        .map(|(p, issues)| {
+            let in_progress: Vec<_> = issues
+                .iter()
+                .filter(|i| i.metadata.status == Status::InProgress)
+                .collect();
+            (p, issues, in_progress)
+        })
+        .filter(|(_, _, in_progress)| !in_progress.is_empty())
         .collect();
 
-    if !in_progress_issues.is_empty() {
+    if !in_progress_groups.is_empty() {
         println!("{}", "Issues In Progress:".bold());
-        for issue in in_progress_issues {
-            let project = issue.metadata.project.as_deref().unwrap_or("unknown");
+        for (project, issues, in_progress) in in_progress_groups {
             println!(
-                "  • {} / {} [{}]",
-                project,
-                issue.metadata.title,
-                format_priority(&issue.metadata.priority)
+                "  {} ({}/{} completed)",
+                project.metadata.name.bold(),
+                count_status(issues, Status::Completed),
+                issues.len()
             );
+            for issue in in_progress {
+                println!(
+                    "    • {} [{}]",
+                    issue.metadata.title,
+                    format_priority(&issue.metadata.priority)
+                );
+            }
         }
         println!();
     }
 
     // Show upcoming milestones
     let mut all_milestones = Vec::new();
-    for project in &projects {
+    for (project, _) in &project_issues {
         let milestones = crate::fs::list_milestones(&project.path)?;
         for milestone in milestones {
             all_milestones.push((project.metadata.name.clone(), milestone));
@@ -95,19 +247,27 @@ pub fn status() -> Result<()> {
         println!();
     }
 
-    // Summary stats
-    let total_issues = all_issues.len();
-    let completed = all_issues
-        .iter()
-        .filter(|i| i.metadata.status == Status::Completed)
-        .count();
-    let todo = all_issues
+    // Summary stats, per project then overall
+    println!("{}", "Summary:".bold());
+    for (project, issues) in &project_issues {
+        println!(
+            "  {}: {}/{} issues completed",
+            project.metadata.name,
+            count_status(issues, Status::Completed),
+            issues.len()
+        );
+    }
+
+    let all_issues: Vec<_> = project_issues
         .iter()
-        .filter(|i| i.metadata.status == Status::Todo)
-        .count();
+        .flat_map(|(_, issues)| issues.iter())
+        .cloned()
+        .collect();
+    let total_issues = all_issues.len();
+    let completed = count_status(&all_issues, Status::Completed);
+    let todo = count_status(&all_issues, Status::Todo);
 
-    println!("{}", "Summary:".bold());
-    println!("  Projects: {}", projects.len());
+    println!("  Projects: {}", project_issues.len());
     println!(
         "  Issues: {} total, {} completed, {} todo",
         total_issues, completed, todo
@@ -116,12 +276,23 @@ pub fn status() -> Result<()> {
     Ok(())
 }
 
-pub fn board(project_filter: Option<&str>) -> Result<()> {
+fn count_status(issues: &[Issue], status: Status) -> usize {
+    issues
+        .iter()
+        .filter(|i| i.metadata.status == status)
+        .count()
+}
+
+pub fn board(
+    project_filter: Option<&str>,
+    all_statuses: bool,
+    group_by: &str,
+    swimlanes: bool,
+) -> Result<()> {
     let base_dir = get_base_directory()?;
 
     let issues = if let Some(proj) = project_filter {
-        let project_path = base_dir.join(proj);
-        crate::fs::list_issues(&project_path)?
+        crate::fs::list_issues(crate::fs::project_path(&base_dir, proj)?)?
     } else {
         list_all_issues(&base_dir)?
     };
@@ -139,71 +310,262 @@ pub fn board(project_filter: Option<&str>) -> Result<()> {
 
     println!("{}\n", title.bold());
 
-    // Group by status
-    let statuses = [
-        (Status::Backlog, "Backlog"),
-        (Status::Todo, "Todo"),
-        (Status::InProgress, "In Progress"),
-        (Status::Completed, "Completed"),
-    ];
+    if swimlanes {
+        for line in swimlane_lines(&issues, group_by, all_statuses, terminal_width())? {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
 
-    for (status, label) in statuses {
-        let status_issues: Vec<_> = issues
-            .iter()
-            .filter(|i| i.metadata.status == status)
-            .collect();
+    for line in column_lines(&issues, group_by, all_statuses)? {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
 
-        if status_issues.is_empty() {
-            continue;
+/// The board as one column after another, each a header, a rule, and its issues
+fn column_lines(issues: &[Issue], group_by: &str, all_statuses: bool) -> Result<Vec<String>> {
+    let mut lines = Vec::new();
+    for column in board_columns(issues, group_by, all_statuses)? {
+        let header = match column.color {
+            Some(color) => column.label.color(color),
+            None => column.label.normal(),
+        };
+        lines.push(format!("{} ({})", header.bold(), column.issues.len()));
+        lines.push("─".repeat(40));
+
+        for issue in column.issues {
+            let project = issue.metadata.project.as_deref().unwrap_or("?");
+            // Show whichever of status/priority the columns don't already
+            let detail = match group_by {
+                "status" => format_priority(&issue.metadata.priority),
+                _ => format_status(&issue.metadata.status),
+            };
+            lines.push(format!(
+                "  • {} / {} [{}]",
+                project, issue.metadata.title, detail
+            ));
         }
+        lines.push(String::new());
+    }
 
-        println!(
-            "{} ({})",
-            format_status_label(&status, label),
-            status_issues.len()
+    Ok(lines)
+}
+
+/// Narrowest a swimlane column gets, however small the terminal
+const MIN_LANE_COLUMN_WIDTH: usize = 12;
+
+/// Longest project name shown at the start of a swimlane
+const MAX_LANE_LABEL_WIDTH: usize = 20;
+
+/// The board as a grid: one row (swimlane) per project, the usual columns across.
+/// Columns share `width` evenly and titles are truncated to fit.
+fn swimlane_lines(
+    issues: &[Issue],
+    group_by: &str,
+    all_statuses: bool,
+    width: usize,
+) -> Result<Vec<String>> {
+    let columns = board_columns(issues, group_by, all_statuses)?;
+    if columns.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut projects: Vec<String> = columns
+        .iter()
+        .flat_map(|c| c.issues.iter().map(|i| issue_project_dir(i)))
+        .collect();
+    projects.sort();
+    projects.dedup();
+
+    let label_width = projects
+        .iter()
+        .map(|p| p.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LANE_LABEL_WIDTH);
+    // Every column is preceded by a " │ " separator
+    let column_width = (width.saturating_sub(label_width) / columns.len())
+        .saturating_sub(3)
+        .max(MIN_LANE_COLUMN_WIDTH);
+
+    // Padding is computed from the uncolored text
+    let pad = |plain: &str, shown: String, width: usize| {
+        format!(
+            "{}{}",
+            shown,
+            " ".repeat(width.saturating_sub(plain.chars().count()))
+        )
+    };
+
+    let mut header = " ".repeat(label_width);
+    let mut rule = "─".repeat(label_width);
+    for column in &columns {
+        let label = truncate(
+            &format!("{} ({})", column.label, column.issues.len()),
+            column_width,
         );
-        println!("{}", "─".repeat(40));
+        let shown = match column.color {
+            Some(color) => label.color(color).bold(),
+            None => label.bold(),
+        };
+        header.push_str(&format!(
+            " │ {}",
+            pad(&label, shown.to_string(), column_width)
+        ));
+        rule.push_str(&format!("─┼─{}", "─".repeat(column_width)));
+    }
 
-        for issue in status_issues {
-            let project = issue.metadata.project.as_deref().unwrap_or("?");
-            println!(
-                "  • {} / {} [{}]",
-                project,
-                issue.metadata.title,
-                format_priority(&issue.metadata.priority)
-            );
+    let mut lines = vec![header.trim_end().to_string()];
+    for project in &projects {
+        lines.push(rule.clone());
+
+        let cells: Vec<Vec<&Issue>> = columns
+            .iter()
+            .map(|c| {
+                c.issues
+                    .iter()
+                    .copied()
+                    .filter(|i| issue_project_dir(i) == *project)
+                    .collect()
+            })
+            .collect();
+        let height = cells.iter().map(Vec::len).max().unwrap_or(0);
+
+        for row in 0..height {
+            let label = if row == 0 {
+                truncate(project, label_width)
+            } else {
+                String::new()
+            };
+            let mut line = pad(&label, label.bold().to_string(), label_width);
+            for cell in &cells {
+                let text = cell
+                    .get(row)
+                    .map(|i| truncate(&format!("• {}", i.metadata.title), column_width))
+                    .unwrap_or_default();
+                line.push_str(&format!(" │ {}", pad(&text, text.clone(), column_width)));
+            }
+            lines.push(line.trim_end().to_string());
         }
-        println!();
     }
 
-    Ok(())
+    Ok(lines)
+}
+
+/// One column of the board
+struct BoardColumn<'a> {
+    label: String,
+    color: Option<Color>,
+    issues: Vec<&'a Issue>,
 }
 
-fn format_status_label(status: &Status, label: &str) -> String {
-    use colored::Colorize;
+/// Partition issues into board columns by `group_by` (status, priority, or milestone).
+/// Empty columns are dropped, and cancelled issues are hidden unless `all_statuses`.
+fn board_columns<'a>(
+    issues: &'a [Issue],
+    group_by: &str,
+    all_statuses: bool,
+) -> Result<Vec<BoardColumn<'a>>> {
+    let visible: Vec<&Issue> = issues
+        .iter()
+        .filter(|i| all_statuses || i.metadata.status != Status::Cancelled)
+        .collect();
 
+    let column = |label: &str, color: Option<Color>, keep: &dyn Fn(&Issue) -> bool| BoardColumn {
+        label: label.to_string(),
+        color,
+        issues: visible.iter().copied().filter(|i| keep(i)).collect(),
+    };
+
+    let columns = match group_by {
+        "status" => Status::ALL
+            .iter()
+            .map(|status| {
+                column(status_label(status), Some(status_color(status)), &|i| {
+                    i.metadata.status == *status
+                })
+            })
+            .collect::<Vec<_>>(),
+        "priority" => Priority::ALL
+            .iter()
+            .rev()
+            .map(|priority| {
+                column(
+                    priority_label(priority),
+                    Some(priority_color(priority)),
+                    &|i| i.metadata.priority == *priority,
+                )
+            })
+            .collect(),
+        "milestone" => {
+            let mut milestones: Vec<&str> = visible
+                .iter()
+                .filter_map(|i| i.metadata.milestone.as_deref())
+                .collect();
+            milestones.sort_unstable();
+            milestones.dedup();
+
+            let mut columns: Vec<_> = milestones
+                .into_iter()
+                .map(|m| column(m, None, &|i| i.metadata.milestone.as_deref() == Some(m)))
+                .collect();
+            columns.push(column("No Milestone", None, &|i| {
+                i.metadata.milestone.is_none()
+            }));
+            columns
+        }
+        other => {
+            return Err(anyhow::anyhow!(
+                "Invalid grouping '{}'. Expected status, priority, or milestone",
+                other
+            ))
+        }
+    };
+
+    Ok(columns
+        .into_iter()
+        .filter(|c| !c.issues.is_empty())
+        .collect())
+}
+
+fn status_label(status: &Status) -> &'static str {
     match status {
-        Status::Backlog => label.white(),
-        Status::Todo => label.cyan(),
-        Status::InProgress => label.yellow(),
-        Status::Completed => label.green(),
-        Status::Cancelled => label.red(),
+        Status::Backlog => "Backlog",
+        Status::Todo => "Todo",
+        Status::InProgress => "In Progress",
+        Status::Completed => "Completed",
+        Status::Cancelled => "Cancelled",
     }
-    .bold()
-    .to_string()
 }
 
-fn format_priority(priority: &crate::models::Priority) -> String {
-    use crate::models::Priority;
-    use colored::Colorize;
+fn status_color(status: &Status) -> Color {
+    match status {
+        Status::Backlog => Color::White,
+        Status::Todo => Color::Cyan,
+        Status::InProgress => Color::Yellow,
+        Status::Completed => Color::Green,
+        Status::Cancelled => Color::Red,
+    }
+}
 
+fn priority_label(priority: &Priority) -> &'static str {
     match priority {
-        Priority::Low => "low".white(),
-        Priority::Medium => "medium".cyan(),
-        Priority::High => "high".yellow(),
-        Priority::Urgent => "urgent".red(),
+        Priority::Low => "Low",
+        Priority::Medium => "Medium",
+        Priority::High => "High",
+        Priority::Urgent => "Urgent",
+    }
+}
+
+fn priority_color(priority: &Priority) -> Color {
+    match priority {
+        Priority::Low => Color::White,
+        Priority::Medium => Color::Cyan,
+        Priority::High => Color::Yellow,
+        Priority::Urgent => Color::Red,
     }
-    .to_string()
 }
 
 #[cfg(test)]
@@ -217,11 +579,32 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        crate::commands::init(None)?;
-        crate::commands::create_project("project-a", None, "high")?;
-        crate::commands::create_issue("project-a", "Issue 1", "high", None, None)?;
-        crate::commands::create_issue("project-a", "Issue 2", "medium", None, None)?;
-        crate::commands::edit_issue("project-a/001", Some("in-progress"), None, None, None)?;
+        crate::commands::init(None, false)?;
+        crate::commands::create_project("project-a", None, Some("high"))?;
+        crate::commands::create_issue(
+            "project-a",
+            "Issue 1",
+            &crate::commands::NewIssue {
+                priority: Some("high"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::create_issue(
+            "project-a",
+            "Issue 2",
+            &crate::commands::NewIssue {
+                priority: Some("medium"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::edit_issue(
+            "project-a/001",
+            &crate::commands::IssueEdits {
+                status: Some("in-progress"),
+                ..Default::default()
+            },
+            false,
+        )?;
         env::set_current_dir(&original_dir)?;
 
         Ok(temp_dir)
@@ -233,36 +616,286 @@ mod tests {
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = status();
+        let result = status(None);
         env::set_current_dir(&original_dir)?;
 
         result?;
         Ok(())
     }
 
+    #[test]
+    fn test_porcelain_format() -> Result<()> {
+        let temp_dir = setup_workspace_with_data()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        crate::commands::create_project("b-side", None, Some("low"))?;
+        crate::commands::create_milestone("project-a", "v1.0", Some("2026-06-30"), None, None)?;
+        crate::commands::create_milestone("project-a", "Later", None, None, None)?;
+        let printed = status_porcelain(None);
+        env::set_current_dir(&original_dir)?;
+        printed?;
+
+        assert_eq!(
+            porcelain_lines(temp_dir.path(), None)?,
+            vec![
+                "project\tb-side\tbacklog\tlow",
+                "project\tproject-a\tbacklog\thigh",
+                "milestone\tproject-a/Later\tbacklog\t-",
+                "milestone\tproject-a/v1.0\tbacklog\t2026-06-30",
+                "issue\tproject-a/001\tin-progress\thigh",
+                "issue\tproject-a/002\tbacklog\tmedium",
+            ]
+        );
+        assert_eq!(porcelain_lines(temp_dir.path(), Some("b-side"))?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_scoped_to_project() -> Result<()> {
+        let temp_dir = setup_workspace_with_data()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        crate::commands::create_project("project-b", None, None)?;
+        crate::commands::create_issue(
+            "project-b",
+            "Elsewhere",
+            &crate::commands::NewIssue::default(),
+        )?;
+        let scoped = status(Some("project-a"));
+        let missing = status(Some("no-such-project"));
+        env::set_current_dir(&original_dir)?;
+
+        scoped?;
+        assert!(missing.is_err());
+
+        let base_dir = temp_dir.path();
+        let names = |selected: Vec<(Project, Vec<Issue>)>| -> Vec<(String, Vec<String>)> {
+            selected
+                .into_iter()
+                .map(|(p, issues)| {
+                    let mut titles: Vec<_> = issues.into_iter().map(|i| i.metadata.title).collect();
+                    titles.sort();
+                    (p.metadata.name, titles)
+                })
+                .collect()
+        };
+        assert_eq!(
+            names(status_projects(base_dir, Some("project-a"))?),
+            [(
+                "project-a".to_string(),
+                vec!["Issue 1".to_string(), "Issue 2".to_string()]
+            )]
+        );
+        assert_eq!(
+            names(status_projects(base_dir, None)?)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>(),
+            ["project-a", "project-b"]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_board_command() -> Result<()> {
         let temp_dir = setup_workspace_with_data()?;
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = board(Some("project-a"));
+        let result = board(Some("project-a"), false, "status", false);
         env::set_current_dir(&original_dir)?;
 
         result?;
         Ok(())
     }
 
+    #[test]
+    fn test_board_all_statuses() -> Result<()> {
+        let temp_dir = setup_workspace_with_data()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        for (title, status) in [
+            ("Issue 3", "todo"),
+            ("Issue 4", "completed"),
+            ("Issue 5", "cancelled"),
+        ] {
+            crate::commands::create_issue(
+                "project-a",
+                title,
+                &crate::commands::NewIssue::default(),
+            )?;
+            crate::commands::edit_issue(
+                &format!("project-a/{}", title.replace("Issue ", "00")),
+                &crate::commands::IssueEdits {
+                    status: Some(status),
+                    ..Default::default()
+                },
+                false,
+            )?;
+        }
+        let result = board(Some("project-a"), true, "status", false);
+        env::set_current_dir(&original_dir)?;
+
+        result?;
+
+        let issues = crate::fs::list_issues(temp_dir.path().join("project-a"))?;
+        let rendered = column_lines(&issues, "status", true)?.join("\n");
+        for status in Status::ALL {
+            assert!(rendered.contains(status_label(&status)), "{}", rendered);
+        }
+        for n in 1..=5 {
+            assert!(rendered.contains(&format!("Issue {}", n)), "{}", rendered);
+        }
+
+        let hidden = column_lines(&issues, "status", false)?.join("\n");
+        assert!(!hidden.contains("Cancelled"));
+        assert!(!hidden.contains("Issue 5"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_board_all_projects() -> Result<()> {
         let temp_dir = setup_workspace_with_data()?;
         let original_dir = env::current_dir()?;
 
         env::set_current_dir(temp_dir.path())?;
-        let result = board(None);
+        let result = board(None, false, "status", false);
         env::set_current_dir(&original_dir)?;
 
         result?;
         Ok(())
     }
+
+    fn lane_issue(project: &str, id: &str, title: &str, status: Status) -> Issue {
+        Issue {
+            metadata: crate::models::IssueMetadata {
+                title: title.to_string(),
+                status,
+                priority: Priority::Medium,
+                severity: None,
+                project: Some(project.to_string()),
+                milestone: None,
+                tags: vec![],
+                assignee: None,
+                reporter: None,
+                url: None,
+                created: None,
+                updated: None,
+            },
+            description: String::new(),
+            path: std::path::PathBuf::from(format!("{}/issues/{}-x.md", project, id)),
+        }
+    }
+
+    #[test]
+    fn test_board_swimlanes() -> Result<()> {
+        colored::control::set_override(false);
+
+        let issues = vec![
+            lane_issue(
+                "beta",
+                "001",
+                "Write the release announcement",
+                Status::Todo,
+            ),
+            lane_issue("alpha", "001", "Fix login", Status::Todo),
+            lane_issue("alpha", "002", "Add export", Status::Todo),
+            lane_issue("alpha", "003", "Ship it", Status::Completed),
+        ];
+
+        let lines = swimlane_lines(&issues, "status", false, 56)?;
+        assert_eq!(
+            lines,
+            [
+                "      │ Todo (3)               │ Completed (1)",
+                "──────┼────────────────────────┼───────────────────────",
+                "alpha │ • Fix login            │ • Ship it",
+                "      │ • Add export           │",
+                "──────┼────────────────────────┼───────────────────────",
+                "beta  │ • Write the release a… │",
+            ]
+        );
+        assert!(lines.iter().all(|l| l.chars().count() <= 56));
+
+        Ok(())
+    }
+
+    fn column_labels(issues: &[Issue], group_by: &str, all_statuses: bool) -> Vec<String> {
+        board_columns(issues, group_by, all_statuses)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.label)
+            .collect()
+    }
+
+    #[test]
+    fn test_board_groupings() -> Result<()> {
+        let temp_dir = setup_workspace_with_data()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        crate::commands::create_milestone("project-a", "v1.0", None, None, None)?;
+        crate::commands::create_issue(
+            "project-a",
+            "Issue 3",
+            &crate::commands::NewIssue {
+                priority: Some("urgent"),
+                milestone: Some("v1.0"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::create_issue(
+            "project-a",
+            "Issue 4",
+            &crate::commands::NewIssue {
+                priority: Some("low"),
+                ..Default::default()
+            },
+        )?;
+        crate::commands::edit_issue(
+            "project-a/004",
+            &crate::commands::IssueEdits {
+                status: Some("cancelled"),
+                ..Default::default()
+            },
+            false,
+        )?;
+        let printed = board(None, false, "milestone", false);
+        env::set_current_dir(&original_dir)?;
+        printed?;
+
+        let issues = list_all_issues(temp_dir.path())?;
+
+        assert_eq!(
+            column_labels(&issues, "status", false),
+            vec!["Backlog", "In Progress"]
+        );
+        assert_eq!(
+            column_labels(&issues, "status", true),
+            vec!["Backlog", "In Progress", "Cancelled"]
+        );
+        assert_eq!(
+            column_labels(&issues, "priority", false),
+            vec!["Urgent", "High", "Medium"]
+        );
+        assert_eq!(
+            column_labels(&issues, "priority", true),
+            vec!["Urgent", "High", "Medium", "Low"]
+        );
+        assert_eq!(
+            column_labels(&issues, "milestone", false),
+            vec!["v1.0", "No Milestone"]
+        );
+
+        let by_priority = board_columns(&issues, "priority", false)?;
+        assert_eq!(by_priority[0].issues[0].metadata.title, "Issue 3");
+        assert!(board_columns(&issues, "assignee", false).is_err());
+
+        Ok(())
+    }
 }
@@ -1,15 +1,20 @@
-use crate::models::{Issue, Milestone, Project};
-use anyhow::Result;
+use crate::models::{Issue, Milestone, Project, Status};
+use anyhow::{Context, Result};
 use axum::{
-    extract::Path,
+    extract::{Path, Request, State},
     http::{header, StatusCode, Uri},
-    response::IntoResponse,
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
     routing::{get, patch, post},
     Json, Router,
 };
+use chrono::Utc;
 use rust_embed::RustEmbed;
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
 
 #[derive(RustEmbed)]
@@ -28,6 +33,10 @@ struct UIMilestone {
     #[serde(flatten)]
     inner: Milestone,
     id: String,
+    /// Issues in the project assigned to this milestone
+    issue_count: usize,
+    /// How many of those are completed
+    completed_count: usize,
 }
 
 #[derive(Serialize)]
@@ -44,6 +53,8 @@ struct UIData {
     projects: Vec<UIProject>,
     milestones: Vec<UIMilestone>,
     issues: Vec<UIIssue>,
+    /// Files that could not be parsed and were left out of the lists above
+    errors: Vec<String>,
 }
 
 #[derive(Deserialize)]
@@ -59,9 +70,15 @@ struct UpdateIssueRequest {
 struct CreateIssueRequest {
     project: String,
     title: String,
-    priority: String,
+    priority: Option<String>,
     milestone: Option<String>,
     tags: Option<String>,
+    template: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AddCommentRequest {
+    content: String,
 }
 
 #[derive(Deserialize)]
@@ -75,7 +92,7 @@ struct UpdateProjectRequest {
 struct CreateProjectRequest {
     name: String,
     id: Option<String>,
-    priority: String,
+    priority: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -90,46 +107,141 @@ struct CreateMilestoneRequest {
     project: String,
     title: String,
     date: Option<String>,
+    status: Option<String>,
+    priority: Option<String>,
 }
 
-pub async fn run_ui(port: u16) -> Result<()> {
+/// How many ports past the requested one `--auto-port` will try
+const AUTO_PORT_ATTEMPTS: u16 = 20;
+
+pub async fn run_ui(
+    port: u16,
+    auto_port: bool,
+    token: Option<&str>,
+    read_only: bool,
+) -> Result<()> {
     // Assets are embedded at compile time.
     // If the UI isn't built, Assets::iter() will be empty or folder won't exist.
+    if token.is_some_and(|t| t.trim().is_empty()) {
+        return Err(anyhow::anyhow!("--token cannot be empty"));
+    }
+
     if Assets::iter().count() == 0 {
         println!("Warning: No UI assets found. Did you run 'npm run build' in services/ui before compiling?");
     }
 
-    let app = Router::new()
-        .route(
-            "/api/data",
-            get(move || async move {
-                match get_ui_data() {
-                    Ok(data) => Json(data).into_response(),
-                    Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
-                }
-            }),
-        )
-        .route("/api/issues/:project/:number", patch(update_issue_handler))
-        .route("/api/issues", post(create_issue_handler))
-        .route("/api/projects/:id", patch(update_project_handler))
-        .route("/api/projects", post(create_project_handler))
-        .route(
-            "/api/milestones/:project/:title",
-            patch(update_milestone_handler),
-        )
-        .route("/api/milestones", post(create_milestone_handler))
-        .fallback(get(static_handler))
-        .layer(CorsLayer::permissive());
-
-    let addr = SocketAddr::from(([127, 0, 0, 1], port));
-    println!("Starting Pillar UI on http://{}", addr);
-
-    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let app = router(token, read_only);
+
+    let listener = bind_listener(port, auto_port).await?;
+    match token {
+        // The page picks the token up from the query string and sends it with API calls
+        Some(token) => println!(
+            "Starting Pillar UI on http://{}/?token={}",
+            listener.local_addr()?,
+            token
+        ),
+        None => println!("Starting Pillar UI on http://{}", listener.local_addr()?),
+    }
+    if read_only {
+        println!("Read-only mode: changes made in the UI will be rejected");
+    }
+
     axum::serve(listener, app).await?;
 
     Ok(())
 }
 
+/// API routes need the bearer token when one is set; static assets are always public.
+/// In read-only mode only the GET routes exist, so anything else gets a 405.
+fn router(token: Option<&str>, read_only: bool) -> Router {
+    let comments = get(list_comments_handler);
+    let mut api = Router::new().route(
+        "/api/data",
+        get(move || async move {
+            match get_ui_data() {
+                Ok(data) => Json(data).into_response(),
+                Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+            }
+        }),
+    );
+
+    if read_only {
+        api = api.route("/api/comments/:entity_type/:project/:identifier", comments);
+    } else {
+        api = api
+            .route(
+                "/api/comments/:entity_type/:project/:identifier",
+                comments.post(add_comment_handler),
+            )
+            .route("/api/issues/:project/:number", patch(update_issue_handler))
+            .route("/api/issues", post(create_issue_handler))
+            .route("/api/projects/:id", patch(update_project_handler))
+            .route("/api/projects", post(create_project_handler))
+            .route(
+                "/api/milestones/:project/:title",
+                patch(update_milestone_handler),
+            )
+            .route("/api/milestones", post(create_milestone_handler));
+    }
+
+    if let Some(token) = token {
+        api = api.route_layer(middleware::from_fn_with_state(
+            Arc::<str>::from(format!("Bearer {}", token)),
+            require_token,
+        ));
+    }
+
+    api.fallback(get(static_handler))
+        .layer(CompressionLayer::new())
+        .layer(CorsLayer::permissive())
+}
+
+async fn require_token(State(expected): State<Arc<str>>, request: Request, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .is_some_and(|value| constant_time_eq(value.as_bytes(), expected.as_bytes()));
+
+    if authorized {
+        next.run(request).await
+    } else {
+        (StatusCode::UNAUTHORIZED, "Missing or invalid token").into_response()
+    }
+}
+
+/// Compare secrets without stopping at the first differing byte, so response times don't
+/// reveal how much of a guessed token was right. Only the length can leak.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Bind to `port` on localhost, or to the next free port after it when `auto_port` is set
+async fn bind_listener(port: u16, auto_port: bool) -> Result<TcpListener> {
+    let attempts = if auto_port { AUTO_PORT_ATTEMPTS } else { 1 };
+
+    for candidate in (port..=u16::MAX).take(attempts as usize) {
+        let addr = SocketAddr::from(([127, 0, 0, 1], candidate));
+        match TcpListener::bind(addr).await {
+            Ok(listener) => return Ok(listener),
+            Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => continue,
+            Err(e) => return Err(e).with_context(|| format!("Failed to listen on {}", addr)),
+        }
+    }
+
+    if auto_port {
+        Err(anyhow::anyhow!(
+            "Ports {} to {} are all in use. Pick another with --port",
+            port,
+            port.saturating_add(attempts - 1)
+        ))
+    } else {
+        Err(anyhow::anyhow!(
+            "Port {} is already in use. Pick another with --port, or pass --auto-port to use the next free one",
+            port
+        ))
+    }
+}
+
 async fn update_issue_handler(
     Path((project, number)): Path<(String, String)>,
     Json(payload): Json<UpdateIssueRequest>,
@@ -137,10 +249,14 @@ async fn update_issue_handler(
     let id = format!("{}/{}", project, number);
     match crate::commands::edit_issue(
         &id,
-        payload.status.as_deref(),
-        payload.priority.as_deref(),
-        payload.milestone.as_deref(),
-        payload.tags.as_deref(),
+        &crate::commands::IssueEdits {
+            status: payload.status.as_deref(),
+            priority: payload.priority.as_deref(),
+            milestone: payload.milestone.as_deref(),
+            tags: payload.tags.as_deref(),
+            ..Default::default()
+        },
+        false,
     ) {
         Ok(_) => {
             // If description is provided, we need to update it separately since edit_issue doesn't support it yet
@@ -157,23 +273,17 @@ async fn update_issue_handler(
 
 fn update_issue_description(id: &str, content: &str) -> Result<()> {
     let base_dir = crate::fs::get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
     let (project_name, issue_id) = id
         .split_once('/')
         .ok_or_else(|| anyhow::anyhow!("Invalid ID"))?;
-    let project_path = base_dir.join(project_name);
-    let issues = crate::fs::list_issues(&project_path)?;
-    let issue = issues
-        .into_iter()
-        .find(|i| {
-            i.path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .and_then(|s| s.split('-').next())
-                == Some(issue_id)
-        })
-        .ok_or_else(|| anyhow::anyhow!("Issue not found"))?;
+    let project_path = crate::fs::project_path(&base_dir, project_name)?;
+    let issue = crate::fs::find_issue(&project_path, issue_id)?;
 
-    crate::parser::write_with_frontmatter(&issue.path, &issue.metadata, content)?;
+    let mut metadata = issue.metadata;
+    metadata.updated = Some(Utc::now());
+    crate::parser::write_with_frontmatter(&issue.path, &metadata, content)?;
+    crate::fs::record_activity("edit", "issue", id, vec![description_change()]);
     Ok(())
 }
 
@@ -181,9 +291,13 @@ async fn create_issue_handler(Json(payload): Json<CreateIssueRequest>) -> impl I
     match crate::commands::create_issue(
         &payload.project,
         &payload.title,
-        &payload.priority,
-        payload.milestone.as_deref(),
-        payload.tags.as_deref(),
+        &crate::commands::NewIssue {
+            priority: payload.priority.as_deref(),
+            milestone: payload.milestone.as_deref(),
+            tags: payload.tags.as_deref(),
+            template: payload.template.as_deref(),
+            ..Default::default()
+        },
     ) {
         Ok(_) => StatusCode::CREATED.into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
@@ -210,17 +324,27 @@ async fn update_project_handler(
 
 fn update_project_description(name: &str, content: &str) -> Result<()> {
     let base_dir = crate::fs::get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
     let project = crate::fs::find_project(&base_dir, name)?;
-    crate::parser::write_with_frontmatter(
-        project.path.join("README.md"),
-        &project.metadata,
-        content,
-    )?;
+
+    let mut metadata = project.metadata;
+    metadata.updated = Some(Utc::now());
+    crate::parser::write_with_frontmatter(project.path.join("README.md"), &metadata, content)?;
+    crate::fs::record_activity(
+        "edit",
+        "project",
+        &metadata.name,
+        vec![description_change()],
+    );
     Ok(())
 }
 
 async fn create_project_handler(Json(payload): Json<CreateProjectRequest>) -> impl IntoResponse {
-    match crate::commands::create_project(&payload.name, payload.id.as_deref(), &payload.priority) {
+    match crate::commands::create_project(
+        &payload.name,
+        payload.id.as_deref(),
+        payload.priority.as_deref(),
+    ) {
         Ok(_) => StatusCode::CREATED.into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -235,6 +359,7 @@ async fn update_milestone_handler(
         &title,
         payload.status.as_deref(),
         payload.target_date.as_deref(),
+        None,
     ) {
         Ok(_) => {
             if let Some(content) = payload.description {
@@ -250,6 +375,7 @@ async fn update_milestone_handler(
 
 fn update_milestone_description(project_name: &str, title: &str, content: &str) -> Result<()> {
     let base_dir = crate::fs::get_base_directory()?;
+    let _lock = crate::fs::lock_workspace()?;
     let project = crate::fs::find_project(&base_dir, project_name)?;
     let milestones = crate::fs::list_milestones(&project.path)?;
     let milestone = milestones
@@ -257,10 +383,23 @@ fn update_milestone_description(project_name: &str, title: &str, content: &str)
         .find(|m| m.metadata.title == title)
         .ok_or_else(|| anyhow::anyhow!("Milestone not found"))?;
 
-    crate::parser::write_with_frontmatter(&milestone.path, &milestone.metadata, content)?;
+    let mut metadata = milestone.metadata;
+    metadata.updated = Some(Utc::now());
+    crate::parser::write_with_frontmatter(&milestone.path, &metadata, content)?;
+    crate::fs::record_activity(
+        "edit",
+        "milestone",
+        &format!("{}/{}", project.metadata.name, title),
+        vec![description_change()],
+    );
     Ok(())
 }
 
+/// Description edits are logged without their (potentially long) contents
+fn description_change() -> crate::models::FieldChange {
+    crate::models::FieldChange::new("description", None, None)
+}
+
 async fn create_milestone_handler(
     Json(payload): Json<CreateMilestoneRequest>,
 ) -> impl IntoResponse {
@@ -268,12 +407,72 @@ async fn create_milestone_handler(
         &payload.project,
         &payload.title,
         payload.date.as_deref(),
+        payload.status.as_deref(),
+        payload.priority.as_deref(),
     ) {
         Ok(_) => StatusCode::CREATED.into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
+/// The response for a comment request whose target is wrong: 400 for an entity type that
+/// can't have comments, 404 for a project, milestone, or issue that doesn't exist
+fn comment_target_error(entity_type: &str, project: &str, identifier: &str) -> Option<Response> {
+    if !matches!(entity_type, "project" | "milestone" | "issue") {
+        return Some(
+            (
+                StatusCode::BAD_REQUEST,
+                format!("Invalid entity type: {}", entity_type),
+            )
+                .into_response(),
+        );
+    }
+    match crate::commands::comment::target_exists(entity_type, project, Some(identifier)) {
+        Ok(false) => Some(
+            (
+                StatusCode::NOT_FOUND,
+                format!(
+                    "{} '{}' not found in '{}'",
+                    entity_type, identifier, project
+                ),
+            )
+                .into_response(),
+        ),
+        _ => None,
+    }
+}
+
+async fn list_comments_handler(
+    Path((entity_type, project, identifier)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    if let Some(response) = comment_target_error(&entity_type, &project, &identifier) {
+        return response;
+    }
+    match crate::commands::comment::list_comments(&entity_type, &project, Some(&identifier)) {
+        Ok(comments) => Json(comments).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// Responds with the whole thread so the UI can redraw it in one go
+async fn add_comment_handler(
+    Path((entity_type, project, identifier)): Path<(String, String, String)>,
+    Json(payload): Json<AddCommentRequest>,
+) -> impl IntoResponse {
+    if let Some(response) = comment_target_error(&entity_type, &project, &identifier) {
+        return response;
+    }
+    let result =
+        crate::commands::comment::add(&entity_type, &project, Some(&identifier), &payload.content)
+            .and_then(|_| {
+                crate::commands::comment::list_comments(&entity_type, &project, Some(&identifier))
+            });
+    match result {
+        Ok(comments) => (StatusCode::CREATED, Json(comments)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
 async fn static_handler(uri: Uri) -> impl IntoResponse {
     let path = uri.path();
 
@@ -292,7 +491,13 @@ async fn static_handler(uri: Uri) -> impl IntoResponse {
 
 fn get_ui_data() -> Result<UIData> {
     let base_dir = crate::fs::get_base_directory()?;
-    let projects = crate::fs::list_projects(&base_dir)?;
+    let loaded_projects = crate::fs::load_projects(&base_dir)?;
+    let projects = loaded_projects.items;
+    let mut errors: Vec<String> = loaded_projects
+        .errors
+        .iter()
+        .map(|e| e.to_string())
+        .collect();
 
     let mut ui_projects = Vec::new();
     let mut ui_milestones = Vec::new();
@@ -307,26 +512,42 @@ fn get_ui_data() -> Result<UIData> {
             inner: project,
         });
 
-        if let Ok(p_milestones) = crate::fs::list_milestones(&project_path) {
-            for m in p_milestones {
+        let issues = match crate::fs::load_issues(&project_path) {
+            Ok(p_issues) => {
+                errors.extend(p_issues.errors.iter().map(|e| e.to_string()));
+                p_issues.items
+            }
+            Err(_) => Vec::new(),
+        };
+
+        if let Ok(p_milestones) = crate::fs::load_milestones(&project_path) {
+            errors.extend(p_milestones.errors.iter().map(|e| e.to_string()));
+            for m in p_milestones.items {
+                let assigned: Vec<&Issue> = issues
+                    .iter()
+                    .filter(|i| i.metadata.milestone.as_ref() == Some(&m.metadata.title))
+                    .collect();
                 ui_milestones.push(UIMilestone {
                     id: m.metadata.title.clone(),
+                    issue_count: assigned.len(),
+                    completed_count: assigned
+                        .iter()
+                        .filter(|i| i.metadata.status == Status::Completed)
+                        .count(),
                     inner: m,
                 });
             }
         }
 
-        if let Ok(p_issues) = crate::fs::list_issues(&project_path) {
-            for i in p_issues {
-                let filename = i.path.file_name().and_then(|f| f.to_str()).unwrap_or("");
-                let number = filename.split('-').next().unwrap_or("000").to_string();
+        for i in issues {
+            let filename = i.path.file_name().and_then(|f| f.to_str()).unwrap_or("");
+            let number = filename.split('-').next().unwrap_or("000").to_string();
 
-                ui_issues.push(UIIssue {
-                    id: format!("{}/{}", project_id, number),
-                    number,
-                    inner: i,
-                });
-            }
+            ui_issues.push(UIIssue {
+                id: format!("{}/{}", project_id, number),
+                number,
+                inner: i,
+            });
         }
     }
 
@@ -343,5 +564,304 @@ fn get_ui_data() -> Result<UIData> {
         projects: ui_projects,
         milestones: ui_milestones,
         issues: ui_issues,
+        errors,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{create_issue, create_milestone, create_project, init, NewIssue};
+    use chrono::{Duration, Utc};
+    use std::env;
+    use tempfile::TempDir;
+
+    /// Push every `updated` timestamp an hour into the past so a bump is detectable
+    fn backdate(path: &std::path::Path) -> Result<()> {
+        let content = std::fs::read_to_string(path)?;
+        let (frontmatter, body) = crate::parser::split_frontmatter(&content)?;
+        let mut value: serde_yaml::Value = serde_yaml::from_str(frontmatter)?;
+        let past = (Utc::now() - Duration::hours(1)).to_rfc3339();
+        value["updated"] = serde_yaml::Value::String(past);
+        crate::parser::write_with_frontmatter(path, &value, body)
+    }
+
+    #[test]
+    fn test_description_updates_bump_updated() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let project_dir = temp_dir.path().join("alpha");
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, None)?;
+        create_milestone("alpha", "v1", None, None, None)?;
+        create_issue("alpha", "Task", &NewIssue::default())?;
+
+        let issue_path = crate::fs::find_issue(&project_dir, "001")?.path;
+        let milestone_path = project_dir.join("milestones/v1.md");
+        let readme_path = project_dir.join("README.md");
+        for path in [&issue_path, &milestone_path, &readme_path] {
+            backdate(path)?;
+        }
+        let before = Utc::now() - Duration::minutes(1);
+
+        let results = [
+            update_issue_description("alpha/001", "New issue body"),
+            update_milestone_description("alpha", "v1", "New milestone body"),
+            update_project_description("alpha", "New project body"),
+        ];
+        env::set_current_dir(&original_dir)?;
+
+        for result in results {
+            result?;
+        }
+
+        let issue = crate::parser::read_issue(&issue_path)?;
+        assert_eq!(issue.description, "New issue body");
+        assert!(issue.metadata.updated.unwrap() > before);
+
+        let milestone = crate::parser::read_milestone(&milestone_path)?;
+        assert!(milestone.metadata.updated.unwrap() > before);
+
+        let project = crate::parser::read_project(&project_dir)?;
+        assert!(project.metadata.updated.unwrap() > before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_milestone_issue_counts() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, None)?;
+        create_milestone("alpha", "v1", None, None, None)?;
+        create_milestone("alpha", "v2", None, None, None)?;
+        create_issue(
+            "alpha",
+            "One",
+            &NewIssue {
+                milestone: Some("v1"),
+                ..Default::default()
+            },
+        )?;
+        create_issue(
+            "alpha",
+            "Two",
+            &NewIssue {
+                milestone: Some("v1"),
+                ..Default::default()
+            },
+        )?;
+        create_issue("alpha", "Loose", &NewIssue::default())?;
+        let edited = crate::commands::edit_issue(
+            "alpha/001",
+            &crate::commands::IssueEdits {
+                status: Some("completed"),
+                ..Default::default()
+            },
+            false,
+        );
+        let data = get_ui_data();
+        env::set_current_dir(&original_dir)?;
+
+        edited?;
+        let data = data?;
+        let counts = |title: &str| {
+            let m = data
+                .milestones
+                .iter()
+                .find(|m| m.inner.metadata.title == title)
+                .unwrap();
+            (m.issue_count, m.completed_count)
+        };
+        assert_eq!(counts("v1"), (2, 1));
+        assert_eq!(counts("v2"), (0, 0));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bind_listener_reports_taken_port() -> Result<()> {
+        let taken = TcpListener::bind("127.0.0.1:0").await?;
+        let port = taken.local_addr()?.port();
+
+        let err = bind_listener(port, false).await.unwrap_err().to_string();
+        assert!(err.contains(&format!("Port {} is already in use", port)));
+        assert!(err.contains("--port"));
+
+        let next = bind_listener(port, true).await?;
+        assert_ne!(next.local_addr()?.port(), port);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_api_responses_are_compressed() -> Result<()> {
+        use tower::ServiceExt;
+
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, None)?;
+        let request = axum::http::Request::get("/api/data")
+            .header(header::ACCEPT_ENCODING, "gzip")
+            .body(axum::body::Body::empty())?;
+        let response = router(None, false).oneshot(request).await;
+        env::set_current_dir(&original_dir)?;
+
+        let response = response?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_token_required_for_api_routes() -> Result<()> {
+        use tower::ServiceExt;
+
+        let request = |path: &str, auth: Option<&str>| {
+            let mut builder = axum::http::Request::get(path);
+            if let Some(auth) = auth {
+                builder = builder.header(header::AUTHORIZATION, auth);
+            }
+            builder.body(axum::body::Body::empty())
+        };
+
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        let app = router(Some("secret"), false);
+        let responses = [
+            app.clone().oneshot(request("/api/data", None)?).await,
+            app.clone()
+                .oneshot(request("/api/data", Some("Bearer wrong"))?)
+                .await,
+            app.clone()
+                .oneshot(request("/api/data", Some("Bearer secrex"))?)
+                .await,
+            app.clone()
+                .oneshot(request("/api/data", Some("Bearer secret"))?)
+                .await,
+            app.oneshot(request("/", None)?).await,
+        ];
+        env::set_current_dir(&original_dir)?;
+
+        let statuses = responses
+            .into_iter()
+            .map(|r| r.map(|r| r.status()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(statuses[0], StatusCode::UNAUTHORIZED);
+        assert_eq!(statuses[1], StatusCode::UNAUTHORIZED);
+        assert_eq!(statuses[2], StatusCode::UNAUTHORIZED);
+        assert_eq!(statuses[3], StatusCode::OK);
+        assert_ne!(statuses[4], StatusCode::UNAUTHORIZED);
+
+        assert!(run_ui(0, false, Some(""), false).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_comment_errors_use_client_statuses() -> Result<()> {
+        use tower::ServiceExt;
+
+        let get = |path: &str| axum::http::Request::get(path).body(axum::body::Body::empty());
+        let post = |path: &str| {
+            axum::http::Request::post(path)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(axum::body::Body::from(r#"{"content": "hi"}"#))
+        };
+
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+
+        env::set_current_dir(temp_dir.path())?;
+        init(None, false)?;
+        create_project("alpha", None, None)?;
+        create_issue("alpha", "Task", &NewIssue::default())?;
+        let app = router(None, false);
+        let responses = [
+            app.clone()
+                .oneshot(get("/api/comments/issue/alpha/001")?)
+                .await,
+            app.clone()
+                .oneshot(get("/api/comments/issue/alpha/999")?)
+                .await,
+            app.clone()
+                .oneshot(get("/api/comments/issue/nowhere/001")?)
+                .await,
+            app.clone()
+                .oneshot(get("/api/comments/task/alpha/001")?)
+                .await,
+            app.clone()
+                .oneshot(post("/api/comments/issue/alpha/999")?)
+                .await,
+            app.oneshot(post("/api/comments/task/alpha/001")?).await,
+        ];
+        env::set_current_dir(&original_dir)?;
+
+        let statuses = responses
+            .into_iter()
+            .map(|r| r.map(|r| r.status()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            statuses,
+            [
+                StatusCode::OK,
+                StatusCode::NOT_FOUND,
+                StatusCode::NOT_FOUND,
+                StatusCode::BAD_REQUEST,
+                StatusCode::NOT_FOUND,
+                StatusCode::BAD_REQUEST,
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_only_rejects_changes() -> Result<()> {
+        use tower::ServiceExt;
+
+        let post = |path: &str, body: &str| {
+            axum::http::Request::post(path)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(axum::body::Body::from(body.to_string()))
+        };
+
+        let app = router(None, true);
+        let create = app
+            .clone()
+            .oneshot(post("/api/projects", r#"{"name": "alpha"}"#)?)
+            .await?;
+        let comment = app
+            .clone()
+            .oneshot(post(
+                "/api/comments/project/alpha/alpha",
+                r#"{"content": "hi"}"#,
+            )?)
+            .await?;
+        let edit = app
+            .oneshot(
+                axum::http::Request::patch("/api/issues/alpha/001")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::from("{}"))?,
+            )
+            .await?;
+
+        assert_eq!(create.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(comment.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(edit.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        Ok(())
+    }
+}
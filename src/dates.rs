@@ -0,0 +1,149 @@
+//! Parsing of date arguments given on the command line
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Days, NaiveDate, NaiveTime, Utc, Weekday};
+
+const DATE_FORMS: &str = "YYYY-MM-DD, today, tomorrow, yesterday, +7d, +2w, or next-friday";
+
+/// Resolve a date argument against today's date (UTC) into YYYY-MM-DD
+pub fn resolve_date(value: &str) -> Result<String> {
+    Ok(parse_date(value, Utc::now().date_naive())?
+        .format("%Y-%m-%d")
+        .to_string())
+}
+
+/// Parse an absolute (YYYY-MM-DD) or relative date, relative to `today`:
+/// `today`, `tomorrow`, `yesterday`, `+<n>d`, `+<n>w`, or `next-<weekday>`
+/// (the first such day after today)
+pub fn parse_date(value: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let invalid = || anyhow::anyhow!("Invalid date '{}'. Expected {}", value, DATE_FORMS);
+    let lowered = value.trim().to_lowercase();
+
+    let days_from_today = match lowered.as_str() {
+        "today" => Some(0),
+        "tomorrow" => Some(1),
+        "yesterday" => Some(-1),
+        other => {
+            if let Some(offset) = other.strip_prefix('+') {
+                let (count, days_per_unit) = if let Some(count) = offset.strip_suffix('d') {
+                    (count, 1)
+                } else if let Some(count) = offset.strip_suffix('w') {
+                    (count, 7)
+                } else {
+                    return Err(invalid());
+                };
+                // Digits only, so "+-3d" isn't read as three days ago
+                if count.is_empty() || !count.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                let count: i64 = count.parse().map_err(|_| invalid())?;
+                Some(count.checked_mul(days_per_unit).ok_or_else(invalid)?)
+            } else if let Some(day) = other.strip_prefix("next-") {
+                let weekday: Weekday = day.parse().map_err(|_| invalid())?;
+                let ahead = (weekday.num_days_from_monday() + 7
+                    - today.weekday().num_days_from_monday())
+                    % 7;
+                Some(if ahead == 0 { 7 } else { i64::from(ahead) })
+            } else {
+                None
+            }
+        }
+    };
+
+    match days_from_today {
+        Some(days) if days >= 0 => today.checked_add_days(Days::new(days.unsigned_abs())),
+        Some(days) => today.checked_sub_days(Days::new(days.unsigned_abs())),
+        None => NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok(),
+    }
+    .ok_or_else(invalid)
+}
+
+/// Parse a lower date bound such as `--since`: a date accepted by `parse_date`
+/// (start of day, UTC) or an RFC3339 timestamp
+pub fn parse_since(value: &str) -> Result<DateTime<Utc>> {
+    parse_bound(value, NaiveTime::MIN)
+}
+
+/// Parse an inclusive upper date bound such as `--until`: a date accepted by `parse_date`
+/// (end of day, UTC) or an RFC3339 timestamp
+pub fn parse_until(value: &str) -> Result<DateTime<Utc>> {
+    let end_of_day =
+        NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap_or(NaiveTime::MIN);
+    parse_bound(value, end_of_day)
+}
+
+fn parse_bound(value: &str, time_of_day: NaiveTime) -> Result<DateTime<Utc>> {
+    if let Ok(date) = parse_date(value, Utc::now().date_naive()) {
+        return Ok(date.and_time(time_of_day).and_utc());
+    }
+
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid date '{}'. Expected {}, or an RFC3339 timestamp",
+                value,
+                DATE_FORMS
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_relative_dates() -> Result<()> {
+        // A Wednesday
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+        assert_eq!(parse_date("today", today)?, today);
+        assert_eq!(parse_date("Tomorrow", today)?, date("2026-10-15"));
+        assert_eq!(parse_date("yesterday", today)?, date("2026-10-13"));
+        assert_eq!(parse_date("+7d", today)?, date("2026-10-21"));
+        assert_eq!(parse_date("+2w", today)?, date("2026-10-28"));
+        assert_eq!(parse_date("+0d", today)?, today);
+        assert_eq!(parse_date("next-friday", today)?, date("2026-10-16"));
+        assert_eq!(parse_date("next-mon", today)?, date("2026-10-19"));
+        assert_eq!(parse_date("next-wednesday", today)?, date("2026-10-21"));
+        assert_eq!(parse_date("2026-12-31", today)?, date("2026-12-31"));
+
+        let overflow = format!("+{}w", i64::MAX);
+        for invalid in [
+            "soon",
+            "+d",
+            "+3m",
+            "-2d",
+            "+-3d",
+            "++3d",
+            "+3é",
+            &overflow,
+            "next-someday",
+            "2026-02-30",
+            "",
+        ] {
+            let err = parse_date(invalid, today).unwrap_err().to_string();
+            assert!(err.contains("next-friday"), "{}", invalid);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_bounds() -> Result<()> {
+        assert_eq!(
+            parse_since("2026-03-02")?.to_rfc3339(),
+            "2026-03-02T00:00:00+00:00"
+        );
+        assert!(parse_until("2026-03-02")? > parse_since("2026-03-02T23:59:59Z")?);
+        assert!(parse_until("2026-03-02")? < parse_since("2026-03-03")?);
+        assert_eq!(
+            parse_until("2026-03-02T10:00:00+02:00")?.to_rfc3339(),
+            "2026-03-02T08:00:00+00:00"
+        );
+        assert!(parse_since("yesterday-ish").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,336 @@
+//! Terminal formatting shared by every command
+
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::RwLock;
+
+use colored::{Color, Colorize};
+
+use crate::models::{FieldChange, Priority, Status, StatusStyle};
+
+static EMOJI_STATUS: AtomicBool = AtomicBool::new(false);
+static QUIET_WARNINGS: AtomicBool = AtomicBool::new(false);
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+static TAG_COLORS: RwLock<BTreeMap<String, Color>> = RwLock::new(BTreeMap::new());
+
+/// Color of tags without an entry in `[tags] colors`
+const DEFAULT_TAG_COLOR: Color = Color::Blue;
+
+/// How much commands report about what they did, from `--quiet` and `--verbose`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only results and errors; no success messages
+    Quiet,
+    Normal,
+    /// Also extra detail such as the files written
+    Verbose,
+}
+
+/// Choose how chatty commands are for the rest of the run
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> Verbosity {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        1 => Verbosity::Normal,
+        _ => Verbosity::Verbose,
+    }
+}
+
+/// Print a success or info message, unless `--quiet` was given
+pub fn success(message: &str) {
+    if verbosity() >= Verbosity::Normal {
+        println!("{}", message);
+    }
+}
+
+/// Print extra detail, only with `--verbose`
+pub fn detail(message: &str) {
+    if verbosity() >= Verbosity::Verbose {
+        println!("{}", message);
+    }
+}
+
+/// Choose how `format_status` renders statuses for the rest of the run
+pub fn set_status_style(style: StatusStyle) {
+    EMOJI_STATUS.store(style == StatusStyle::Emoji, Ordering::Relaxed);
+}
+
+/// Hide warnings about files that couldn't be read, for the rest of the run
+pub fn set_quiet_warnings(quiet: bool) {
+    QUIET_WARNINGS.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether `display.quiet_warnings` is on
+pub fn quiet_warnings() -> bool {
+    QUIET_WARNINGS.load(Ordering::Relaxed)
+}
+
+/// Color tags by `[tags] colors` for the rest of the run
+/// Names are matched like tags (trimmed, case-insensitive); unknown color names are skipped.
+pub fn set_tag_colors(colors: &BTreeMap<String, String>) {
+    let parsed = colors
+        .iter()
+        .filter_map(|(tag, color)| {
+            Some((
+                crate::models::normalize_tag(tag)?,
+                Color::from_str(color).ok()?,
+            ))
+        })
+        .collect();
+    if let Ok(mut tag_colors) = TAG_COLORS.write() {
+        *tag_colors = parsed;
+    }
+}
+
+/// Apply the `[display]` settings of the current workspace, if there is one
+pub fn configure_from_workspace() {
+    let config = crate::fs::find_workspace_root().and_then(|root| crate::fs::read_config(&root));
+    if let Ok(config) = config {
+        set_status_style(config.display.status_style);
+        set_quiet_warnings(config.display.quiet_warnings);
+        set_tag_colors(&config.tags.colors);
+    }
+}
+
+fn current_status_style() -> StatusStyle {
+    if EMOJI_STATUS.load(Ordering::Relaxed) {
+        StatusStyle::Emoji
+    } else {
+        StatusStyle::Text
+    }
+}
+
+/// Render a status the way the workspace's `display.status_style` asks for
+pub fn format_status(status: &Status) -> String {
+    format_status_with(status, current_status_style())
+}
+
+/// Render a status in the given style; colors still apply when the terminal supports them
+pub fn format_status_with(status: &Status, style: StatusStyle) -> String {
+    let label = match status {
+        Status::Backlog => "backlog".white(),
+        Status::Todo => "todo".cyan(),
+        Status::InProgress => "in-progress".yellow(),
+        Status::Completed => "completed".green(),
+        Status::Cancelled => "cancelled".red(),
+    };
+
+    match style {
+        StatusStyle::Text => label.to_string(),
+        StatusStyle::Emoji => format!("{} {}", status_emoji(status), label),
+    }
+}
+
+/// Render a priority, colored by urgency
+pub fn format_priority(priority: &Priority) -> String {
+    match priority {
+        Priority::Low => "low".white(),
+        Priority::Medium => "medium".cyan(),
+        Priority::High => "high".yellow(),
+        Priority::Urgent => "urgent".red(),
+    }
+    .to_string()
+}
+
+/// Render a tag in its configured color
+pub fn format_tag(tag: &str) -> String {
+    let color = TAG_COLORS
+        .read()
+        .ok()
+        .and_then(|colors| colors.get(tag).copied())
+        .unwrap_or(DEFAULT_TAG_COLOR);
+    tag.color(color).to_string()
+}
+
+/// Render a tag list as comma-separated, colored tags
+pub fn format_tags(tags: &[String]) -> String {
+    tags.iter()
+        .map(|t| format_tag(t))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// One edited field as `field: old → new`, old value in red and new in green
+pub fn format_change(change: &FieldChange) -> String {
+    let value = |v: &Option<String>, color: Color| match v.as_deref() {
+        Some(v) if !v.is_empty() => v.color(color).to_string(),
+        _ => "none".dimmed().to_string(),
+    };
+
+    format!(
+        "{}: {} → {}",
+        change.field,
+        value(&change.from, Color::Red),
+        value(&change.to, Color::Green)
+    )
+}
+
+/// Whether stdout is a terminal that can show OSC 8 hyperlinks
+/// Paged output goes through a pipe, so it always gets plain URLs.
+pub fn hyperlinks_supported() -> bool {
+    std::io::stdout().is_terminal() && std::env::var("TERM").map_or(true, |t| t != "dumb")
+}
+
+/// Render a URL, clickable when the terminal supports hyperlinks
+pub fn format_url(url: &str) -> String {
+    format_url_with(url, hyperlinks_supported())
+}
+
+/// Render a URL as an OSC 8 hyperlink labelled with the URL itself, or as plain text.
+/// Control characters are escaped and such URLs stay plain, so they can't inject escape codes.
+pub fn format_url_with(url: &str, hyperlinks: bool) -> String {
+    if url.chars().any(char::is_control) {
+        url.chars()
+            .map(|c| {
+                if c.is_control() {
+                    c.escape_default().to_string()
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect()
+    } else if hyperlinks {
+        format!("\x1b]8;;{url}\x1b\\{url}\x1b]8;;\x1b\\")
+    } else {
+        url.to_string()
+    }
+}
+
+/// Width of the terminal in columns: `$COLUMNS` if set, else the size of the terminal
+/// on stdout or stderr, else 80
+pub fn terminal_width() -> usize {
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|c| c.trim().parse().ok())
+    {
+        return columns;
+    }
+
+    #[cfg(unix)]
+    for fd in [libc::STDOUT_FILENO, libc::STDERR_FILENO] {
+        // SAFETY: TIOCGWINSZ only writes into the winsize struct we pass
+        let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+        if unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) } == 0 && size.ws_col > 0 {
+            return size.ws_col as usize;
+        }
+    }
+
+    80
+}
+
+/// Cut `text` to at most `width` characters, ending in `…` when anything was cut
+pub fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(width.saturating_sub(1)).collect();
+    format!("{}…", cut)
+}
+
+fn status_emoji(status: &Status) -> &'static str {
+    match status {
+        Status::Backlog => "📋",
+        Status::Todo => "🔵",
+        Status::InProgress => "🟡",
+        Status::Completed => "✅",
+        Status::Cancelled => "❌",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_status_styles() {
+        colored::control::set_override(false);
+
+        assert_eq!(format_status_with(&Status::Todo, StatusStyle::Text), "todo");
+        assert_eq!(
+            format_status_with(&Status::Backlog, StatusStyle::Emoji),
+            "📋 backlog"
+        );
+        assert_eq!(
+            format_status_with(&Status::InProgress, StatusStyle::Emoji),
+            "🟡 in-progress"
+        );
+        assert_eq!(
+            format_status_with(&Status::Completed, StatusStyle::Emoji),
+            "✅ completed"
+        );
+        assert_eq!(
+            format_status_with(&Status::Cancelled, StatusStyle::Emoji),
+            "❌ cancelled"
+        );
+    }
+
+    #[test]
+    fn test_format_change() {
+        colored::control::set_override(false);
+
+        let change = FieldChange::new(
+            "status",
+            Some("todo".to_string()),
+            Some("in-progress".to_string()),
+        );
+        assert_eq!(format_change(&change), "status: todo → in-progress");
+
+        let cleared = FieldChange::new("milestone", Some("v1".to_string()), None);
+        assert_eq!(format_change(&cleared), "milestone: v1 → none");
+
+        let tagged = FieldChange::new("tags", Some(String::new()), Some("bug".to_string()));
+        assert_eq!(format_change(&tagged), "tags: none → bug");
+    }
+
+    #[test]
+    fn test_format_tags() {
+        set_tag_colors(&BTreeMap::from([
+            ("Bug".to_string(), "red".to_string()),
+            ("feature".to_string(), "no such color".to_string()),
+        ]));
+        let tags = ["bug".to_string(), "feature".to_string()];
+
+        colored::control::set_override(false);
+        assert_eq!(format_tags(&tags), "bug, feature");
+
+        colored::control::set_override(true);
+        let bug = format_tag("bug");
+        let feature = format_tag("feature");
+        colored::control::set_override(false);
+        set_tag_colors(&BTreeMap::new());
+
+        assert_eq!(bug, "\x1b[31mbug\x1b[0m");
+        // Unmapped tags and unknown color names fall back to the default color
+        assert_eq!(feature, "\x1b[34mfeature\x1b[0m");
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("exactly10!", 10), "exactly10!");
+        assert_eq!(truncate("much too long", 8), "much to…");
+        assert_eq!(truncate("ünïcödé", 4), "ünï…");
+    }
+
+    #[test]
+    fn test_format_url() {
+        let url = "https://example.com/T-1";
+        assert_eq!(format_url_with(url, false), url);
+        assert_eq!(
+            format_url_with(url, true),
+            "\x1b]8;;https://example.com/T-1\x1b\\https://example.com/T-1\x1b]8;;\x1b\\"
+        );
+
+        let sneaky = "https://example.com/\x1b]0;title\x07";
+        for hyperlinks in [true, false] {
+            let rendered = format_url_with(sneaky, hyperlinks);
+            assert!(!rendered.contains(['\x1b', '\x07']));
+            assert_eq!(rendered, "https://example.com/\\u{1b}]0;title\\u{7}");
+        }
+    }
+}
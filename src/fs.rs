@@ -1,15 +1,57 @@
 use anyhow::{Context, Result};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::cell::{Cell, RefCell};
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::str::FromStr;
+use std::sync::Once;
+use std::time::{Duration, Instant};
 use walkdir::WalkDir;
 
-use crate::models::{Config, Issue, Milestone, Project};
-use crate::parser::{read_issue, read_milestone, read_project};
+use crate::models::{
+    ActivityEntry, Config, FieldChange, Issue, Milestone, Priority, Project, Status,
+    WORKSPACE_VERSION,
+};
+use crate::parser::{read_issue, read_milestone, read_project, split_frontmatter};
 
-/// Find the workspace root by looking for .pillar directory
+/// Environment variable that pins the workspace root instead of searching upward
+pub const WORKSPACE_ENV: &str = "PILLAR_WORKSPACE";
+
+/// Find the workspace root
+/// Uses `PILLAR_WORKSPACE` (also set by the global `--workspace` flag) when present,
+/// otherwise walks up from the current directory looking for a .pillar directory
 pub fn find_workspace_root() -> Result<PathBuf> {
-    let mut current = std::env::current_dir()?;
+    if let Some(root) = std::env::var_os(WORKSPACE_ENV).filter(|v| !v.is_empty()) {
+        return resolve_workspace_override(Path::new(&root));
+    }
+
+    discover_workspace_root(&std::env::current_dir()?)
+}
+
+/// Validate an explicitly configured workspace root
+fn resolve_workspace_override(root: &Path) -> Result<PathBuf> {
+    let root = if root.is_absolute() {
+        root.to_path_buf()
+    } else {
+        std::env::current_dir()?.join(root)
+    };
+
+    if !root.join(".pillar").is_dir() {
+        return Err(anyhow::anyhow!(
+            "{} is not a Pillar workspace (no .pillar directory). Check --workspace or {}.",
+            root.display(),
+            WORKSPACE_ENV
+        ));
+    }
+
+    Ok(root)
+}
+
+/// Walk up from `start` until a directory containing .pillar is found
+fn discover_workspace_root(start: &Path) -> Result<PathBuf> {
+    let mut current = start.to_path_buf();
 
     loop {
         let pillar_dir = current.join(".pillar");
@@ -18,15 +60,148 @@ pub fn find_workspace_root() -> Result<PathBuf> {
         }
 
         if !current.pop() {
-            return Err(anyhow::anyhow!(
-                "Not in a Pillar workspace. Run 'pillar init' to initialize one."
-            ));
+            return Err(not_in_workspace(start));
         }
     }
 }
 
-/// Read and parse the workspace configuration
+/// The "not in a workspace" error, pointing at workspaces one level below `start` if any
+fn not_in_workspace(start: &Path) -> anyhow::Error {
+    let mut nearby: Vec<String> = fs::read_dir(start)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join(".pillar").is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    nearby.sort();
+
+    match nearby.as_slice() {
+        [] => anyhow::anyhow!("Not in a Pillar workspace. Run 'pillar init' to initialize one."),
+        [only] => anyhow::anyhow!(
+            "Not in a Pillar workspace, but '{}' is one. Run 'cd {}' to use it, or 'pillar init' to initialize one here.",
+            only,
+            only
+        ),
+        many => anyhow::anyhow!(
+            "Not in a Pillar workspace, but these subdirectories are: {}. cd into one of them, or run 'pillar init' to initialize one here.",
+            many.join(", ")
+        ),
+    }
+}
+
+/// Name of the advisory lock file inside `.pillar/`
+const LOCK_FILE: &str = "lock";
+
+/// How long a writer waits for another process to release the workspace lock
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+thread_local! {
+    /// Number of live lock guards held by the current thread
+    static LOCK_DEPTH: Cell<usize> = const { Cell::new(0) };
+
+    /// Files written, moved, or removed by the current operation, for git auto-commit
+    static TOUCHED: RefCell<Vec<PathBuf>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Remember that the current operation changed `path`, so auto-commit picks it up.
+/// `write_atomic` does this itself; callers that rename or remove files must too.
+pub fn mark_touched<P: AsRef<Path>>(path: P) {
+    TOUCHED.with(|t| t.borrow_mut().push(path.as_ref().to_path_buf()));
+}
+
+fn take_touched() -> Vec<PathBuf> {
+    TOUCHED.with(|t| std::mem::take(&mut *t.borrow_mut()))
+}
+
+/// Guard for the workspace write lock, released on drop
+#[derive(Debug)]
+pub struct WorkspaceLock {
+    path: PathBuf,
+}
+
+impl Drop for WorkspaceLock {
+    fn drop(&mut self) {
+        let depth = LOCK_DEPTH.with(|d| {
+            let depth = d.get().saturating_sub(1);
+            d.set(depth);
+            depth
+        });
+        if depth == 0 {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Acquire the advisory write lock for a workspace
+/// The lock is a `.pillar/lock` file created with O_EXCL semantics. Only commands that
+/// modify files take it; reads never block. Nested acquisitions on the same thread are
+/// allowed so composed commands don't deadlock on themselves.
+pub fn acquire_lock(workspace_root: &Path, timeout: Duration) -> Result<WorkspaceLock> {
+    let path = workspace_root.join(".pillar").join(LOCK_FILE);
+
+    if LOCK_DEPTH.with(|d| d.get()) > 0 {
+        LOCK_DEPTH.with(|d| d.set(d.get() + 1));
+        return Ok(WorkspaceLock { path });
+    }
+
+    let start = Instant::now();
+    loop {
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                let _ = writeln!(file, "{}", std::process::id());
+                LOCK_DEPTH.with(|d| d.set(1));
+                // A new operation starts here; drop paths left over from a failed one
+                take_touched();
+                return Ok(WorkspaceLock { path });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                if start.elapsed() >= timeout {
+                    return Err(anyhow::anyhow!(
+                        "Workspace is locked by another pillar process. \
+                         If no other process is running, remove {}",
+                        path.display()
+                    ));
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to create lock file: {}", path.display()));
+            }
+        }
+    }
+}
+
+/// Acquire the write lock for the current workspace
+pub fn lock_workspace() -> Result<WorkspaceLock> {
+    let workspace_root = find_workspace_root()?;
+    acquire_lock(&workspace_root, LOCK_TIMEOUT)
+}
+
+/// Environment variables that override config values, for CI jobs that can't edit
+/// `.pillar/config.toml`. Precedence: environment, then the file, then built-in defaults.
+pub const CONFIG_ENV_OVERRIDES: [(&str, &str); 4] = [
+    ("PILLAR_BASE_DIRECTORY", "workspace.base_directory"),
+    ("PILLAR_DEFAULT_PRIORITY", "defaults.priority"),
+    ("PILLAR_DEFAULT_STATUS", "defaults.status"),
+    ("PILLAR_GIT_AUTO_COMMIT", "git.auto_commit"),
+];
+
+/// Read and parse the workspace configuration, with `CONFIG_ENV_OVERRIDES` applied
 pub fn read_config(workspace_root: &Path) -> Result<Config> {
+    let mut config = read_config_file(workspace_root)?;
+    apply_env_overrides(&mut config, |name| std::env::var(name).ok())?;
+    Ok(config)
+}
+
+/// The configuration exactly as stored on disk, without environment overrides
+/// Use this when the config will be written back, so overrides never leak into the file.
+pub fn read_config_file(workspace_root: &Path) -> Result<Config> {
     let config_path = workspace_root.join(".pillar/config.toml");
     let content = fs::read_to_string(&config_path).context("Failed to read config.toml")?;
 
@@ -35,12 +210,135 @@ pub fn read_config(workspace_root: &Path) -> Result<Config> {
     Ok(config)
 }
 
+/// Apply `CONFIG_ENV_OVERRIDES`, looking variables up with `lookup`; empty values are ignored
+fn apply_env_overrides(config: &mut Config, lookup: impl Fn(&str) -> Option<String>) -> Result<()> {
+    for (name, key) in CONFIG_ENV_OVERRIDES {
+        let Some(value) = lookup(name).filter(|v| !v.is_empty()) else {
+            continue;
+        };
+
+        match key {
+            "workspace.base_directory" => config.workspace.base_directory = value,
+            "defaults.priority" => {
+                Priority::from_str(&value).with_context(|| format!("Invalid {}", name))?;
+                config.defaults.priority = value;
+            }
+            "defaults.status" => {
+                Status::from_str(&value).with_context(|| format!("Invalid {}", name))?;
+                config.defaults.status = value;
+            }
+            "git.auto_commit" => {
+                config.git.auto_commit = value.parse().map_err(|_| {
+                    anyhow::anyhow!("Invalid {}: expected true or false, got '{}'", name, value)
+                })?;
+            }
+            _ => unreachable!("unhandled config override {}", key),
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a `[workspace] version` string
+pub fn parse_workspace_version(version: &str) -> Result<semver::Version> {
+    semver::Version::parse(version.trim()).with_context(|| {
+        format!(
+            "Invalid workspace version '{}' in .pillar/config.toml",
+            version
+        )
+    })
+}
+
+/// Compare the workspace format against the one this build supports
+/// Errors for workspaces from a newer pillar; returns a hint for older ones
+fn workspace_version_notice(version: &str) -> Result<Option<String>> {
+    let workspace = parse_workspace_version(version)?;
+    let supported = parse_workspace_version(WORKSPACE_VERSION)?;
+
+    if workspace > supported {
+        return Err(anyhow::anyhow!(
+            "This workspace uses format {}, but pillar {} only supports up to {}. Upgrade pillar to use it",
+            workspace,
+            env!("CARGO_PKG_VERSION"),
+            supported
+        ));
+    }
+
+    Ok((workspace < supported).then(|| {
+        format!(
+            "Workspace format {} is older than {}. Run 'pillar migrate' to upgrade it",
+            workspace, supported
+        )
+    }))
+}
+
+/// Refuse to touch a workspace written by a newer pillar, and suggest `pillar migrate`
+/// (once per run) for older ones
+pub fn check_workspace_version(config: &Config) -> Result<()> {
+    static WARNED: Once = Once::new();
+
+    if let Some(notice) = workspace_version_notice(&config.workspace.version)? {
+        WARNED.call_once(|| eprintln!("Warning: {}", notice));
+    }
+
+    Ok(())
+}
+
+/// Write the workspace configuration back to `.pillar/config.toml`
+pub fn write_config(workspace_root: &Path, config: &Config) -> Result<()> {
+    let content = toml::to_string_pretty(config).context("Failed to serialize config")?;
+    write_atomic(workspace_root.join(".pillar/config.toml"), &content)
+}
+
+/// Parse an explicit priority, or fall back to `[defaults] priority` from config
+pub fn resolve_priority(priority: Option<&str>) -> Result<Priority> {
+    if let Some(p) = priority {
+        return Priority::from_str(p).with_context(|| format!("Invalid priority: {}", p));
+    }
+
+    let config = read_config(&find_workspace_root()?)?;
+    Priority::from_str(&config.defaults.priority).with_context(|| {
+        format!(
+            "Invalid default priority '{}' in .pillar/config.toml",
+            config.defaults.priority
+        )
+    })
+}
+
+/// Parse an explicit status, or fall back to `[defaults] status` from config
+pub fn resolve_status(status: Option<&str>) -> Result<Status> {
+    if let Some(s) = status {
+        return Status::from_str(s).with_context(|| format!("Invalid status: {}", s));
+    }
+
+    let config = read_config(&find_workspace_root()?)?;
+    Status::from_str(&config.defaults.status).with_context(|| {
+        format!(
+            "Invalid default status '{}' in .pillar/config.toml",
+            config.defaults.status
+        )
+    })
+}
+
+/// Resolve a configured `base_directory`: relative paths are relative to the workspace root,
+/// absolute paths are used as-is
+pub fn resolve_base_directory(workspace_root: &Path, base_directory: &str) -> PathBuf {
+    let base = Path::new(base_directory);
+    if base.is_absolute() {
+        base.to_path_buf()
+    } else {
+        // Collecting the components drops the "." of the default `base_directory = "."`
+        workspace_root.join(base).components().collect()
+    }
+}
+
 /// Get the base directory path where projects are stored
 pub fn get_base_directory() -> Result<PathBuf> {
     let workspace_root = find_workspace_root()?;
     let config = read_config(&workspace_root)?;
+    check_workspace_version(&config)?;
 
-    let base_path = workspace_root.join(&config.workspace.base_directory);
+    let base_path = resolve_base_directory(&workspace_root, &config.workspace.base_directory);
 
     // Ensure base directory exists
     ensure_dir(&base_path)?;
@@ -48,6 +346,34 @@ pub fn get_base_directory() -> Result<PathBuf> {
     Ok(base_path)
 }
 
+/// Render a workspace template from `.pillar/templates/<name>.md`
+/// `{{KEY}}` placeholders are replaced with the given values and the template's own
+/// frontmatter is dropped, leaving the body. Returns `None` if the template is missing.
+pub fn render_template(name: &str, vars: &[(&str, &str)]) -> Result<Option<String>> {
+    let workspace_root = find_workspace_root()?;
+    let template_path = workspace_root
+        .join(".pillar/templates")
+        .join(format!("{}.md", name));
+
+    if !template_path.exists() {
+        return Ok(None);
+    }
+
+    let mut content = fs::read_to_string(&template_path)
+        .with_context(|| format!("Failed to read template: {}", template_path.display()))?;
+
+    for (key, value) in vars {
+        content = content.replace(&format!("{{{{{}}}}}", key), value);
+    }
+
+    let body = match split_frontmatter(&content) {
+        Ok((_, body)) => body.trim().to_string(),
+        Err(_) => content.trim().to_string(),
+    };
+
+    Ok(Some(body))
+}
+
 /// Get the current user's name for comments
 /// Tries git config, then $USER environment variable, then falls back to "Unknown"
 pub fn get_author() -> String {
@@ -74,6 +400,175 @@ pub fn get_author() -> String {
     "Unknown".to_string()
 }
 
+/// Append-only JSON lines log of mutations, inside .pillar
+const ACTIVITY_LOG: &str = "activity.log";
+
+/// Record a mutation in the workspace activity log
+/// Logging is best-effort: a failure is reported as a warning and never aborts the caller
+pub fn record_activity(action: &str, kind: &str, entity: &str, changes: Vec<FieldChange>) {
+    let entry = ActivityEntry {
+        timestamp: chrono::Utc::now(),
+        author: get_author(),
+        action: action.to_string(),
+        kind: kind.to_string(),
+        entity: if kind == "issue" {
+            normalize_issue_entity(entity)
+        } else {
+            entity.to_string()
+        },
+        changes,
+    };
+
+    let root = match find_workspace_root() {
+        Ok(root) => root,
+        Err(e) => {
+            eprintln!("Warning: could not write activity log: {}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = append_activity(&root, &entry) {
+        eprintln!("Warning: could not write activity log: {}", e);
+    }
+
+    let mut paths = take_touched();
+    paths.push(root.join(".pillar").join(ACTIVITY_LOG));
+    if let Ok(config) = read_config(&root) {
+        if config.git.auto_commit {
+            if let Err(e) = git_commit(&root, &paths, &commit_message(&entry)) {
+                eprintln!("Warning: git auto-commit failed: {}", e);
+            }
+        }
+    }
+}
+
+/// Build an auto-commit message such as "pillar: update proj/001 status->completed"
+fn commit_message(entry: &ActivityEntry) -> String {
+    let verb = match entry.action.as_str() {
+        "edit" => "update",
+        "comment" => "comment on",
+        other => other,
+    };
+    let mut message = format!("pillar: {} {}", verb, entry.entity);
+
+    let changes: Vec<String> = entry
+        .changes
+        .iter()
+        .map(|c| match &c.to {
+            Some(to) => format!("{}->{}", c.field, to),
+            None => c.field.clone(),
+        })
+        .collect();
+    if !changes.is_empty() {
+        message.push_str(&format!(" {}", changes.join(", ")));
+    }
+
+    message
+}
+
+/// Stage and commit exactly `paths` (the files an operation touched, plus the activity log)
+/// Anything else in the repository, staged or not, stays out of the commit.
+/// Does nothing when the workspace isn't inside a git repository
+fn git_commit(workspace_root: &Path, paths: &[PathBuf], message: &str) -> Result<()> {
+    let inside_repo = Command::new("git")
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .current_dir(workspace_root)
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false);
+    if !inside_repo {
+        return Ok(());
+    }
+
+    // A path that is neither on disk nor tracked (say, created and removed again)
+    // would make git reject the whole pathspec list
+    let tracked = |pathspec: &str| {
+        Command::new("git")
+            .args(["ls-files", "--error-unmatch", "--", pathspec])
+            .current_dir(workspace_root)
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    };
+    let mut pathspecs: Vec<String> = Vec::new();
+    for path in paths {
+        let relative = path.strip_prefix(workspace_root).unwrap_or(path);
+        let pathspec = format!(":(literal){}", relative.display());
+        if !pathspecs.contains(&pathspec) && (path.exists() || tracked(&pathspec)) {
+            pathspecs.push(pathspec);
+        }
+    }
+    if pathspecs.is_empty() {
+        return Ok(());
+    }
+
+    let status = Command::new("git")
+        .args(["add", "-A", "--"])
+        .args(&pathspecs)
+        .current_dir(workspace_root)
+        .output()?;
+    if !status.status.success() {
+        return Err(anyhow::anyhow!(
+            "git add failed: {}",
+            String::from_utf8_lossy(&status.stderr).trim()
+        ));
+    }
+
+    // Nothing staged under our paths means nothing to commit
+    let staged = Command::new("git")
+        .args(["diff", "--cached", "--quiet", "--"])
+        .args(&pathspecs)
+        .current_dir(workspace_root)
+        .status()?;
+    if staged.success() {
+        return Ok(());
+    }
+
+    let commit = Command::new("git")
+        .args(["commit", "-q", "-m", message, "--"])
+        .args(&pathspecs)
+        .current_dir(workspace_root)
+        .output()?;
+    if !commit.status.success() {
+        return Err(anyhow::anyhow!(
+            "git commit failed: {}",
+            String::from_utf8_lossy(&commit.stderr).trim()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Append a single entry to the activity log of the workspace at `workspace_root`
+pub fn append_activity(workspace_root: &Path, entry: &ActivityEntry) -> Result<()> {
+    let path = workspace_root.join(".pillar").join(ACTIVITY_LOG);
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    Ok(())
+}
+
+/// Read the activity log, oldest entry first
+/// A missing log is empty; lines that fail to parse are skipped
+pub fn read_activity(workspace_root: &Path) -> Result<Vec<ActivityEntry>> {
+    let path = workspace_root.join(".pillar").join(ACTIVITY_LOG);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
 /// Create directory structure if it doesn't exist
 pub fn ensure_dir<P: AsRef<Path>>(path: P) -> Result<()> {
     fs::create_dir_all(path.as_ref())
@@ -81,35 +576,171 @@ pub fn ensure_dir<P: AsRef<Path>>(path: P) -> Result<()> {
     Ok(())
 }
 
-/// List all projects in the workspace
-pub fn list_projects<P: AsRef<Path>>(workspace_root: P) -> Result<Vec<Project>> {
+/// Path of the sibling temp file used by `write_atomic`
+fn temp_path_for(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.tmp", file_name))
+}
+
+/// Write a file atomically
+/// Content goes to a sibling temp file first, which is then renamed over the target,
+/// so an interrupted write never leaves a truncated file behind
+pub fn write_atomic<P: AsRef<Path>>(path: P, content: &str) -> Result<()> {
+    let path = path.as_ref();
+    mark_touched(path);
+    let temp_path = temp_path_for(path);
+
+    let result = (|| -> Result<()> {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result.with_context(|| format!("Failed to write file: {}", path.display()))
+}
+
+/// A file that could not be parsed while listing entities
+#[derive(Debug)]
+pub struct LoadError {
+    pub kind: &'static str,
+    pub path: PathBuf,
+    pub error: anyhow::Error,
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Failed to read {} at {}: {}",
+            self.kind,
+            self.path.display(),
+            self.error
+        )
+    }
+}
+
+/// Entities read from disk, along with the files that failed to parse
+#[derive(Debug)]
+pub struct Loaded<T> {
+    pub items: Vec<T>,
+    pub errors: Vec<LoadError>,
+}
+
+impl<T> Loaded<T> {
+    /// Print a warning for every failed file, unless `display.quiet_warnings` is set,
+    /// and return the parsed entities
+    pub fn report(self) -> Vec<T> {
+        if !crate::display::quiet_warnings() {
+            for error in &self.errors {
+                eprintln!("Warning: {}", error);
+            }
+        }
+        self.items
+    }
+}
+
+/// File in the workspace root listing directories that aren't projects
+pub const IGNORE_FILE: &str = ".pillarignore";
+
+/// Directories under `base_dir` that `.pillarignore` excludes from scanning
+/// Each line is a glob (blank lines and `#` comments are skipped, and a trailing `/` is
+/// allowed). It is matched against the directory's name and against its path relative
+/// to the workspace root, so both `archive` and `pm/archive` work.
+struct IgnoreList {
+    root: PathBuf,
+    patterns: GlobSet,
+}
+
+impl IgnoreList {
+    fn load(base_dir: &Path) -> Result<IgnoreList> {
+        let root = discover_workspace_root(base_dir).unwrap_or_else(|_| base_dir.to_path_buf());
+        let path = root.join(IGNORE_FILE);
+
+        let mut builder = GlobSetBuilder::new();
+        if let Ok(content) = fs::read_to_string(&path) {
+            for line in content.lines().map(str::trim) {
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let glob = Glob::new(line.trim_end_matches('/'))
+                    .with_context(|| format!("Invalid pattern '{}' in {}", line, path.display()))?;
+                builder.add(glob);
+            }
+        }
+
+        let patterns = builder
+            .build()
+            .with_context(|| format!("Invalid patterns in {}", path.display()))?;
+        Ok(IgnoreList { root, patterns })
+    }
+
+    fn is_ignored(&self, dir: &Path) -> bool {
+        dir.file_name()
+            .is_some_and(|name| self.patterns.is_match(name))
+            || dir
+                .strip_prefix(&self.root)
+                .is_ok_and(|relative| self.patterns.is_match(relative))
+    }
+}
+
+/// Load all projects in the workspace, collecting parse failures
+/// Hidden directories and those listed in `.pillarignore` are skipped.
+pub fn load_projects<P: AsRef<Path>>(workspace_root: P) -> Result<Loaded<Project>> {
     let workspace_root = workspace_root.as_ref();
-    let mut projects = Vec::new();
+    let ignore = IgnoreList::load(workspace_root)?;
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
 
     for entry in fs::read_dir(workspace_root)? {
         let entry = entry?;
         let path = entry.path();
 
         // Skip .pillar directory and other hidden directories
-        if path.is_dir() && !path.file_name().unwrap().to_str().unwrap().starts_with('.') {
+        if path.is_dir()
+            && !path.file_name().unwrap().to_str().unwrap().starts_with('.')
+            && !ignore.is_ignored(&path)
+        {
             let readme = path.join("README.md");
             if readme.exists() {
                 match read_project(&path) {
-                    Ok(project) => projects.push(project),
-                    Err(e) => eprintln!(
-                        "Warning: Failed to read project at {}: {}",
-                        path.display(),
-                        e
-                    ),
+                    Ok(project) => items.push(project),
+                    // A README without frontmatter is an ordinary folder, not a broken project
+                    Err(_) if !has_frontmatter(&readme) => {}
+                    Err(error) => errors.push(LoadError {
+                        kind: "project",
+                        path,
+                        error,
+                    }),
                 }
             }
         }
     }
 
-    Ok(projects)
+    Ok(Loaded { items, errors })
+}
+
+/// Whether a file starts with a `---` frontmatter block, i.e. was meant for Pillar
+fn has_frontmatter(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|content| split_frontmatter(&content).is_ok())
+}
+
+/// List all projects in the workspace
+pub fn list_projects<P: AsRef<Path>>(workspace_root: P) -> Result<Vec<Project>> {
+    Ok(load_projects(workspace_root)?.report())
 }
 
 /// Find a project by name or project ID
+/// Falls back to a case-insensitive match, then to a unique prefix of the directory name or ID, ignoring `-`, `_` and spaces,
+/// so `myproj` finds `my-project`
 pub fn find_project<P: AsRef<Path>>(workspace_root: P, identifier: &str) -> Result<Project> {
     let workspace_root = workspace_root.as_ref();
 
@@ -120,77 +751,237 @@ pub fn find_project<P: AsRef<Path>>(workspace_root: P, identifier: &str) -> Resu
     }
 
     // If not found by path, search all projects for matching project_id
-    let all_projects = list_projects(workspace_root)?;
-    for project in all_projects {
-        if let Some(pid) = &project.metadata.project_id {
-            if pid == identifier {
-                return Ok(project);
-            }
+    let (mut by_id, others): (Vec<Project>, Vec<Project>) = list_projects(workspace_root)?
+        .into_iter()
+        .partition(|p| p.metadata.project_id.as_deref() == Some(identifier));
+    if !by_id.is_empty() {
+        return Ok(by_id.remove(0));
+    }
+
+    // Directory names are case-sensitive on most filesystems; match regardless of case
+    let lowered = identifier.to_lowercase();
+    let (mut by_case, others): (Vec<Project>, Vec<Project>) = others.into_iter().partition(|p| {
+        project_dir_name(p).to_lowercase() == lowered
+            || p.metadata.project_id.as_deref().map(str::to_lowercase) == Some(lowered.clone())
+    });
+    if by_case.len() == 1 {
+        return Ok(by_case.remove(0));
+    }
+    let others = by_case.into_iter().chain(others);
+
+    // Finally, accept a prefix that only one project matches
+    let wanted = loose_name(identifier);
+    let mut candidates: Vec<Project> = others
+        .into_iter()
+        .filter(|p| {
+            let dir_name = p.path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            std::iter::once(dir_name)
+                .chain(p.metadata.project_id.as_deref())
+                .any(|name| loose_name(name).starts_with(&wanted))
+        })
+        .collect();
+
+    match candidates.len() {
+        1 if !wanted.is_empty() => Ok(candidates.remove(0)),
+        n if n > 1 && !wanted.is_empty() => {
+            let mut names: Vec<String> = candidates
+                .iter()
+                .map(|p| match &p.metadata.project_id {
+                    Some(id) => format!("{} ({})", project_dir_name(p), id),
+                    None => project_dir_name(p),
+                })
+                .collect();
+            names.sort();
+            Err(anyhow::anyhow!(
+                "Project '{}' is ambiguous; it matches: {}. Use a longer name or the project ID",
+                identifier,
+                names.join(", ")
+            ))
         }
+        _ => Err(anyhow::anyhow!(
+            "Project '{}' does not exist (searched by name and ID)",
+            identifier
+        )),
     }
+}
 
-    Err(anyhow::anyhow!(
-        "Project '{}' does not exist (searched by name and ID)",
-        identifier
-    ))
+/// The project directory (under `base_dir`) that contains `dir`, if any.
+/// Returns `None` for the base directory itself or anywhere outside a project.
+pub fn project_containing(base_dir: &Path, dir: &Path) -> Option<String> {
+    let base_dir = base_dir.canonicalize().ok()?;
+    let dir = dir.canonicalize().ok()?;
+    let first = dir.strip_prefix(&base_dir).ok()?.components().next()?;
+    let name = first.as_os_str().to_str()?;
+
+    base_dir
+        .join(name)
+        .join("README.md")
+        .is_file()
+        .then(|| name.to_string())
 }
 
-/// List all milestones in a project
-pub fn list_milestones<P: AsRef<Path>>(project_path: P) -> Result<Vec<Milestone>> {
-    let milestones_dir = project_path.as_ref().join("milestones");
+/// The project the current working directory is inside, if any
+pub fn infer_project(base_dir: &Path) -> Option<String> {
+    project_containing(base_dir, &std::env::current_dir().ok()?)
+}
 
-    if !milestones_dir.exists() {
-        return Ok(Vec::new());
-    }
+/// A lowercased project name with separators removed, for forgiving prefix matches
+fn loose_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
 
-    let mut milestones = Vec::new();
+fn project_dir_name(project: &Project) -> String {
+    project
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| project.metadata.name.clone())
+}
 
-    for entry in WalkDir::new(&milestones_dir)
+/// Parse every markdown file directly inside `dir`, collecting parse failures
+fn load_markdown_files<T>(
+    dir: &Path,
+    kind: &'static str,
+    read: fn(&Path) -> Result<T>,
+) -> Result<Loaded<T>> {
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+
+    if !dir.exists() {
+        return Ok(Loaded { items, errors });
+    }
+
+    for entry in WalkDir::new(dir)
         .max_depth(1)
         .into_iter()
         .filter_map(|e| e.ok())
     {
         let path = entry.path();
         if path.is_file() && path.extension().is_some_and(|ext| ext == "md") {
-            match read_milestone(path) {
-                Ok(milestone) => milestones.push(milestone),
-                Err(e) => eprintln!(
-                    "Warning: Failed to read milestone at {}: {}",
-                    path.display(),
-                    e
-                ),
+            match read(path) {
+                Ok(item) => items.push(item),
+                Err(error) => errors.push(LoadError {
+                    kind,
+                    path: path.to_path_buf(),
+                    error,
+                }),
             }
         }
     }
 
-    Ok(milestones)
+    Ok(Loaded { items, errors })
+}
+
+/// Load all milestones in a project, collecting parse failures
+pub fn load_milestones<P: AsRef<Path>>(project_path: P) -> Result<Loaded<Milestone>> {
+    load_markdown_files(
+        &project_path.as_ref().join("milestones"),
+        "milestone",
+        |p| read_milestone(p),
+    )
+}
+
+/// List all milestones in a project
+pub fn list_milestones<P: AsRef<Path>>(project_path: P) -> Result<Vec<Milestone>> {
+    Ok(load_milestones(project_path)?.report())
+}
+
+/// Load all issues in a project, collecting parse failures
+pub fn load_issues<P: AsRef<Path>>(project_path: P) -> Result<Loaded<Issue>> {
+    load_markdown_files(&project_path.as_ref().join("issues"), "issue", |p| {
+        read_issue(p)
+    })
+}
+
+/// Name of the pseudo-project holding issues not yet filed under a real project
+pub const INBOX: &str = "inbox";
+
+/// Directory of the inbox: `.pillar/inbox`, laid out like a project (`issues/NNN-slug.md`)
+pub fn inbox_dir(workspace_root: &Path) -> PathBuf {
+    workspace_root.join(".pillar").join(INBOX)
+}
+
+/// Directory holding a project's files, resolved like `find_project` (directory name,
+/// ID, or unique prefix); `inbox` is the inbox. Unknown projects are an error.
+pub fn project_path(base_dir: &Path, project_name: &str) -> Result<PathBuf> {
+    if project_name == INBOX {
+        Ok(inbox_dir(&find_workspace_root()?))
+    } else {
+        Ok(find_project(base_dir, project_name)?.path)
+    }
 }
 
 /// List all issues in a project
 pub fn list_issues<P: AsRef<Path>>(project_path: P) -> Result<Vec<Issue>> {
-    let issues_dir = project_path.as_ref().join("issues");
+    Ok(load_issues(project_path)?.report())
+}
 
-    if !issues_dir.exists() {
-        return Ok(Vec::new());
-    }
+/// Extract the issue ID from an issue file path: everything before the first `-`
+/// Pillar names issues `NNN-slug.md`, but a hand-made `bugfix-login.md` still gets an
+/// ID (`bugfix`); it just never takes part in numbering.
+pub fn extract_issue_id(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .and_then(|s| s.split('-').next())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+/// Name of the project directory an issue file lives in (`<project>/issues/NNN-*.md`)
+pub fn issue_project_dir(issue: &Issue) -> String {
+    issue
+        .path
+        .parent()
+        .and_then(|issues_dir| issues_dir.parent())
+        .and_then(|dir| dir.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "?".to_string())
+}
 
-    let mut issues = Vec::new();
+/// Find an issue in a project by its ID
+/// If the matching file exists but fails to parse, its parse error is returned
+/// instead of a generic "not found". Two files with the same ID are an error rather
+/// than a silent pick of one of them.
+pub fn find_issue<P: AsRef<Path>>(project_path: P, issue_id: &str) -> Result<Issue> {
+    let loaded = load_issues(project_path)?;
 
-    for entry in WalkDir::new(&issues_dir)
-        .max_depth(1)
+    let mut matches: Vec<Issue> = loaded
+        .items
         .into_iter()
-        .filter_map(|e| e.ok())
+        .filter(|i| extract_issue_id(&i.path) == issue_id)
+        .collect();
+    if matches.len() > 1 {
+        let mut files: Vec<String> = matches
+            .iter()
+            .filter_map(|i| i.path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+        return Err(anyhow::anyhow!(
+            "Issue ID '{}' is used by more than one file: {}. Rename all but one so each starts with a unique number",
+            issue_id,
+            files.join(", ")
+        ));
+    }
+    if let Some(issue) = matches.pop() {
+        return Ok(issue);
+    }
+
+    if let Some(failure) = loaded
+        .errors
+        .into_iter()
+        .find(|e| extract_issue_id(&e.path) == issue_id)
     {
-        let path = entry.path();
-        if path.is_file() && path.extension().is_some_and(|ext| ext == "md") {
-            match read_issue(path) {
-                Ok(issue) => issues.push(issue),
-                Err(e) => eprintln!("Warning: Failed to read issue at {}: {}", path.display(), e),
-            }
-        }
+        return Err(failure.error.context(format!(
+            "Failed to read issue at {}",
+            failure.path.display()
+        )));
     }
 
-    Ok(issues)
+    Err(anyhow::anyhow!("Issue '{}' not found", issue_id))
 }
 
 /// List all issues across all projects in the workspace
@@ -206,12 +997,27 @@ pub fn list_all_issues<P: AsRef<Path>>(workspace_root: P) -> Result<Vec<Issue>>
     Ok(all_issues)
 }
 
-/// Generate a unique issue ID based on existing issues
+/// Digits in a generated issue ID; shorter numbers are zero-padded (`001`)
+pub const ISSUE_ID_WIDTH: usize = 3;
+
+/// `project/<number>` with the number zero-padded to `ISSUE_ID_WIDTH`, so an issue filed
+/// as `1-*.md` is logged and looked up as `project/001`. Other IDs are returned as-is.
+pub fn normalize_issue_entity(entity: &str) -> String {
+    match entity.rsplit_once('/') {
+        Some((project, id)) if !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()) => {
+            format!("{}/{:0>width$}", project, id, width = ISSUE_ID_WIDTH)
+        }
+        _ => entity.to_string(),
+    }
+}
+
+/// Generate a unique issue ID: one more than the highest numeric prefix in `issues/`
+/// Files without a numeric prefix are skipped; their IDs can never clash with a number.
 pub fn generate_issue_id<P: AsRef<Path>>(project_path: P) -> Result<String> {
     let issues_dir = project_path.as_ref().join("issues");
 
     if !issues_dir.exists() {
-        return Ok("001".to_string());
+        return Ok(format!("{:0width$}", 1, width = ISSUE_ID_WIDTH));
     }
 
     let mut max_id = 0;
@@ -229,7 +1035,7 @@ pub fn generate_issue_id<P: AsRef<Path>>(project_path: P) -> Result<String> {
         }
     }
 
-    Ok(format!("{:03}", max_id + 1))
+    Ok(format!("{:0width$}", max_id + 1, width = ISSUE_ID_WIDTH))
 }
 
 #[cfg(test)]
@@ -273,6 +1079,253 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_workspace_override_resolution() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let nested = temp_dir.path().join("a/b");
+        fs::create_dir_all(&nested)?;
+
+        assert!(resolve_workspace_override(temp_dir.path()).is_err());
+        assert!(discover_workspace_root(&nested).is_err());
+
+        fs::create_dir(temp_dir.path().join(".pillar"))?;
+        assert_eq!(
+            resolve_workspace_override(temp_dir.path())?,
+            temp_dir.path().to_path_buf()
+        );
+        assert_eq!(
+            discover_workspace_root(&nested)?,
+            temp_dir.path().to_path_buf()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_workspace_suggests_child() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let plain = not_in_workspace(temp_dir.path()).to_string();
+        assert!(plain.starts_with("Not in a Pillar workspace. Run 'pillar init'"));
+
+        fs::create_dir_all(temp_dir.path().join("tracker/.pillar"))?;
+        fs::create_dir_all(temp_dir.path().join("src"))?;
+        let suggestion = not_in_workspace(temp_dir.path()).to_string();
+        assert!(suggestion.contains("Run 'cd tracker'"));
+
+        fs::create_dir_all(temp_dir.path().join("archive/.pillar"))?;
+        let several = not_in_workspace(temp_dir.path()).to_string();
+        assert!(several.contains("archive, tracker"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_stale_temp_file() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let target = temp_dir.path().join("001-issue.md");
+        fs::write(&target, "original content")?;
+
+        // Simulate a previous write that was interrupted half-way
+        let temp_path = temp_path_for(&target);
+        assert_eq!(temp_path, temp_dir.path().join(".001-issue.md.tmp"));
+        fs::write(&temp_path, "partial")?;
+
+        write_atomic(&target, "fully written content")?;
+
+        assert_eq!(fs::read_to_string(&target)?, "fully written content");
+        assert!(!temp_path.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_activity_log_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        fs::create_dir(temp_dir.path().join(".pillar"))?;
+        assert!(read_activity(temp_dir.path())?.is_empty());
+
+        let entry = ActivityEntry {
+            timestamp: chrono::Utc::now(),
+            author: "alice".to_string(),
+            action: "edit".to_string(),
+            kind: "issue".to_string(),
+            entity: "proj/001".to_string(),
+            changes: vec![FieldChange::new(
+                "status",
+                Some("todo".to_string()),
+                Some("completed".to_string()),
+            )],
+        };
+        append_activity(temp_dir.path(), &entry)?;
+
+        // A corrupt line must not hide the entries around it
+        let log_path = temp_dir.path().join(".pillar").join(ACTIVITY_LOG);
+        let mut file = fs::OpenOptions::new().append(true).open(&log_path)?;
+        writeln!(file, "{{not json")?;
+        append_activity(temp_dir.path(), &entry)?;
+
+        let entries = read_activity(temp_dir.path())?;
+        assert_eq!(entries, vec![entry.clone(), entry]);
+        assert_eq!(
+            entries[0].changes[0].to_string(),
+            "status todo -> completed"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_git_auto_commit() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        let paths = [
+            root.join("pm/proj/README.md"),
+            root.join(".pillar").join(ACTIVITY_LOG),
+            root.join("pm/proj/issues/001-gone.md"),
+        ];
+
+        fs::create_dir_all(root.join(".pillar"))?;
+        fs::create_dir_all(root.join("pm/proj"))?;
+        fs::write(root.join(".pillar").join(ACTIVITY_LOG), "{}\n")?;
+        fs::write(root.join(".pillar").join(LOCK_FILE), "")?;
+        fs::write(root.join("pm/proj/README.md"), "# proj\n")?;
+        fs::write(root.join("notes.txt"), "not tracker data\n")?;
+
+        // Outside a repository this is a no-op
+        git_commit(root, &paths, "pillar: create proj")?;
+
+        let git = |args: &[&str]| -> Result<String> {
+            let output = Command::new("git").args(args).current_dir(root).output()?;
+            Ok(String::from_utf8(output.stdout)?)
+        };
+        git(&["init", "-q"])?;
+        git(&["config", "user.name", "Test"])?;
+        git(&["config", "user.email", "test@example.com"])?;
+
+        let entry = ActivityEntry {
+            timestamp: chrono::Utc::now(),
+            author: "Test".to_string(),
+            action: "edit".to_string(),
+            kind: "issue".to_string(),
+            entity: "proj/001".to_string(),
+            changes: vec![FieldChange::new(
+                "status",
+                Some("todo".to_string()),
+                Some("completed".to_string()),
+            )],
+        };
+        let message = commit_message(&entry);
+        assert_eq!(message, "pillar: update proj/001 status->completed");
+
+        git_commit(root, &paths, &message)?;
+        assert_eq!(git(&["log", "--format=%s"])?.trim(), message);
+
+        let tracked = git(&["ls-files"])?;
+        assert!(tracked.contains("pm/proj/README.md"));
+        assert!(tracked.contains(".pillar/activity.log"));
+        assert!(!tracked.contains(".pillar/lock"));
+        assert!(!tracked.contains("notes.txt"));
+
+        // With nothing new to commit, no empty commit is made
+        git_commit(root, &paths, "pillar: noop")?;
+        assert_eq!(git(&["rev-list", "--count", "HEAD"])?.trim(), "1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_git_auto_commit_leaves_unrelated_changes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = std::env::current_dir()?;
+        let root = temp_dir.path();
+
+        let git = |args: &[&str]| -> Result<String> {
+            let output = Command::new("git").args(args).current_dir(root).output()?;
+            Ok(String::from_utf8(output.stdout)?)
+        };
+        git(&["init", "-q"])?;
+        git(&["config", "user.name", "Test"])?;
+        git(&["config", "user.email", "test@example.com"])?;
+        fs::write(root.join("main.rs"), "fn main() {}\n")?;
+        git(&["add", "main.rs"])?;
+        git(&["commit", "-q", "-m", "initial"])?;
+
+        std::env::set_current_dir(root)?;
+        let result = (|| -> Result<()> {
+            crate::commands::init(None, false)?;
+            let mut config = read_config_file(root)?;
+            assert_eq!(config.workspace.base_directory, ".");
+            config.git.auto_commit = true;
+            write_config(root, &config)?;
+
+            // Unrelated work in progress, both unstaged and staged
+            fs::write(root.join("main.rs"), "fn main() { todo!() }\n")?;
+            fs::write(root.join("scratch.txt"), "draft\n")?;
+            git(&["add", "scratch.txt"])?;
+
+            crate::commands::create_project("proj", None, None)
+        })();
+        std::env::set_current_dir(&original_dir)?;
+        result?;
+
+        let committed = git(&["show", "--name-only", "--format=", "HEAD"])?;
+        assert!(committed.contains("proj/README.md"));
+        assert!(committed.contains(".pillar/activity.log"));
+        assert!(!committed.contains("main.rs"));
+        assert!(!committed.contains("scratch.txt"));
+
+        let status = git(&["status", "--porcelain"])?;
+        assert!(status.contains(" M main.rs"));
+        assert!(status.contains("A  scratch.txt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lock_is_exclusive_while_held() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        fs::create_dir(temp_dir.path().join(".pillar"))?;
+        let root = temp_dir.path().to_path_buf();
+
+        let lock = acquire_lock(&root, Duration::ZERO)?;
+        assert!(root.join(".pillar/lock").exists());
+
+        // A second writer (on another thread) must not get the lock
+        let contender = root.clone();
+        let second = std::thread::spawn(move || acquire_lock(&contender, Duration::ZERO).is_ok())
+            .join()
+            .unwrap();
+        assert!(!second);
+
+        drop(lock);
+        assert!(!root.join(".pillar/lock").exists());
+
+        // Released locks can be acquired again
+        let _relock = acquire_lock(&root, Duration::ZERO)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lock_clears_touched_paths_from_earlier_operation() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        fs::create_dir(temp_dir.path().join(".pillar"))?;
+        let root = temp_dir.path().to_path_buf();
+
+        // An operation that failed before recording activity
+        let failed = acquire_lock(&root, Duration::ZERO)?;
+        mark_touched(root.join("stale.md"));
+        drop(failed);
+
+        let _lock = acquire_lock(&root, Duration::ZERO)?;
+        mark_touched(root.join("fresh.md"));
+        let nested = acquire_lock(&root, Duration::ZERO)?;
+        drop(nested);
+        assert_eq!(take_touched(), vec![root.join("fresh.md")]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_list_projects() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -305,13 +1358,90 @@ mod tests {
         let result = find_project(temp_dir.path(), "nonexistent");
         assert!(result.is_err());
 
-        #[test]
-        fn test_read_config() -> Result<()> {
-            let temp_dir = TempDir::new()?;
-            let pillar_dir = temp_dir.path().join(".pillar");
-            fs::create_dir(&pillar_dir)?;
+        Ok(())
+    }
 
-            let config_content = r#"
+    #[test]
+    fn test_find_project_unique_prefix() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        create_test_project(temp_dir.path(), "my-project")?;
+        create_test_project(temp_dir.path(), "other")?;
+
+        assert_eq!(
+            find_project(temp_dir.path(), "myproj")?.metadata.name,
+            "my-project"
+        );
+        assert_eq!(
+            find_project(temp_dir.path(), "my-p")?.metadata.name,
+            "my-project"
+        );
+        assert_eq!(find_project(temp_dir.path(), "oth")?.metadata.name, "other");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_project_case_insensitive() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        create_test_project(temp_dir.path(), "TestProject")?;
+        create_test_project(temp_dir.path(), "testproject-2")?;
+
+        let project = find_project(temp_dir.path(), "testproject")?;
+        assert_eq!(project.metadata.name, "TestProject");
+        assert_eq!(
+            find_project(temp_dir.path(), "TESTPROJECT-2")?
+                .metadata
+                .name,
+            "testproject-2"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_project_ambiguous_prefix() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        create_test_project(temp_dir.path(), "my-project")?;
+        create_test_project(temp_dir.path(), "my-prototype")?;
+
+        let err = find_project(temp_dir.path(), "my-pro")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("ambiguous"));
+        assert!(err.contains("my-project"));
+        assert!(err.contains("my-prototype"));
+
+        assert_eq!(
+            find_project(temp_dir.path(), "my-proj")?.metadata.name,
+            "my-project"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_workspace_version_notice() -> Result<()> {
+        assert_eq!(workspace_version_notice(WORKSPACE_VERSION)?, None);
+
+        let older = workspace_version_notice("0.0.1")?.unwrap();
+        assert!(older.contains("pillar migrate"));
+
+        let newer = workspace_version_notice("99.0.0").unwrap_err().to_string();
+        assert!(newer.contains("Upgrade pillar"));
+        assert!(newer.contains(env!("CARGO_PKG_VERSION")));
+
+        assert!(workspace_version_notice("banana").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_config() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let pillar_dir = temp_dir.path().join(".pillar");
+        fs::create_dir(&pillar_dir)?;
+
+        let config_content = r#"
 [workspace]
 version = "0.1.0"
 base_directory = "pm"
@@ -320,24 +1450,24 @@ base_directory = "pm"
 priority = "medium"
 status = "backlog"
 "#;
-            fs::write(pillar_dir.join("config.toml"), config_content)?;
+        fs::write(pillar_dir.join("config.toml"), config_content)?;
 
-            let config = read_config(temp_dir.path())?;
-            assert_eq!(config.workspace.version, "0.1.0");
-            assert_eq!(config.workspace.base_directory, "pm");
-            assert_eq!(config.defaults.priority, "medium");
+        let config = read_config(temp_dir.path())?;
+        assert_eq!(config.workspace.version, "0.1.0");
+        assert_eq!(config.workspace.base_directory, "pm");
+        assert_eq!(config.defaults.priority, "medium");
 
-            Ok(())
-        }
+        Ok(())
+    }
 
-        #[test]
-        fn test_read_config_default_base_directory() -> Result<()> {
-            let temp_dir = TempDir::new()?;
-            let pillar_dir = temp_dir.path().join(".pillar");
-            fs::create_dir(&pillar_dir)?;
+    #[test]
+    fn test_read_config_default_base_directory() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let pillar_dir = temp_dir.path().join(".pillar");
+        fs::create_dir(&pillar_dir)?;
 
-            // Config without base_directory
-            let config_content = r#"
+        // Config without base_directory
+        let config_content = r#"
 [workspace]
 version = "0.1.0"
 
@@ -345,26 +1475,71 @@ version = "0.1.0"
 priority = "medium"
 status = "backlog"
 "#;
-            fs::write(pillar_dir.join("config.toml"), config_content)?;
+        fs::write(pillar_dir.join("config.toml"), config_content)?;
 
-            let config = read_config(temp_dir.path())?;
-            assert_eq!(config.workspace.base_directory, ".");
+        let config = read_config(temp_dir.path())?;
+        assert_eq!(config.workspace.base_directory, ".");
 
-            Ok(())
-        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_env_overrides() -> Result<()> {
+        let config = || -> Config {
+            toml::from_str(
+                "[workspace]\nversion = \"0.1.0\"\n\n[defaults]\npriority = \"medium\"\nstatus = \"backlog\"\n",
+            )
+            .unwrap()
+        };
+        let lookup = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
 
-        #[test]
-        fn test_get_base_directory() -> Result<()> {
-            let temp_dir = TempDir::new()?;
-            let original_dir = env::current_dir()?;
+        let mut overridden = config();
+        apply_env_overrides(
+            &mut overridden,
+            lookup(&[
+                ("PILLAR_BASE_DIRECTORY", "ci-projects"),
+                ("PILLAR_DEFAULT_PRIORITY", "urgent"),
+                ("PILLAR_DEFAULT_STATUS", ""),
+                ("PILLAR_GIT_AUTO_COMMIT", "true"),
+            ]),
+        )?;
+        assert_eq!(overridden.workspace.base_directory, "ci-projects");
+        assert_eq!(overridden.defaults.priority, "urgent");
+        // Empty values leave the file's setting alone
+        assert_eq!(overridden.defaults.status, config().defaults.status);
+        assert!(overridden.git.auto_commit);
+
+        assert!(apply_env_overrides(
+            &mut config(),
+            lookup(&[("PILLAR_DEFAULT_PRIORITY", "asap")])
+        )
+        .is_err());
+        assert!(
+            apply_env_overrides(&mut config(), lookup(&[("PILLAR_GIT_AUTO_COMMIT", "yes")]))
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_base_directory() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
 
-            env::set_current_dir(temp_dir.path())?;
+        env::set_current_dir(temp_dir.path())?;
 
-            // Create workspace with custom base directory
-            let pillar_dir = temp_dir.path().join(".pillar");
-            fs::create_dir(&pillar_dir)?;
+        // Create workspace with custom base directory
+        let pillar_dir = temp_dir.path().join(".pillar");
+        fs::create_dir(&pillar_dir)?;
 
-            let config_content = r#"
+        let config_content = r#"
 [workspace]
 version = "0.1.0"
 base_directory = "project-management"
@@ -373,17 +1548,116 @@ base_directory = "project-management"
 priority = "medium"
 status = "backlog"
 "#;
-            fs::write(pillar_dir.join("config.toml"), config_content)?;
+        fs::write(pillar_dir.join("config.toml"), config_content)?;
 
-            let base_dir = get_base_directory()?;
+        let base_dir = get_base_directory()?;
 
-            env::set_current_dir(original_dir)?;
+        env::set_current_dir(original_dir)?;
 
-            assert_eq!(base_dir, temp_dir.path().join("project-management"));
-            assert!(base_dir.exists()); // Should be created
+        assert_eq!(base_dir, temp_dir.path().join("project-management"));
+        assert!(base_dir.exists()); // Should be created
 
-            Ok(())
-        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_containing() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base = temp_dir.path();
+        std::fs::create_dir_all(base.join("web/issues"))?;
+        std::fs::write(base.join("web/README.md"), "---\nname: web\n---\n")?;
+        std::fs::create_dir_all(base.join("notes"))?;
+
+        assert_eq!(
+            project_containing(base, &base.join("web/issues")),
+            Some("web".to_string())
+        );
+        assert_eq!(
+            project_containing(base, &base.join("web")),
+            Some("web".to_string())
+        );
+        assert_eq!(project_containing(base, base), None);
+        assert_eq!(project_containing(base, &base.join("notes")), None);
+        assert_eq!(project_containing(&base.join("web"), base), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_base_directory() {
+        let root = Path::new("/work/tracker");
+        assert_eq!(
+            resolve_base_directory(root, "pm"),
+            PathBuf::from("/work/tracker/pm")
+        );
+        assert_eq!(
+            resolve_base_directory(root, "."),
+            PathBuf::from("/work/tracker/.")
+        );
+
+        let absolute = std::env::temp_dir().join("pmdata");
+        assert_eq!(
+            resolve_base_directory(root, absolute.to_str().unwrap()),
+            absolute
+        );
+    }
+
+    #[test]
+    fn test_get_base_directory_absolute() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let data_dir = TempDir::new()?;
+        let original_dir = env::current_dir()?;
+        let base = data_dir.path().join("pmdata");
+
+        let pillar_dir = temp_dir.path().join(".pillar");
+        fs::create_dir(&pillar_dir)?;
+        fs::write(
+            pillar_dir.join("config.toml"),
+            format!(
+                "[workspace]\nversion = \"0.1.0\"\nbase_directory = {:?}\n\n[defaults]\npriority = \"medium\"\nstatus = \"backlog\"\n",
+                base.to_str().unwrap()
+            ),
+        )?;
+
+        env::set_current_dir(temp_dir.path())?;
+        let base_dir = get_base_directory();
+        env::set_current_dir(original_dir)?;
+
+        assert_eq!(base_dir?, base);
+        assert!(base.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_skips_malformed_file() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("project");
+        let issues_dir = project_dir.join("issues");
+        fs::create_dir_all(&issues_dir)?;
+
+        fs::write(
+            issues_dir.join("001-good.md"),
+            "---\ntitle: Good issue\nstatus: todo\npriority: high\n---\n\nBody\n",
+        )?;
+        fs::write(
+            issues_dir.join("002-broken.md"),
+            "---\ntitle: [unclosed\nstatus: todo\n---\n\nBody\n",
+        )?;
+
+        let issues = list_issues(&project_dir)?;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].metadata.title, "Good issue");
+
+        let loaded = load_issues(&project_dir)?;
+        assert_eq!(loaded.items.len(), 1);
+        assert_eq!(loaded.errors.len(), 1);
+        assert!(loaded.errors[0].path.ends_with("002-broken.md"));
+
+        // Looking up the broken issue surfaces its parse error
+        assert!(find_issue(&project_dir, "001").is_ok());
+        let err = find_issue(&project_dir, "002").unwrap_err();
+        assert!(format!("{:#}", err).contains("002-broken.md"));
 
         Ok(())
     }
@@ -409,4 +1683,104 @@ status = "backlog"
 
         Ok(())
     }
+
+    #[test]
+    fn test_issue_ids_without_numeric_prefix() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("project");
+        let issues_dir = project_dir.join("issues");
+        fs::create_dir_all(&issues_dir)?;
+
+        let write_issue = |file: &str, title: &str| {
+            let metadata = crate::models::IssueMetadata {
+                title: title.to_string(),
+                status: Status::Todo,
+                priority: Priority::Low,
+                severity: None,
+                project: None,
+                milestone: None,
+                tags: vec![],
+                assignee: None,
+                reporter: None,
+                url: None,
+                created: None,
+                updated: None,
+            };
+            write_with_frontmatter(issues_dir.join(file), &metadata, "")
+        };
+
+        // A hand-made file doesn't take part in numbering but can still be found
+        write_issue("bugfix-login.md", "Login fix")?;
+        assert_eq!(generate_issue_id(&project_dir)?, "001");
+        write_issue("001-first.md", "First")?;
+        assert_eq!(generate_issue_id(&project_dir)?, "002");
+        assert_eq!(
+            find_issue(&project_dir, "bugfix")?.metadata.title,
+            "Login fix"
+        );
+        assert_eq!(find_issue(&project_dir, "001")?.metadata.title, "First");
+
+        // Two files claiming the same number are reported, not resolved arbitrarily
+        write_issue("001-copy.md", "Copy")?;
+        let message = find_issue(&project_dir, "001").unwrap_err().to_string();
+        assert!(message.contains("001-copy.md, 001-first.md"), "{}", message);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pillarignore_skips_directories() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        fs::create_dir(temp_dir.path().join(".pillar"))?;
+        create_test_project(temp_dir.path(), "real")?;
+        create_test_project(temp_dir.path(), "old-project")?;
+        fs::create_dir_all(temp_dir.path().join("docs"))?;
+        fs::write(
+            temp_dir.path().join("docs/README.md"),
+            "---\ntitle: Docs site\n---\n# Documentation\n",
+        )?;
+
+        let loaded = load_projects(temp_dir.path())?;
+        assert_eq!(loaded.items.len(), 2);
+        assert_eq!(loaded.errors.len(), 1);
+
+        fs::write(
+            temp_dir.path().join(IGNORE_FILE),
+            "# not projects\ndocs/\n\nold-*\n",
+        )?;
+        let loaded = load_projects(temp_dir.path())?;
+        let names: Vec<_> = loaded
+            .items
+            .iter()
+            .map(|p| p.metadata.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["real"]);
+        assert!(loaded.errors.is_empty());
+
+        fs::write(temp_dir.path().join(IGNORE_FILE), "[unclosed\n")?;
+        assert!(load_projects(temp_dir.path()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_readme_without_frontmatter_is_not_a_project() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        create_test_project(temp_dir.path(), "real")?;
+        fs::create_dir_all(temp_dir.path().join("notes"))?;
+        fs::write(temp_dir.path().join("notes/README.md"), "# Notes\n")?;
+        fs::create_dir_all(temp_dir.path().join("broken"))?;
+        fs::write(
+            temp_dir.path().join("broken/README.md"),
+            "---\nname: Broken\n---\n",
+        )?;
+
+        let loaded = load_projects(temp_dir.path())?;
+        assert_eq!(loaded.items.len(), 1);
+        // Only the README that was meant to be a project is reported
+        assert_eq!(loaded.errors.len(), 1);
+        assert!(loaded.errors[0].path.ends_with("broken"));
+
+        Ok(())
+    }
 }
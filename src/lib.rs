@@ -0,0 +1,36 @@
+//! Pillar: a file-based task tracker
+//!
+//! Projects, milestones, and issues are markdown files with YAML frontmatter under a
+//! workspace root (the directory containing `.pillar/`). The `pillar` binary is a thin
+//! wrapper around this crate; the same modules can be used to build other tooling.
+//!
+//! - [`models`]: the data types (`Issue`, `Project`, `Status`, `Config`, ...)
+//! - [`parser`]: reading and writing individual files
+//! - [`fs`]: workspace discovery, config, and listing entities on disk
+//! - [`commands`]: the operations behind each CLI subcommand
+//! - [`display`]: terminal formatting shared by the commands
+//! - [`dates`]: parsing date arguments
+//!
+//! ```no_run
+//! use pillar_cli::fs::{get_base_directory, list_all_issues};
+//! use pillar_cli::models::Status;
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let base_dir = get_base_directory()?;
+//! for issue in list_all_issues(&base_dir)? {
+//!     if issue.metadata.status == Status::InProgress {
+//!         println!("{}", issue.metadata.title);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod cli;
+pub mod commands;
+pub mod dates;
+pub mod display;
+pub mod fs;
+pub mod models;
+pub mod pager;
+pub mod parser;
@@ -1,26 +1,56 @@
-mod cli;
-mod commands;
-mod fs;
-mod models;
-mod parser;
-
 use anyhow::Result;
 use clap::Parser;
-use cli::{Cli, Commands, CommentCommands, IssueCommands, MilestoneCommands, ProjectCommands};
+use pillar_cli::cli::{
+    Cli, Commands, CommentCommands, ConfigCommands, ImportCommands, IssueCommands,
+    MilestoneCommands, ProjectCommands, TagCommands,
+};
+use pillar_cli::{commands, display, fs, pager};
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    if let Some(workspace) = &cli.workspace {
+        std::env::set_var(fs::WORKSPACE_ENV, workspace);
+    }
+
+    display::configure_from_workspace();
+    display::set_verbosity(if cli.quiet {
+        display::Verbosity::Quiet
+    } else if cli.verbose {
+        display::Verbosity::Verbose
+    } else {
+        display::Verbosity::Normal
+    });
+
+    let _pager = if !cli.no_pager && cli.command.pages_output() {
+        pager::Pager::start()
+    } else {
+        None
+    };
+
     match cli.command {
-        Commands::Init { base_directory } => {
-            commands::init(base_directory.as_deref())?;
+        Commands::Init {
+            base_directory,
+            with_example,
+        } => {
+            commands::init(base_directory.as_deref(), with_example)?;
         }
         Commands::Project(cmd) => match cmd {
             ProjectCommands::Create { name, id, priority } => {
-                commands::create_project(&name, id.as_deref(), &priority)?;
+                commands::create_project(&name, id.as_deref(), priority.as_deref())?;
             }
-            ProjectCommands::List { status, priority } => {
-                commands::list_projects(status.as_deref(), priority.as_deref())?;
+            ProjectCommands::List {
+                status,
+                priority,
+                count,
+                json,
+            } => {
+                let projects = commands::list_projects(status.as_deref(), priority.as_deref())?;
+                if json {
+                    print_json(&projects)?;
+                } else {
+                    commands::project::print_projects(&projects, count);
+                }
             }
             ProjectCommands::Show { name } => {
                 commands::show_project(&name)?;
@@ -32,74 +62,185 @@ fn main() -> Result<()> {
             } => {
                 commands::edit_project(&name, status.as_deref(), priority.as_deref())?;
             }
+            ProjectCommands::Rename { old, new } => {
+                commands::rename_project(&old, &new)?;
+            }
         },
         Commands::Milestone(cmd) => match cmd {
             MilestoneCommands::Create {
                 project,
                 title,
                 date,
+                status,
+                priority,
+                every,
+                count,
+            } => match (every, date, count) {
+                (Some(every), Some(date), Some(count)) => {
+                    commands::milestone::create_milestone_series(
+                        &project,
+                        &title,
+                        &date,
+                        &every,
+                        count,
+                        status.as_deref(),
+                        priority.as_deref(),
+                    )?;
+                }
+                (_, date, _) => {
+                    commands::create_milestone(
+                        &project,
+                        &title,
+                        date.as_deref(),
+                        status.as_deref(),
+                        priority.as_deref(),
+                    )?;
+                }
+            },
+            MilestoneCommands::List {
+                project,
+                count,
+                json,
             } => {
-                commands::create_milestone(&project, &title, date.as_deref())?;
-            }
-            MilestoneCommands::List { project } => {
-                commands::list_milestones(project.as_deref())?;
+                let milestones = commands::list_milestones(project.as_deref())?;
+                if json {
+                    print_json(&commands::milestone::milestones_to_json(&milestones)?)?;
+                } else {
+                    commands::milestone::print_milestones(&milestones, count);
+                }
             }
             MilestoneCommands::Edit {
                 project,
                 title,
                 status,
                 date,
+                new_title,
             } => {
-                commands::edit_milestone(&project, &title, status.as_deref(), date.as_deref())?;
+                commands::edit_milestone(
+                    &project,
+                    &title,
+                    status.as_deref(),
+                    date.as_deref(),
+                    new_title.as_deref(),
+                )?;
+            }
+            MilestoneCommands::Burndown {
+                project,
+                title,
+                format,
+                output,
+            } => {
+                commands::milestone::burndown(&project, &title, &format, output.as_deref())?;
             }
         },
         Commands::Issue(cmd) => match cmd {
             IssueCommands::Create {
                 project,
                 title,
+                inbox,
                 priority,
+                severity,
                 milestone,
                 tags,
+                template,
+                url,
             } => {
+                // With --inbox the only positional argument is the title
+                let (project, title) = match title {
+                    Some(title) if !inbox => (project, title),
+                    _ => (fs::INBOX.to_string(), project),
+                };
                 commands::create_issue(
                     &project,
                     &title,
-                    &priority,
-                    milestone.as_deref(),
-                    tags.as_deref(),
+                    &commands::NewIssue {
+                        priority: priority.as_deref(),
+                        milestone: milestone.as_deref(),
+                        tags: tags.as_deref(),
+                        template: template.as_deref(),
+                        severity: severity.as_deref(),
+                        url: url.as_deref(),
+                    },
                 )?;
             }
             IssueCommands::List {
                 status,
                 priority,
+                severity,
                 project,
                 milestone,
                 tag,
+                assignee,
+                me,
+                unassigned,
+                since,
+                until,
+                updated_after,
+                sort,
+                count,
+                table,
+                json,
+                show_tasks,
             } => {
-                commands::list_issues(
+                let mut filter = commands::issue::IssueFilter::new(
                     status.as_deref(),
                     priority.as_deref(),
-                    project.as_deref(),
                     milestone.as_deref(),
                     tag.as_deref(),
-                )?;
+                )?
+                .with_severity(severity.as_deref())?
+                .with_updated_range(since.as_deref(), until.as_deref())?
+                .with_updated_after(updated_after.as_deref())?;
+                filter.assignee = commands::issue::AssigneeFilter::from_flags(
+                    assignee.as_deref(),
+                    me,
+                    unassigned,
+                );
+                let issues = commands::list_issues(project.as_deref(), &filter, &sort)?;
+                if json {
+                    print_json(&commands::issue::issues_to_json(&issues)?)?;
+                } else {
+                    commands::issue::print_issues(&issues, count, table, show_tasks);
+                }
             }
             IssueCommands::Show { id } => {
                 commands::show_issue(&id)?;
             }
+            IssueCommands::History { id } => {
+                commands::issue_history(&id)?;
+            }
+            IssueCommands::Task {
+                id, index, done, ..
+            } => {
+                commands::issue::set_task(&id, index, done)?;
+            }
+            IssueCommands::Clone { id } => {
+                commands::clone_issue(&id)?;
+            }
+            IssueCommands::Move { id, project } => {
+                commands::move_issue(&id, &project)?;
+            }
             IssueCommands::Edit {
                 id,
                 status,
                 priority,
+                severity,
                 milestone,
                 tags,
+                url,
+                force,
             } => {
                 commands::edit_issue(
                     &id,
-                    status.as_deref(),
-                    priority.as_deref(),
-                    milestone.as_deref(),
-                    tags.as_deref(),
+                    &commands::IssueEdits {
+                        status: status.as_deref(),
+                        priority: priority.as_deref(),
+                        milestone: milestone.as_deref(),
+                        tags: tags.as_deref(),
+                        severity: severity.as_deref(),
+                        url: url.as_deref(),
+                    },
+                    force,
                 )?;
             }
         },
@@ -117,39 +258,149 @@ fn main() -> Result<()> {
                 project,
                 identifier,
             } => {
-                commands::comment::list(&entity_type, &project, identifier.as_deref())?;
+                commands::comment::list(&entity_type, project.as_deref(), identifier.as_deref())?;
+            }
+            CommentCommands::ListAll { since } => {
+                commands::comment::list_all(since.as_deref())?;
+            }
+            CommentCommands::Mentions { username } => {
+                commands::comment::mentions(&username)?;
+            }
+        },
+        Commands::Tag(cmd) => match cmd {
+            TagCommands::List => {
+                commands::tag::list_tags()?;
+            }
+            TagCommands::Rename { old, new } => {
+                commands::tag::rename_tag(&old, &new)?;
+            }
+        },
+        Commands::Import(cmd) => match cmd {
+            ImportCommands::Csv { file, project } => {
+                commands::import::import_csv(&file, &project)?;
             }
         },
-        Commands::Status => {
-            commands::status()?;
+        Commands::Config(cmd) => match cmd {
+            ConfigCommands::Get { key } => {
+                commands::config::config_get(&key)?;
+            }
+            ConfigCommands::Set { key, value } => {
+                commands::config::config_set(&key, &value)?;
+            }
+        },
+        Commands::Status {
+            project,
+            watch,
+            porcelain,
+            interval,
+        } => {
+            if porcelain {
+                commands::view::status_porcelain(project.as_deref())?;
+            } else if watch {
+                commands::view::watch_status(project.as_deref(), interval)?;
+            } else {
+                commands::status(project.as_deref())?;
+            }
+        }
+        Commands::Next { project } => {
+            commands::next::next(project.as_deref())?;
+        }
+        Commands::Stats { json } => {
+            commands::stats::stats(json)?;
         }
-        Commands::Board { project } => {
-            commands::board(project.as_deref())?;
+        Commands::Board {
+            project,
+            all_statuses,
+            group_by,
+            swimlanes,
+        } => {
+            commands::board(project.as_deref(), all_statuses, &group_by, swimlanes)?;
+        }
+        Commands::Search {
+            query,
+            entity_type,
+            count_only,
+        } => {
+            commands::search::search(&query, &entity_type, count_only)?;
         }
-        Commands::Search { query, entity_type } => {
-            commands::search::search(&query, &entity_type)?;
+        Commands::Log { entity, limit } => {
+            commands::log::show_log(entity.as_deref(), limit)?;
+        }
+        Commands::ReleaseNotes {
+            project,
+            milestone,
+            group_by,
+            output,
+        } => {
+            commands::release::release_notes(&project, &milestone, &group_by, output.as_deref())?;
         }
         Commands::Export {
             format,
             entity_type,
             output,
+            project,
+            status,
+            priority,
+            milestone,
+            tag,
+            fields,
         } => {
-            commands::export::export(&format, &entity_type, output.as_deref())?;
+            let filter = commands::issue::IssueFilter::new(
+                status.as_deref(),
+                priority.as_deref(),
+                milestone.as_deref(),
+                tag.as_deref(),
+            )?;
+            commands::export::export(
+                &format,
+                &entity_type,
+                output.as_deref(),
+                project.as_deref(),
+                &filter,
+                fields.as_deref(),
+            )?;
         }
-        Commands::Ui { port } => {
+        Commands::Ui {
+            port,
+            auto_port,
+            token,
+            read_only,
+        } => {
             let rt = tokio::runtime::Builder::new_multi_thread()
                 .enable_all()
                 .build()?;
-            rt.block_on(commands::run_ui(port))?;
+            rt.block_on(commands::run_ui(
+                port,
+                auto_port,
+                token.as_deref(),
+                read_only,
+            ))?;
         }
         Commands::Prompts => {
             print_agent_prompts();
         }
+        Commands::Migrate => {
+            commands::migrate::migrate()?;
+        }
+        Commands::Doctor { fix } => {
+            commands::doctor::doctor(fix)?;
+        }
+        Commands::Schema { entity } => {
+            commands::schema::schema(entity.as_deref())?;
+        }
+        Commands::Manpages { dir } => {
+            commands::manpages::generate_manpages(&dir)?;
+        }
     }
 
     Ok(())
 }
 
+fn print_json<T: serde::Serialize + ?Sized>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
 fn print_agent_prompts() {
     println!(
         r#"# AI Agent System Prompt for Pillar
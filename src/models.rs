@@ -1,8 +1,13 @@
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use uuid::Uuid;
 
+/// On-disk workspace format written by this build; `pillar migrate` upgrades older workspaces to it
+pub const WORKSPACE_VERSION: &str = "0.1.0";
+
 /// Workspace configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceConfig {
@@ -22,15 +27,116 @@ pub struct DefaultConfig {
     pub status: String,
 }
 
+/// Git integration settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// Commit tracker changes after every create/edit
+    #[serde(default)]
+    pub auto_commit: bool,
+}
+
+/// How statuses are rendered in terminal output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StatusStyle {
+    /// Colored status names
+    #[default]
+    Text,
+    /// An emoji before the status name, for terminals and logs without color
+    Emoji,
+}
+
+/// Output settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DisplayConfig {
+    #[serde(default)]
+    pub status_style: StatusStyle,
+    /// Don't warn about project, milestone, and issue files that fail to parse
+    #[serde(default)]
+    pub quiet_warnings: bool,
+}
+
+/// Tag display settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagsConfig {
+    /// Color for each tag, by name (`bug = "red"`); other tags use a default color
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub colors: BTreeMap<String, String>,
+}
+
+/// Work-in-progress limits, applied to each project separately
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LimitsConfig {
+    /// Most issues a project may have in progress at once; unlimited if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress: Option<usize>,
+}
+
+/// Weights for `issue list --sort score`, which ranks issues by
+/// `priority weight + days since created × age_weight`, highest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreConfig {
+    pub low: f64,
+    pub medium: f64,
+    pub high: f64,
+    pub urgent: f64,
+    /// Score gained per day of age; the default makes a low-priority issue outrank a
+    /// fresh urgent one after about 140 days
+    pub age_weight: f64,
+}
+
+impl Default for ScoreConfig {
+    fn default() -> Self {
+        ScoreConfig {
+            low: 1.0,
+            medium: 2.0,
+            high: 4.0,
+            urgent: 8.0,
+            age_weight: 0.05,
+        }
+    }
+}
+
+impl ScoreConfig {
+    pub fn priority_weight(&self, priority: Priority) -> f64 {
+        match priority {
+            Priority::Low => self.low,
+            Priority::Medium => self.medium,
+            Priority::High => self.high,
+            Priority::Urgent => self.urgent,
+        }
+    }
+
+    /// The score of an issue at `now`; an issue without a `created` date has no age
+    pub fn score(&self, metadata: &IssueMetadata, now: DateTime<Utc>) -> f64 {
+        let age_days = metadata
+            .created
+            .map(|created| (now - created).num_seconds().max(0) as f64 / 86_400.0)
+            .unwrap_or(0.0);
+        self.priority_weight(metadata.priority) + age_days * self.age_weight
+    }
+}
+
 /// Complete configuration file structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub workspace: WorkspaceConfig,
     pub defaults: DefaultConfig,
+    #[serde(default)]
+    pub git: GitConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub score: ScoreConfig,
+    #[serde(default)]
+    pub limits: LimitsConfig,
+    #[serde(default)]
+    pub tags: TagsConfig,
 }
 
 /// Status of a project, milestone, or issue
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum Status {
     Backlog,
@@ -40,6 +146,17 @@ pub enum Status {
     Cancelled,
 }
 
+impl Status {
+    /// Every status, in workflow order
+    pub const ALL: [Status; 5] = [
+        Status::Backlog,
+        Status::Todo,
+        Status::InProgress,
+        Status::Completed,
+        Status::Cancelled,
+    ];
+}
+
 impl std::fmt::Display for Status {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -68,7 +185,9 @@ impl std::str::FromStr for Status {
 }
 
 /// Priority level for projects and issues
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum Priority {
     Low,
@@ -77,6 +196,72 @@ pub enum Priority {
     Urgent,
 }
 
+impl Priority {
+    /// Every priority, from lowest to highest
+    pub const ALL: [Priority; 4] = [
+        Priority::Low,
+        Priority::Medium,
+        Priority::High,
+        Priority::Urgent,
+    ];
+}
+
+/// Technical impact of an issue, independent of its priority
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Trivial,
+    Minor,
+    Major,
+    Critical,
+}
+
+impl Severity {
+    /// Every severity, from least to most severe
+    pub const ALL: [Severity; 4] = [
+        Severity::Trivial,
+        Severity::Minor,
+        Severity::Major,
+        Severity::Critical,
+    ];
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Trivial => write!(f, "trivial"),
+            Severity::Minor => write!(f, "minor"),
+            Severity::Major => write!(f, "major"),
+            Severity::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "trivial" => Ok(Severity::Trivial),
+            "minor" => Ok(Severity::Minor),
+            "major" => Ok(Severity::Major),
+            "critical" => Ok(Severity::Critical),
+            _ => Err(anyhow::anyhow!("Invalid severity: {}", s)),
+        }
+    }
+}
+
+/// Standard list order for prioritized entities: highest priority first, then by name
+/// Used by `project list`, `issue list`, and `next` so they always agree
+pub fn by_priority_then_name<K: Ord + ?Sized>(
+    a: (Priority, &K),
+    b: (Priority, &K),
+) -> std::cmp::Ordering {
+    b.0.cmp(&a.0).then_with(|| a.1.cmp(b.1))
+}
+
 impl std::fmt::Display for Priority {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -103,11 +288,12 @@ impl std::str::FromStr for Priority {
 }
 
 /// Metadata for a project
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectMetadata {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_id: Option<String>,
+    #[serde(alias = "state")]
     pub status: Status,
     pub priority: Priority,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -127,10 +313,15 @@ pub struct Project {
 }
 
 /// Metadata for a milestone
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MilestoneMetadata {
     pub title: String,
+    #[serde(alias = "state")]
     pub status: Status,
+    /// Optional; milestones without one sort after prioritized milestones with the same date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    #[serde(alias = "due")]
     pub target_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
@@ -151,23 +342,59 @@ pub struct Milestone {
 }
 
 /// Metadata for an issue
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IssueMetadata {
     pub title: String,
+    #[serde(alias = "state")]
     pub status: Status,
     pub priority: Priority,
+    /// Technical impact, for bug triage; unset on most issues
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub milestone: Option<String>,
-    #[serde(default)]
+    #[serde(default, alias = "labels")]
     pub tags: Vec<String>,
+    /// Who is working on the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// Who created the issue; set once by `issue create` and kept through edits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reporter: Option<String>,
+    /// Link to an external ticket this issue mirrors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated: Option<DateTime<Utc>>,
 }
 
+/// Normalize a single tag: trimmed and lowercased
+/// Spaces inside a tag are kept ("good first issue"); returns None for an empty tag
+pub fn normalize_tag(tag: &str) -> Option<String> {
+    let tag = tag.trim().to_lowercase();
+    (!tag.is_empty()).then_some(tag)
+}
+
+/// Parse a comma-separated tag list, normalizing each tag and dropping empties and duplicates
+pub fn parse_tags(list: &str) -> Vec<String> {
+    normalize_tags(list.split(','))
+}
+
+/// Normalize tags in order, dropping empties and duplicates
+pub fn normalize_tags<'a>(tags: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags.into_iter().filter_map(normalize_tag) {
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
 /// An issue with its content and location
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Issue {
@@ -185,6 +412,9 @@ pub struct Comment {
     pub author: String,
     pub timestamp: String,
     pub content: String,
+    /// Usernames `@mentioned` in the content; derived from it, never stored in the file
+    #[serde(default)]
+    pub mentions: Vec<String>,
 }
 
 impl Comment {
@@ -192,19 +422,79 @@ impl Comment {
     pub fn new(author: String, content: String) -> Self {
         let id = Uuid::new_v4().to_string();
         let timestamp = Utc::now().to_rfc3339();
+        let mentions = crate::parser::extract_mentions(&content);
         Comment {
             id,
             author,
             timestamp,
             content,
+            mentions,
         }
     }
 }
 
+/// A single field change recorded in the activity log
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+impl FieldChange {
+    pub fn new(field: &str, from: Option<String>, to: Option<String>) -> Self {
+        FieldChange {
+            field: field.to_string(),
+            from,
+            to,
+        }
+    }
+}
+
+impl std::fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.from.is_none() && self.to.is_none() {
+            return write!(f, "{} changed", self.field);
+        }
+        let from = self.from.as_deref().unwrap_or("none");
+        let to = self.to.as_deref().unwrap_or("none");
+        write!(f, "{} {} -> {}", self.field, from, to)
+    }
+}
+
+/// One line of the workspace activity log (.pillar/activity.log)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ActivityEntry {
+    pub timestamp: DateTime<Utc>,
+    pub author: String,
+    /// What happened, e.g. "create", "edit", "rename", "comment"
+    pub action: String,
+    /// Entity kind: "project", "milestone", or "issue"
+    pub kind: String,
+    /// Entity identifier, e.g. "my-project", "my-project/v1.0", "my-project/001"
+    pub entity: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<FieldChange>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_tags_normalizes() {
+        assert_eq!(parse_tags("a, b ,,c"), vec!["a", "b", "c"]);
+        assert_eq!(
+            parse_tags("Bug, good first issue ,bug"),
+            vec!["bug", "good first issue"]
+        );
+        assert!(parse_tags(" , ").is_empty());
+        assert_eq!(normalize_tag("  UI "), Some("ui".to_string()));
+        assert_eq!(normalize_tag("   "), None);
+    }
+
     #[test]
     fn test_status_from_str() {
         assert_eq!("backlog".parse::<Status>().unwrap(), Status::Backlog);
@@ -217,7 +507,6 @@ mod tests {
         assert!("invalid".parse::<Status>().is_err());
     }
 
-    #[test]
     #[test]
     fn test_config_default_base_directory() {
         let config = WorkspaceConfig {
@@ -238,6 +527,11 @@ mod tests {
                 priority: "medium".to_string(),
                 status: "backlog".to_string(),
             },
+            git: GitConfig::default(),
+            display: DisplayConfig::default(),
+            score: ScoreConfig::default(),
+            limits: LimitsConfig::default(),
+            tags: TagsConfig::default(),
         };
 
         let toml = toml::to_string(&config).unwrap();
@@ -262,7 +556,20 @@ status = "backlog"
 "#;
         let config: Config = toml::from_str(toml).unwrap();
         assert_eq!(config.workspace.base_directory, ".");
+        assert_eq!(config.display.status_style, StatusStyle::Text);
+    }
+
+    #[test]
+    fn test_severity_from_str_and_display() {
+        for severity in Severity::ALL {
+            assert_eq!(severity.to_string().parse::<Severity>().unwrap(), severity);
+        }
+        assert_eq!("MAJOR".parse::<Severity>().unwrap(), Severity::Major);
+        assert!("blocker".parse::<Severity>().is_err());
+        assert!(Severity::Critical > Severity::Trivial);
     }
+
+    #[test]
     fn test_priority_from_str() {
         assert_eq!("low".parse::<Priority>().unwrap(), Priority::Low);
         assert_eq!("medium".parse::<Priority>().unwrap(), Priority::Medium);
@@ -278,6 +585,27 @@ status = "backlog"
         assert!(Priority::High < Priority::Urgent);
     }
 
+    #[test]
+    fn test_by_priority_then_name() {
+        let mut items = vec![
+            (Priority::Low, "alpha"),
+            (Priority::Urgent, "zulu"),
+            (Priority::High, "bravo"),
+            (Priority::Urgent, "echo"),
+        ];
+        items.sort_by(|a, b| by_priority_then_name(*a, *b));
+
+        assert_eq!(
+            items,
+            vec![
+                (Priority::Urgent, "echo"),
+                (Priority::Urgent, "zulu"),
+                (Priority::High, "bravo"),
+                (Priority::Low, "alpha"),
+            ]
+        );
+    }
+
     #[test]
     fn test_status_display() {
         assert_eq!(Status::Backlog.to_string(), "backlog");
@@ -289,4 +617,65 @@ status = "backlog"
         assert_eq!(Priority::Low.to_string(), "low");
         assert_eq!(Priority::Urgent.to_string(), "urgent");
     }
+
+    #[test]
+    fn test_issue_field_aliases_round_trip() {
+        let yaml = "title: Imported\nstate: in-progress\npriority: high\nlabels: [bug, ui]\n";
+        let metadata: IssueMetadata = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(metadata.status, Status::InProgress);
+        assert_eq!(metadata.tags, vec!["bug", "ui"]);
+
+        let written = serde_yaml::to_string(&metadata).unwrap();
+        assert!(written.contains("status: in-progress"), "{}", written);
+        assert!(written.contains("tags:"), "{}", written);
+        assert!(!written.contains("state:") && !written.contains("labels:"));
+    }
+
+    #[test]
+    fn test_milestone_due_alias_round_trip() {
+        let yaml = "title: Beta\nstate: todo\ndue: 2026-03-01\n";
+        let metadata: MilestoneMetadata = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(metadata.status, Status::Todo);
+        assert_eq!(metadata.target_date.as_deref(), Some("2026-03-01"));
+
+        let written = serde_yaml::to_string(&metadata).unwrap();
+        assert!(written.contains("target_date: 2026-03-01"), "{}", written);
+        assert!(!written.contains("due:"));
+    }
+
+    #[test]
+    fn test_score_weighs_priority_and_age() {
+        let now = Utc::now();
+        let issue = |priority, age_days| IssueMetadata {
+            title: "Scored".to_string(),
+            status: Status::Todo,
+            priority,
+            severity: None,
+            project: None,
+            milestone: None,
+            tags: vec![],
+            assignee: None,
+            reporter: None,
+            url: None,
+            created: Some(now - chrono::Duration::days(age_days)),
+            updated: None,
+        };
+        let weights = ScoreConfig::default();
+        let fresh_urgent = weights.score(&issue(Priority::Urgent, 0), now);
+
+        // A month-old low-priority issue still ranks below a fresh urgent one...
+        assert!(weights.score(&issue(Priority::Low, 30), now) < fresh_urgent);
+        // ...but one that has sat for a year overtakes it
+        assert!(weights.score(&issue(Priority::Low, 365), now) > fresh_urgent);
+
+        // Without an age weight, only priority counts
+        let weights = ScoreConfig {
+            age_weight: 0.0,
+            ..ScoreConfig::default()
+        };
+        assert!(
+            weights.score(&issue(Priority::Low, 365), now)
+                < weights.score(&issue(Priority::Urgent, 0), now)
+        );
+    }
 }
@@ -0,0 +1,111 @@
+//! Send long command output through `$PAGER` when writing to a terminal
+
+use std::io::{IsTerminal, Write};
+
+/// Default pager; `-R` lets ANSI colors through
+const DEFAULT_PAGER: &str = "less -R";
+
+/// Options for `less` when the user hasn't set `LESS`: quit right away if the output
+/// fits on one screen (`F`), keep colors (`R`), and don't clear the screen (`X`)
+const DEFAULT_LESS: &str = "FRX";
+
+/// A running pager that receives this process's stdout
+/// Dropping it flushes stdout, restores the original stream, and waits for the pager to exit
+pub struct Pager {
+    #[cfg(unix)]
+    child: std::process::Child,
+    #[cfg(unix)]
+    saved_stdout: libc::c_int,
+    #[cfg(unix)]
+    saved_sigpipe: libc::sighandler_t,
+}
+
+impl Pager {
+    /// Start `$PAGER` (or `less -R`) and redirect stdout into it
+    /// Returns `None` when stdout isn't a terminal, the pager is disabled (`PAGER=` or
+    /// `PAGER=cat`), or it can't be started, in which case output goes straight to stdout
+    pub fn start() -> Option<Pager> {
+        if !std::io::stdout().is_terminal() {
+            return None;
+        }
+
+        let command = std::env::var("PAGER").unwrap_or_else(|_| DEFAULT_PAGER.to_string());
+        let command = command.trim();
+        if command.is_empty() || command == "cat" {
+            return None;
+        }
+
+        Self::spawn(command)
+    }
+
+    #[cfg(unix)]
+    fn spawn(command: &str) -> Option<Pager> {
+        use std::os::unix::io::AsRawFd;
+        use std::process::{Command, Stdio};
+
+        // Decide on colors while stdout is still the terminal; colored would otherwise
+        // turn them off once it sees a pipe. NO_COLOR and CLICOLOR still apply here.
+        let colorize = colored::control::ShouldColorize::from_env().should_colorize();
+
+        let mut pager = Command::new("sh");
+        pager.arg("-c").arg(command).stdin(Stdio::piped());
+        if std::env::var_os("LESS").is_none() {
+            pager.env("LESS", DEFAULT_LESS);
+        }
+        let mut child = pager.spawn().ok()?;
+
+        let stdin = child.stdin.take()?;
+        let _ = std::io::stdout().flush();
+
+        // SAFETY: plain fd duplication on descriptors we own; `stdin` is closed after
+        // dup2 so fd 1 holds the only write end of the pipe
+        let saved_stdout = unsafe { libc::dup(libc::STDOUT_FILENO) };
+        if saved_stdout < 0 || unsafe { libc::dup2(stdin.as_raw_fd(), libc::STDOUT_FILENO) } < 0 {
+            if saved_stdout >= 0 {
+                // SAFETY: the fd was just returned by dup() and nothing else holds it
+                unsafe { libc::close(saved_stdout) };
+            }
+            drop(stdin);
+            let _ = child.wait();
+            return None;
+        }
+        drop(stdin);
+
+        // Rust ignores SIGPIPE, so once the user quits the pager early the next println!
+        // would panic on the broken pipe. Let the signal end the process quietly instead,
+        // the way `git log | head` does.
+        // SAFETY: swapping a signal disposition; the previous one is restored on drop
+        let saved_sigpipe = unsafe { libc::signal(libc::SIGPIPE, libc::SIG_DFL) };
+
+        colored::control::set_override(colorize);
+
+        Some(Pager {
+            child,
+            saved_stdout,
+            saved_sigpipe,
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn spawn(_command: &str) -> Option<Pager> {
+        None
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Pager {
+    fn drop(&mut self) {
+        let _ = std::io::stdout().flush();
+
+        // Putting the terminal back on fd 1 closes our end of the pipe, so the pager sees EOF
+        // SAFETY: `saved_stdout` came from dup() in `spawn` and is only closed here;
+        // `saved_sigpipe` is the disposition `spawn` replaced
+        unsafe {
+            libc::dup2(self.saved_stdout, libc::STDOUT_FILENO);
+            libc::close(self.saved_stdout);
+            libc::signal(libc::SIGPIPE, self.saved_sigpipe);
+        }
+
+        let _ = self.child.wait();
+    }
+}
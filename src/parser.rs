@@ -2,35 +2,91 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::Path;
 
+use crate::fs::write_atomic;
 use crate::models::{
     Comment, Issue, IssueMetadata, Milestone, MilestoneMetadata, Project, ProjectMetadata,
 };
 
-/// Parse a markdown file with YAML frontmatter
-fn parse_frontmatter<T>(content: &str) -> Result<(T, String)>
-where
-    T: serde::de::DeserializeOwned,
-{
-    let content = content.trim();
+/// Split a markdown document into its YAML frontmatter and body
+/// Both the opening and closing `---` delimiters must be on their own line, so a
+/// Markdown horizontal rule in the body is never mistaken for the terminator.
+pub fn split_frontmatter(content: &str) -> Result<(&str, &str)> {
+    let content = content.trim_start();
+    let mut lines = content.split_inclusive('\n');
 
-    if !content.starts_with("---") {
+    let opening = lines.next().unwrap_or("");
+    if opening.trim_end() != "---" {
         return Err(anyhow::anyhow!(
             "File does not start with frontmatter delimiter"
         ));
     }
 
-    let rest = &content[3..];
-    let end_pos = rest
-        .find("\n---")
-        .ok_or_else(|| anyhow::anyhow!("Could not find end of frontmatter"))?;
+    let start = opening.len();
+    let mut offset = start;
+    for line in lines {
+        if line.trim_end() == "---" {
+            return Ok((&content[start..offset], &content[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+
+    Err(anyhow::anyhow!("Could not find end of frontmatter"))
+}
+
+/// Parse a markdown file with YAML frontmatter; `path` is only used in error messages
+fn parse_frontmatter<T>(content: &str, path: &Path) -> Result<(T, String)>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let (frontmatter, body) = split_frontmatter(content)
+        .with_context(|| format!("Invalid frontmatter in {}", path.display()))?;
+
+    let metadata: T = serde_yaml::from_str(frontmatter).map_err(|e| {
+        anyhow::anyhow!(
+            "Invalid frontmatter in {}: {}",
+            path.display(),
+            describe_yaml_error(&e)
+        )
+    })?;
+
+    Ok((metadata, body.trim().to_string()))
+}
 
-    let frontmatter = &rest[..end_pos];
-    let body = &rest[end_pos + 4..].trim();
+/// Reword a serde_yaml error so it names the offending field, e.g.
+/// "missing required field 'status'" or "invalid value for 'priority' (line 3): ..."
+fn describe_yaml_error(error: &serde_yaml::Error) -> String {
+    let message = error.to_string();
+    // The location is reported separately below
+    let message = match message.rfind(" at line ") {
+        Some(pos) => &message[..pos],
+        None => message.as_str(),
+    };
 
-    let metadata: T =
-        serde_yaml::from_str(frontmatter).context("Failed to parse frontmatter YAML")?;
+    if let Some(field) = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.strip_suffix('`'))
+    {
+        return format!("missing required field '{}'", field);
+    }
 
-    Ok((metadata, body.to_string()))
+    let line = error
+        .location()
+        .map(|l| format!(" (line {})", l.line()))
+        .unwrap_or_default();
+
+    match message.split_once(": ") {
+        Some((field, problem)) if !field.contains(' ') => {
+            let problem = match problem
+                .strip_prefix("unknown variant ")
+                .and_then(|rest| rest.split_once(", expected one of "))
+            {
+                Some((value, expected)) => format!("{} is not one of {}", value, expected),
+                None => problem.to_string(),
+            };
+            format!("invalid value for '{}'{}: {}", field, line, problem)
+        }
+        _ => format!("{}{}", message, line),
+    }
 }
 
 /// Read and parse a project README.md
@@ -40,7 +96,7 @@ pub fn read_project<P: AsRef<Path>>(path: P) -> Result<Project> {
     let content = fs::read_to_string(&readme_path)
         .with_context(|| format!("Failed to read project file: {}", readme_path.display()))?;
 
-    let (metadata, description) = parse_frontmatter::<ProjectMetadata>(&content)
+    let (metadata, description) = parse_frontmatter::<ProjectMetadata>(&content, &readme_path)
         .context("Failed to parse project metadata")?;
 
     Ok(Project {
@@ -56,7 +112,7 @@ pub fn read_milestone<P: AsRef<Path>>(path: P) -> Result<Milestone> {
     let content = fs::read_to_string(path)
         .with_context(|| format!("Failed to read milestone file: {}", path.display()))?;
 
-    let (metadata, description) = parse_frontmatter::<MilestoneMetadata>(&content)
+    let (metadata, description) = parse_frontmatter::<MilestoneMetadata>(&content, path)
         .context("Failed to parse milestone metadata")?;
 
     Ok(Milestone {
@@ -72,8 +128,8 @@ pub fn read_issue<P: AsRef<Path>>(path: P) -> Result<Issue> {
     let content = fs::read_to_string(path)
         .with_context(|| format!("Failed to read issue file: {}", path.display()))?;
 
-    let (metadata, description) =
-        parse_frontmatter::<IssueMetadata>(&content).context("Failed to parse issue metadata")?;
+    let (metadata, description) = parse_frontmatter::<IssueMetadata>(&content, path)
+        .context("Failed to parse issue metadata")?;
 
     Ok(Issue {
         metadata,
@@ -92,10 +148,7 @@ where
 
     let content = format!("---\n{}---\n\n{}", frontmatter, body.trim());
 
-    fs::write(path.as_ref(), content)
-        .with_context(|| format!("Failed to write file: {}", path.as_ref().display()))?;
-
-    Ok(())
+    write_atomic(path, &content)
 }
 
 /// Read comments from a markdown file body
@@ -116,13 +169,14 @@ pub fn read_comments(body: &str) -> Vec<Comment> {
 
     // Split by ### headings
     let mut current_comment: Option<Comment> = None;
-    let mut content_lines = Vec::new();
+    let mut content_lines: Vec<&str> = Vec::new();
 
     for line in comments_section.lines() {
         if line.starts_with("### [") {
             // Save previous comment if any
             if let Some(mut comment) = current_comment.take() {
                 comment.content = content_lines.join("\n").trim().to_string();
+                comment.mentions = extract_mentions(&comment.content);
                 comments.push(comment);
                 content_lines.clear();
             }
@@ -143,6 +197,7 @@ pub fn read_comments(body: &str) -> Vec<Comment> {
                     author,
                     timestamp,
                     content: String::new(),
+                    mentions: Vec::new(),
                 });
             }
         } else if line.starts_with("## ") {
@@ -150,19 +205,120 @@ pub fn read_comments(body: &str) -> Vec<Comment> {
             break;
         } else if current_comment.is_some() {
             // Accumulate content lines
-            content_lines.push(line);
+            content_lines.push(unescape_comment_line(line));
         }
     }
 
     // Save last comment if any
     if let Some(mut comment) = current_comment {
         comment.content = content_lines.join("\n").trim().to_string();
+        comment.mentions = extract_mentions(&comment.content);
         comments.push(comment);
     }
 
     comments
 }
 
+/// Extract `@username` tokens from comment content, in order of first appearance.
+/// An `@` only starts a mention at the beginning of a word, so email addresses are
+/// skipped; duplicates are dropped case-insensitively.
+pub fn extract_mentions(content: &str) -> Vec<String> {
+    let is_name_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | '.');
+    let mut mentions: Vec<String> = Vec::new();
+    let mut prev: Option<char> = None;
+
+    for (i, c) in content.char_indices() {
+        if c == '@' && !prev.is_some_and(|p| p.is_alphanumeric() || p == '_' || p == '@') {
+            let rest = &content[i + 1..];
+            let end = rest.find(|c: char| !is_name_char(c)).unwrap_or(rest.len());
+            // Trailing punctuation ends the sentence, not the name
+            let name = rest[..end].trim_end_matches(['.', '-']);
+
+            if !name.is_empty() && !mentions.iter().any(|m| m.eq_ignore_ascii_case(name)) {
+                mentions.push(name.to_string());
+            }
+        }
+        prev = Some(c);
+    }
+
+    mentions
+}
+
+/// A `- [ ]` or `- [x]` checklist item in an issue body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Task {
+    /// Zero-based line number within the body
+    pub line: usize,
+    pub done: bool,
+    pub text: String,
+}
+
+/// Checklist items in an issue body, in order
+/// Items may start with `-`, `*`, or `+` and be indented; `[x]` and `[X]` are done.
+/// Items inside fenced code blocks or in the Comments section don't count.
+pub fn parse_tasks(body: &str) -> Vec<Task> {
+    let mut tasks = Vec::new();
+    let mut in_fence = false;
+
+    for (line_number, line) in body.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed == "## Comments" {
+            break;
+        }
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+            .or_else(|| trimmed.strip_prefix("+ "))
+        else {
+            continue;
+        };
+        let done = match item.get(..3) {
+            Some("[ ]") => false,
+            Some("[x]") | Some("[X]") => true,
+            _ => continue,
+        };
+
+        tasks.push(Task {
+            line: line_number,
+            done,
+            text: item[3..].trim().to_string(),
+        });
+    }
+
+    tasks
+}
+
+/// Return `body` with the checkbox of the task on `line` (from `parse_tasks`) set to
+/// `[x]` or `[ ]`. Every other byte of the body, line endings included, is kept.
+pub fn set_task_done(body: &str, line: usize, done: bool) -> String {
+    body.split_inclusive('\n')
+        .enumerate()
+        .map(|(number, text)| match text.find('[') {
+            Some(open) if number == line => format!(
+                "{}{}{}",
+                &text[..open],
+                if done { "[x]" } else { "[ ]" },
+                &text[open + 3..]
+            ),
+            _ => text.to_string(),
+        })
+        .collect()
+}
+
+/// Completed and total checklist items in an issue body, e.g. `(3, 5)`
+pub fn count_tasks(body: &str) -> (usize, usize) {
+    let tasks = parse_tasks(body);
+    (tasks.iter().filter(|t| t.done).count(), tasks.len())
+}
+
 /// Write comments section to markdown body
 /// Returns the body with comments section appended
 pub fn write_comments(body: &str, comments: &[Comment]) -> String {
@@ -187,13 +343,40 @@ pub fn write_comments(body: &str, comments: &[Comment]) -> String {
             "\n### [{}] - {}\n",
             comment.timestamp, comment.author
         ));
-        result.push_str(&comment.content);
-        result.push('\n');
+        for line in comment.content.lines() {
+            result.push_str(&escape_comment_line(line));
+            result.push('\n');
+        }
     }
 
     result
 }
 
+/// Whether a comment content line, minus any escaping backslashes, would read back
+/// as a comment header or the start of another section
+fn looks_like_heading(line: &str) -> bool {
+    let unescaped = line.trim_start_matches('\\');
+    unescaped.starts_with("### [") || unescaped.starts_with("## ")
+}
+
+/// Prefix heading-like content lines with a backslash so they stay part of the
+/// comment; lines that are already escaped get one more, so the escaping round-trips
+fn escape_comment_line(line: &str) -> std::borrow::Cow<'_, str> {
+    if looks_like_heading(line) {
+        format!("\\{}", line).into()
+    } else {
+        line.into()
+    }
+}
+
+/// Undo `escape_comment_line`
+fn unescape_comment_line(line: &str) -> &str {
+    match line.strip_prefix('\\') {
+        Some(rest) if looks_like_heading(rest) => rest,
+        _ => line,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,7 +395,8 @@ tags: [bug, urgent]
 This is the issue description.
 "#;
 
-        let result: Result<(IssueMetadata, String)> = parse_frontmatter(content);
+        let result: Result<(IssueMetadata, String)> =
+            parse_frontmatter(content, Path::new("issue.md"));
         assert!(result.is_ok());
 
         let (metadata, body) = result.unwrap();
@@ -226,7 +410,8 @@ This is the issue description.
     #[test]
     fn test_parse_frontmatter_missing_delimiter() {
         let content = "No frontmatter here";
-        let result: Result<(IssueMetadata, String)> = parse_frontmatter(content);
+        let result: Result<(IssueMetadata, String)> =
+            parse_frontmatter(content, Path::new("issue.md"));
         assert!(result.is_err());
     }
 
@@ -238,10 +423,101 @@ invalid: yaml: structure:
 
 Body
 "#;
-        let result: Result<(IssueMetadata, String)> = parse_frontmatter(content);
+        let result: Result<(IssueMetadata, String)> =
+            parse_frontmatter(content, Path::new("issue.md"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_frontmatter_body_starts_with_rule() {
+        let content =
+            "---\ntitle: Ruled\nstatus: todo\npriority: low\n---\n---\n\nAfter the rule\n";
+
+        let (metadata, body): (IssueMetadata, String) =
+            parse_frontmatter(content, Path::new("issue.md")).unwrap();
+        assert_eq!(metadata.title, "Ruled");
+        assert_eq!(body, "---\n\nAfter the rule");
+    }
+
+    #[test]
+    fn test_parse_frontmatter_delimiter_must_be_own_line() {
+        let content =
+            "---\ntitle: Dashes\nstatus: todo\npriority: low\n---\r\nBody\n\n---\n\nMore\n";
+
+        let (metadata, body): (IssueMetadata, String) =
+            parse_frontmatter(content, Path::new("issue.md")).unwrap();
+        assert_eq!(metadata.title, "Dashes");
+        assert_eq!(body, "Body\n\n---\n\nMore");
+
+        // A line that merely starts with dashes doesn't close the frontmatter
+        let content = "---\ntitle: Open\n----\n";
+        let result: Result<(IssueMetadata, String)> =
+            parse_frontmatter(content, Path::new("issue.md"));
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_frontmatter_missing_field_names_file() {
+        let content = "---\ntitle: No status\npriority: low\n---\n";
+        let err = parse_frontmatter::<IssueMetadata>(content, Path::new("issues/001-x.md"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("issues/001-x.md"), "{}", err);
+        assert!(err.contains("missing required field 'status'"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_frontmatter_invalid_value_names_field() {
+        let content = "---\ntitle: Typo\nstatus: todo\npriority: urgentt\n---\n";
+        let err = parse_frontmatter::<IssueMetadata>(content, Path::new("issue.md"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("invalid value for 'priority' (line 3)"),
+            "{}",
+            err
+        );
+        assert!(err.contains("`urgentt` is not one of"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_tasks() {
+        let body = "## Acceptance Criteria\n\n- [x] Parses input\n  * [ ] Nested item\n- [X] Upper-case done\n- [] not a task\n- plain bullet\n\n```\n- [ ] in a code block\n```\n\n## Comments\n\n### [2026-01-01T00:00:00Z] - Alice\n- [ ] in a comment\n";
+
+        let tasks = parse_tasks(body);
+        assert_eq!(
+            tasks,
+            vec![
+                Task {
+                    line: 2,
+                    done: true,
+                    text: "Parses input".to_string()
+                },
+                Task {
+                    line: 3,
+                    done: false,
+                    text: "Nested item".to_string()
+                },
+                Task {
+                    line: 4,
+                    done: true,
+                    text: "Upper-case done".to_string()
+                },
+            ]
+        );
+        assert_eq!(count_tasks(body), (2, 3));
+        assert_eq!(count_tasks("No checklist here"), (0, 0));
+
+        let toggled = set_task_done(body, 3, true);
+        assert_eq!(count_tasks(&toggled), (3, 3));
+        assert!(toggled.contains("\n  * [x] Nested item\n"));
+        assert_eq!(set_task_done(&toggled, 3, false), body);
+        assert_eq!(
+            set_task_done("- [ ] a\r\n- [ ] b\r\n", 1, true),
+            "- [ ] a\r\n- [x] b\r\n"
+        );
+    }
+
     #[test]
     fn test_write_with_frontmatter() -> Result<()> {
         let temp_file = NamedTempFile::new()?;
@@ -250,9 +526,13 @@ Body
             title: "Test".to_string(),
             status: Status::Todo,
             priority: Priority::Medium,
+            severity: None,
             project: None,
             milestone: None,
             tags: vec![],
+            assignee: None,
+            reporter: None,
+            url: None,
             created: None,
             updated: None,
         };
@@ -275,9 +555,13 @@ Body
             title: "Roundtrip Test".to_string(),
             status: Status::InProgress,
             priority: Priority::High,
+            severity: None,
             project: Some("test-project".to_string()),
             milestone: Some("v1.0".to_string()),
             tags: vec!["test".to_string(), "roundtrip".to_string()],
+            assignee: Some("alice".to_string()),
+            reporter: Some("bob".to_string()),
+            url: None,
             created: None,
             updated: None,
         };
@@ -291,6 +575,8 @@ Body
         assert_eq!(issue.metadata.title, original_metadata.title);
         assert_eq!(issue.metadata.status, original_metadata.status);
         assert_eq!(issue.metadata.priority, original_metadata.priority);
+        assert_eq!(issue.metadata.assignee, original_metadata.assignee);
+        assert_eq!(issue.metadata.reporter, original_metadata.reporter);
         assert_eq!(issue.description, body);
 
         Ok(())
@@ -345,6 +631,23 @@ Third comment
         assert_eq!(comments[2].author, "Charlie");
     }
 
+    #[test]
+    fn test_extract_mentions() {
+        assert_eq!(
+            extract_mentions("@alice can you look? cc @bob-smith, @Alice and @carol."),
+            vec!["alice", "bob-smith", "carol"]
+        );
+        assert!(extract_mentions("mail alice@example.com or @ someone").is_empty());
+        assert_eq!(extract_mentions("(@dave_1)"), vec!["dave_1"]);
+    }
+
+    #[test]
+    fn test_read_comments_computes_mentions() {
+        let body = "## Comments\n\n### [2025-12-29T10:00:00Z] - Alice\nThanks @bob\n";
+        let comments = read_comments(body);
+        assert_eq!(comments[0].mentions, vec!["bob"]);
+    }
+
     #[test]
     fn test_write_comments_empty() {
         let body = "# Issue Description\n\nSome content.";
@@ -360,6 +663,7 @@ Third comment
             author: "Alice".to_string(),
             timestamp: "2025-12-29T10:30:00Z".to_string(),
             content: "Test comment".to_string(),
+            mentions: Vec::new(),
         }];
         let result = write_comments(body, &comments);
         assert!(result.contains("## Comments"));
@@ -381,10 +685,42 @@ Old comment
             author: "NewUser".to_string(),
             timestamp: "2025-12-29T11:00:00Z".to_string(),
             content: "New comment".to_string(),
+            mentions: Vec::new(),
         }];
         let result = write_comments(body, &comments);
         assert!(!result.contains("OldUser"));
         assert!(result.contains("NewUser"));
         assert!(result.contains("New comment"));
     }
+
+    #[test]
+    fn test_comment_with_heading_lines_round_trips() {
+        let content = "Quoting the old thread:\n### [2020-01-01] - Eve\n## Not a section\n\\### [already escaped]";
+        let comments = vec![
+            Comment {
+                id: "1".to_string(),
+                author: "Alice".to_string(),
+                timestamp: "2025-12-29T10:30:00Z".to_string(),
+                content: content.to_string(),
+                mentions: Vec::new(),
+            },
+            Comment {
+                id: "2".to_string(),
+                author: "Bob".to_string(),
+                timestamp: "2025-12-29T11:00:00Z".to_string(),
+                content: "Second".to_string(),
+                mentions: Vec::new(),
+            },
+        ];
+
+        let body = write_comments("# Issue", &comments);
+        assert!(body.contains("\n\\### [2020-01-01] - Eve\n"));
+
+        let read = read_comments(&body);
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[0].author, "Alice");
+        assert_eq!(read[0].content, content);
+        assert_eq!(read[1].content, "Second");
+        assert_eq!(write_comments(&body, &read), body);
+    }
 }
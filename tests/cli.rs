@@ -0,0 +1,332 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn pillar() -> Command {
+    let mut cmd = Command::cargo_bin("pillar").unwrap();
+    cmd.env_remove("PILLAR_WORKSPACE");
+    cmd
+}
+
+fn init_workspace() -> TempDir {
+    let workspace = TempDir::new().unwrap();
+    pillar()
+        .current_dir(workspace.path())
+        .arg("init")
+        .assert()
+        .success();
+    pillar()
+        .current_dir(workspace.path())
+        .args(["project", "create", "pinned"])
+        .assert()
+        .success();
+    workspace
+}
+
+#[test]
+fn test_workspace_env_override() {
+    let workspace = init_workspace();
+    let elsewhere = TempDir::new().unwrap();
+
+    pillar()
+        .current_dir(elsewhere.path())
+        .args(["project", "list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Not in a Pillar workspace"));
+
+    pillar()
+        .current_dir(elsewhere.path())
+        .env("PILLAR_WORKSPACE", workspace.path())
+        .args(["project", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pinned"));
+}
+
+#[test]
+fn test_workspace_flag_override() {
+    let workspace = init_workspace();
+    let elsewhere = TempDir::new().unwrap();
+
+    pillar()
+        .current_dir(elsewhere.path())
+        .arg("--workspace")
+        .arg(workspace.path())
+        .args(["project", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pinned"));
+
+    // The flag wins over the environment variable
+    pillar()
+        .current_dir(elsewhere.path())
+        .env("PILLAR_WORKSPACE", elsewhere.path())
+        .args(["project", "list", "--workspace"])
+        .arg(workspace.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pinned"));
+
+    pillar()
+        .current_dir(workspace.path())
+        .arg("--workspace")
+        .arg(elsewhere.path())
+        .args(["project", "list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a Pillar workspace"));
+}
+
+#[test]
+fn test_list_count_flags() {
+    let workspace = init_workspace();
+    let run = |args: &[&str]| {
+        pillar()
+            .current_dir(workspace.path())
+            .args(args)
+            .assert()
+            .success()
+    };
+
+    run(&["issue", "create", "pinned", "First", "-p", "high"]);
+    run(&["issue", "create", "pinned", "Second", "-p", "low"]);
+    run(&["milestone", "create", "pinned", "v1"]);
+
+    run(&["issue", "list", "--count"]).stdout("2\n");
+    run(&["issue", "list", "--priority", "high", "--count"]).stdout("1\n");
+    run(&["issue", "list", "--priority", "urgent", "--count"]).stdout("0\n");
+    run(&["project", "list", "--count"]).stdout("1\n");
+    run(&["milestone", "list", "--count"]).stdout("1\n");
+}
+
+#[test]
+fn test_status_watch_renders_once_without_terminal() {
+    let workspace = init_workspace();
+
+    pillar()
+        .current_dir(workspace.path())
+        .args(["status", "--watch", "--interval", "1"])
+        .timeout(std::time::Duration::from_secs(10))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Workspace Status"));
+}
+
+#[test]
+fn test_status_shows_workspace_in_empty_workspace() {
+    let workspace = TempDir::new().unwrap();
+    pillar()
+        .current_dir(workspace.path())
+        .arg("init")
+        .assert()
+        .success();
+    let root = workspace.path().canonicalize().unwrap();
+
+    pillar()
+        .current_dir(workspace.path())
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Workspace: {}",
+            root.display()
+        )))
+        .stdout(predicate::str::contains(format!(
+            "Base directory: {}\n",
+            root.display()
+        )))
+        .stdout(predicate::str::contains("No projects in workspace."));
+}
+
+#[test]
+fn test_base_directory_env_override() {
+    let workspace = init_workspace();
+
+    pillar()
+        .current_dir(workspace.path())
+        .env("PILLAR_BASE_DIRECTORY", "ci-projects")
+        .args(["project", "create", "from-ci"])
+        .assert()
+        .success();
+    assert!(workspace
+        .path()
+        .join("ci-projects/from-ci/README.md")
+        .is_file());
+
+    // Without the variable, the file's base directory applies again
+    pillar()
+        .current_dir(workspace.path())
+        .args(["project", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pinned"))
+        .stdout(predicate::str::contains("from-ci").not());
+
+    // The override is never written back to config.toml
+    pillar()
+        .current_dir(workspace.path())
+        .env("PILLAR_BASE_DIRECTORY", "ci-projects")
+        .args(["config", "set", "defaults.priority", "high"])
+        .assert()
+        .success();
+    let config = std::fs::read_to_string(workspace.path().join(".pillar/config.toml")).unwrap();
+    assert!(!config.contains("ci-projects"));
+}
+
+#[test]
+fn test_quiet_warnings_hides_unreadable_projects() {
+    let workspace = init_workspace();
+    std::fs::create_dir(workspace.path().join("broken")).unwrap();
+    std::fs::write(
+        workspace.path().join("broken/README.md"),
+        "---\nname: Broken\n---\n",
+    )
+    .unwrap();
+
+    pillar()
+        .current_dir(workspace.path())
+        .args(["project", "list"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Warning: Failed to read project"));
+
+    pillar()
+        .current_dir(workspace.path())
+        .args(["config", "set", "display.quiet_warnings", "true"])
+        .assert()
+        .success();
+
+    pillar()
+        .current_dir(workspace.path())
+        .args(["project", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pinned"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_quiet_create_prints_nothing() {
+    let workspace = init_workspace();
+
+    pillar()
+        .current_dir(workspace.path())
+        .args(["--quiet", "issue", "create", "pinned", "Silent"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    pillar()
+        .current_dir(workspace.path())
+        .args(["issue", "create", "pinned", "Chatty", "--verbose"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("✓ Created issue").and(predicate::str::contains("Priority:")),
+        );
+
+    pillar()
+        .current_dir(workspace.path())
+        .args(["-q", "issue", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Silent"));
+}
+
+#[test]
+fn test_comment_add_prints_checkmark() {
+    let workspace = init_workspace();
+
+    pillar()
+        .current_dir(workspace.path())
+        .args(["comment", "add", "project", "pinned", "Looks good"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("✓ Added comment by "));
+}
+
+#[test]
+fn test_list_json_includes_ids() {
+    let workspace = init_workspace();
+    let run = |args: &[&str]| {
+        let output = pillar()
+            .current_dir(workspace.path())
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        output.stdout
+    };
+    run(&["milestone", "create", "pinned", "v1"]);
+    run(&["issue", "create", "pinned", "First", "--milestone", "v1"]);
+
+    let issues: serde_json::Value =
+        serde_json::from_slice(&run(&["issue", "list", "--json"])).unwrap();
+    assert_eq!(issues[0]["id"], "pinned/001");
+    assert_eq!(issues[0]["project"], "pinned");
+    assert_eq!(issues[0]["title"], "First");
+
+    let milestones: serde_json::Value =
+        serde_json::from_slice(&run(&["milestone", "list", "--json"])).unwrap();
+    assert_eq!(milestones[0]["id"], "pinned/v1");
+    assert_eq!(milestones[0]["project"], "pinned");
+    assert_eq!(milestones[0]["title"], "v1");
+}
+
+#[test]
+fn test_init_with_example_inside_parent_workspace() {
+    let parent = init_workspace();
+    let child = parent.path().join("child");
+    std::fs::create_dir(&child).unwrap();
+
+    pillar()
+        .current_dir(&child)
+        .args(["--workspace", parent.path().to_str().unwrap()])
+        .args(["init", "--with-example"])
+        .assert()
+        .success();
+
+    assert!(child.join("example-project").join("README.md").exists());
+    assert!(!parent.path().join("example-project").exists());
+}
+
+#[test]
+fn test_issue_list_updated_after_conflicts_with_sort() {
+    let workspace = init_workspace();
+    let list = |args: &[&str]| {
+        pillar()
+            .current_dir(workspace.path())
+            .args(["issue", "list", "--updated-after", "2020-01-01T00:00:00Z"])
+            .args(args)
+            .assert()
+    };
+
+    list(&[]).success();
+    list(&["--sort", "score"])
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_issue_list_count_matches_filtered_issues() {
+    let workspace = init_workspace();
+    let run = |args: &[&str]| {
+        let output = pillar()
+            .current_dir(workspace.path())
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    for (title, priority) in [("One", "high"), ("Two", "low"), ("Three", "high")] {
+        run(&["issue", "create", "pinned", title, "--priority", priority]);
+    }
+
+    let count = run(&["issue", "list", "--priority", "high", "--count"]);
+    let issues: serde_json::Value =
+        serde_json::from_str(&run(&["issue", "list", "--priority", "high", "--json"])).unwrap();
+    assert_eq!(issues.as_array().unwrap().len(), 2);
+    assert_eq!(count.trim(), issues.as_array().unwrap().len().to_string());
+}